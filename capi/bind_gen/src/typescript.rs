@@ -1,18 +1,28 @@
-pub static HEADER: &str = r#"export type ComponentStateJson =
+pub static HEADER: &str = r#"export type ComponentStateJson = (
     { BlankSpace: BlankSpaceComponentStateJson } |
+    { ComparisonTable: ComparisonTableComponentStateJson } |
+    { Container: ContainerComponentStateJson } |
     { CurrentComparison: CurrentComparisonComponentStateJson } |
     { CurrentPace: CurrentPaceComponentStateJson } |
     { Delta: DeltaComponentStateJson } |
     { Graph: GraphComponentStateJson } |
+    { PbChance: PbChanceComponentStateJson } |
     { PossibleTimeSave: PossibleTimeSaveComponentStateJson } |
     { PreviousSegment: PreviousSegmentComponentStateJson } |
+    { RunMetadata: RunMetadataComponentStateJson } |
+    { RunPrediction: RunPredictionComponentStateJson } |
+    { SegmentHistoryGraph: SegmentHistoryGraphComponentStateJson } |
+    { SegmentStatistics: SegmentStatisticsComponentStateJson } |
     { Separator: null } |
+    { SessionStatistics: SessionStatisticsComponentStateJson } |
     { Splits: SplitsComponentStateJson } |
     { SumOfBest: SumOfBestComponentStateJson } |
     { Text: TextComponentStateJson } |
     { Timer: TimerComponentStateJson } |
     { Title: TitleComponentStateJson } |
-    { TotalPlaytime: TotalPlaytimeComponentStateJson };
+    { TotalPlaytime: TotalPlaytimeComponentStateJson } |
+    { WallClock: WallClockComponentStateJson }
+) & { horizontal_arrangement: HorizontalArrangementJson };
 
 export type Color = number[];
 
@@ -24,12 +34,43 @@ export type Gradient =
 
 export interface LayoutStateJson {
     components: ComponentStateJson[],
+    direction: LayoutDirectionJson,
     background: Gradient,
     thin_separators_color: Color,
     separators_color: Color,
     text_color: Color,
+    timer_font: Font | null,
+    times_font: Font | null,
+    text_font: Font | null,
 }
 
+export interface Font {
+    family: string,
+    style: FontStyle,
+    weight: FontWeight,
+    scale: number,
+}
+
+export type FontStyle = "Normal" | "Italic";
+
+export type FontWeight =
+    "Thin" |
+    "ExtraLight" |
+    "Light" |
+    "Normal" |
+    "Medium" |
+    "SemiBold" |
+    "Bold" |
+    "ExtraBold" |
+    "Black";
+
+export type LayoutStateDiffJson = JsonPatchOperation[];
+
+export type JsonPatchOperation =
+    { op: "add", path: string, value: any } |
+    { op: "remove", path: string } |
+    { op: "replace", path: string, value: any };
+
 export enum TimingMethod {
     RealTime = 0,
     GameTime = 1,
@@ -42,6 +83,16 @@ export enum TimerPhase {
     Paused = 3,
 }
 
+export interface ContainerComponentStateJson {
+    direction: LayoutDirectionJson,
+    children: ContainerComponentChildStateJson[],
+}
+
+export interface ContainerComponentChildStateJson {
+    size: number,
+    state: ComponentStateJson,
+}
+
 export interface BlankSpaceComponentStateJson {
     background: Gradient,
     height: number,
@@ -55,11 +106,13 @@ export interface TimerComponentStateJson {
     top_color: Color,
     bottom_color: Color,
     height: number,
+    timer_font: Font | null,
 }
 
 export interface TitleComponentStateJson {
     background: Gradient,
     text_color: Color | null,
+    text_font: Font | null,
     icon_change: string | null,
     line1: string,
     line2: string | null,
@@ -73,6 +126,9 @@ export interface SplitsComponentStateJson {
     icon_changes: SplitsComponentIconChangeJson[],
     show_final_separator: boolean,
     current_split_gradient: Gradient,
+    column_labels: string[] | null,
+    text_font: Font | null,
+    times_font: Font | null,
 }
 
 export interface SplitsComponentIconChangeJson {
@@ -82,14 +138,17 @@ export interface SplitsComponentIconChangeJson {
 
 export interface SplitStateJson {
     name: string,
-    delta: string,
-    time: string,
-    semantic_color: SemanticColor,
-    visual_color: Color,
+    columns: SplitColumnStateJson[],
     is_current_split: boolean,
     index: number,
 }
 
+export interface SplitColumnStateJson {
+    value: string,
+    semantic_color: SemanticColor,
+    visual_color: Color,
+}
+
 export interface PreviousSegmentComponentStateJson {
     background: Gradient,
     label_color: Color | null,
@@ -99,6 +158,80 @@ export interface PreviousSegmentComponentStateJson {
     visual_color: Color,
 }
 
+export interface RunMetadataComponentStateJson {
+    background: Gradient,
+    label_color: Color | null,
+    value_color: Color | null,
+    rows: RunMetadataRowStateJson[],
+}
+
+export interface RunMetadataRowStateJson {
+    name: string,
+    value: string,
+}
+
+export interface RunPredictionComponentStateJson {
+    background: Gradient,
+    label_color: Color | null,
+    value_color: Color | null,
+    rows: RunPredictionRowStateJson[],
+}
+
+export interface RunPredictionRowStateJson {
+    comparison: string,
+    time: string,
+    delta: string,
+    semantic_color: SemanticColor,
+    visual_color: Color,
+}
+
+export interface SegmentHistoryGraphComponentStateJson {
+    background: Gradient,
+    segment_name: string,
+    is_histogram: boolean,
+    points: SegmentHistoryGraphComponentStatePointJson[],
+    bars: number[],
+    personal_best_marker: number | null,
+    best_segment_marker: number | null,
+    live_segment_marker: number | null,
+    graph_color: Color,
+    personal_best_color: Color,
+    best_segment_color: Color,
+    live_segment_color: Color,
+    height: number,
+}
+
+export interface SegmentHistoryGraphComponentStatePointJson {
+    x: number,
+    y: number,
+    is_best_segment: boolean,
+}
+
+export interface SegmentStatisticsComponentStateJson {
+    background: Gradient,
+    label_color: Color | null,
+    value_color: Color | null,
+    segment_name: string,
+    rows: SegmentStatisticsRowStateJson[],
+}
+
+export interface SegmentStatisticsRowStateJson {
+    name: string,
+    value: string,
+}
+
+export interface SessionStatisticsComponentStateJson {
+    background: Gradient,
+    label_color: Color | null,
+    value_color: Color | null,
+    rows: SessionStatisticsRowStateJson[],
+}
+
+export interface SessionStatisticsRowStateJson {
+    name: string,
+    value: string,
+}
+
 export interface SumOfBestComponentStateJson {
     background: Gradient,
     label_color: Color | null,
@@ -107,6 +240,14 @@ export interface SumOfBestComponentStateJson {
     time: string,
 }
 
+export interface PbChanceComponentStateJson {
+    background: Gradient,
+    label_color: Color | null,
+    value_color: Color | null,
+    text: string,
+    chance: string,
+}
+
 export interface PossibleTimeSaveComponentStateJson {
     background: Gradient,
     label_color: Color | null,
@@ -150,6 +291,15 @@ export interface TotalPlaytimeComponentStateJson {
     time: string,
 }
 
+export interface WallClockComponentStateJson {
+    background: Gradient,
+    label_color: Color | null,
+    value_color: Color | null,
+    time: string,
+    time_since_start: string | null,
+    session_time: string | null,
+}
+
 export interface CurrentPaceComponentStateJson {
     background: Gradient,
     label_color: Color | null,
@@ -167,6 +317,27 @@ export interface DeltaComponentStateJson {
     visual_color: Color,
 }
 
+export interface ComparisonTableComponentStateJson {
+    background: Gradient,
+    label_color: Color | null,
+    value_color: Color | null,
+    comparison1: string,
+    comparison2: string,
+    rows: ComparisonTableRowStateJson[],
+}
+
+export interface ComparisonTableRowStateJson {
+    name: string,
+    segment_time1: string,
+    segment_time2: string,
+    segment_difference: string,
+    segment_semantic_color: SemanticColor,
+    segment_visual_color: Color,
+    total_difference: string,
+    total_semantic_color: SemanticColor,
+    total_visual_color: Color,
+}
+
 export interface CurrentComparisonComponentStateJson {
     background: Gradient,
     label_color: Color | null,
@@ -192,9 +363,11 @@ export interface DetailedTimerComponentComparisonStateJson {
 
 export interface LayoutEditorStateJson {
     components: string[],
+    component_depths: number[],
     buttons: LayoutEditorButtonsJson,
     selected_component: number,
     component_settings: SettingsDescriptionJson,
+    component_visibility: SettingsDescriptionJson,
     general_settings: SettingsDescriptionJson,
 }
 
@@ -202,6 +375,8 @@ export interface LayoutEditorButtonsJson {
     can_remove: boolean,
     can_move_up: boolean,
     can_move_down: boolean,
+    can_move_into: boolean,
+    can_move_out: boolean,
 }
 
 export interface SettingsDescriptionJson {
@@ -225,7 +400,22 @@ export type SettingsDescriptionValueJson =
     { OptionalTimingMethod: TimingMethodJson | null } |
     { Color: Color } |
     { OptionalColor: Color | null } |
-    { Gradient: Gradient };
+    { Gradient: Gradient } |
+    { ColumnType: ColumnTypeJson } |
+    { LayoutDirection: LayoutDirectionJson } |
+    { OptionalFont: Font | null };
+
+export type LayoutDirectionJson = "Vertical" | "Horizontal";
+
+export type HorizontalArrangementJson = "Block" | "Stacked" | "Columns";
+
+export type ColumnTypeJson =
+    "Delta" |
+    "SplitTime" |
+    "SegmentTime" |
+    "SegmentDelta" |
+    "BestSegment" |
+    "PossibleTimeSave";
 
 export type AccuracyJson = "Seconds" | "Tenths" | "Hundredths";
 
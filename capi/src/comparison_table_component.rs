@@ -0,0 +1,47 @@
+use livesplit_core::component::comparison_table::Component as ComparisonTableComponent;
+use livesplit_core::{GeneralLayoutSettings, Timer};
+use super::{acc, alloc, output_vec, own, own_drop, Json};
+use comparison_table_component_state::OwnedComparisonTableComponentState;
+use component::OwnedComponent;
+
+pub type OwnedComparisonTableComponent = *mut ComparisonTableComponent;
+
+#[no_mangle]
+pub unsafe extern "C" fn ComparisonTableComponent_new() -> OwnedComparisonTableComponent {
+    alloc(ComparisonTableComponent::new())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ComparisonTableComponent_drop(this: OwnedComparisonTableComponent) {
+    own_drop(this);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ComparisonTableComponent_into_generic(
+    this: OwnedComparisonTableComponent,
+) -> OwnedComponent {
+    alloc(own(this).into())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ComparisonTableComponent_state_as_json(
+    this: *const ComparisonTableComponent,
+    timer: *const Timer,
+    layout_settings: *const GeneralLayoutSettings,
+) -> Json {
+    output_vec(|o| {
+        acc(this)
+            .state(acc(timer), acc(layout_settings))
+            .write_json(o)
+            .unwrap();
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ComparisonTableComponent_state(
+    this: *const ComparisonTableComponent,
+    timer: *const Timer,
+    layout_settings: *const GeneralLayoutSettings,
+) -> OwnedComparisonTableComponentState {
+    alloc(acc(this).state(acc(timer), acc(layout_settings)))
+}
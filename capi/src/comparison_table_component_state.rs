@@ -0,0 +1,90 @@
+use livesplit_core::component::comparison_table::State as ComparisonTableComponentState;
+use super::{acc, output_str, output_vec, own_drop};
+use libc::c_char;
+use std::io::Write;
+
+pub type OwnedComparisonTableComponentState = *mut ComparisonTableComponentState;
+
+#[no_mangle]
+pub unsafe extern "C" fn ComparisonTableComponentState_drop(
+    this: OwnedComparisonTableComponentState,
+) {
+    own_drop(this);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ComparisonTableComponentState_comparison1(
+    this: *const ComparisonTableComponentState,
+) -> *const c_char {
+    output_str(&acc(this).comparison1)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ComparisonTableComponentState_comparison2(
+    this: *const ComparisonTableComponentState,
+) -> *const c_char {
+    output_str(&acc(this).comparison2)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ComparisonTableComponentState_len(
+    this: *const ComparisonTableComponentState,
+) -> usize {
+    acc(this).rows.len()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ComparisonTableComponentState_name(
+    this: *const ComparisonTableComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&acc(this).rows[index].name)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ComparisonTableComponentState_segment_time1(
+    this: *const ComparisonTableComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&acc(this).rows[index].segment_time1)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ComparisonTableComponentState_segment_time2(
+    this: *const ComparisonTableComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&acc(this).rows[index].segment_time2)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ComparisonTableComponentState_segment_difference(
+    this: *const ComparisonTableComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&acc(this).rows[index].segment_difference)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ComparisonTableComponentState_total_difference(
+    this: *const ComparisonTableComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&acc(this).rows[index].total_difference)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ComparisonTableComponentState_segment_semantic_color(
+    this: *const ComparisonTableComponentState,
+    index: usize,
+) -> *const c_char {
+    output_vec(|f| write!(f, "{:?}", acc(this).rows[index].segment_semantic_color).unwrap())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ComparisonTableComponentState_total_semantic_color(
+    this: *const ComparisonTableComponentState,
+    index: usize,
+) -> *const c_char {
+    output_vec(|f| write!(f, "{:?}", acc(this).rows[index].total_semantic_color).unwrap())
+}
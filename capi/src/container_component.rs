@@ -0,0 +1,45 @@
+use livesplit_core::component::container::Component as ContainerComponent;
+use livesplit_core::{GeneralLayoutSettings, Timer};
+use super::{acc, acc_mut, alloc, output_vec, own, own_drop, Json};
+use component::OwnedComponent;
+
+pub type OwnedContainerComponent = *mut ContainerComponent;
+
+#[no_mangle]
+pub unsafe extern "C" fn ContainerComponent_new() -> OwnedContainerComponent {
+    alloc(ContainerComponent::new())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ContainerComponent_drop(this: OwnedContainerComponent) {
+    own_drop(this);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ContainerComponent_into_generic(
+    this: OwnedContainerComponent,
+) -> OwnedComponent {
+    alloc(own(this).into())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ContainerComponent_push(
+    this: *mut ContainerComponent,
+    component: OwnedComponent,
+) {
+    acc_mut(this).push(own(component));
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ContainerComponent_state_as_json(
+    this: *mut ContainerComponent,
+    timer: *const Timer,
+    layout_settings: *const GeneralLayoutSettings,
+) -> Json {
+    output_vec(|o| {
+        acc_mut(this)
+            .state(acc(timer), acc(layout_settings))
+            .write_json(o)
+            .unwrap();
+    })
+}
@@ -1,5 +1,6 @@
 use livesplit_core::{Layout, Timer};
-use livesplit_core::layout::LayoutSettings;
+use livesplit_core::layout::{saver, LayoutSettings};
+use libc::c_char;
 use super::{acc, acc_mut, alloc, output_vec, own, own_drop, str, Json};
 use component::OwnedComponent;
 use std::io::Cursor;
@@ -45,6 +46,13 @@ pub unsafe extern "C" fn Layout_state_as_json(this: *mut Layout, timer: *const T
     })
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn Layout_state_diff_as_json(this: *mut Layout, timer: *const Timer) -> Json {
+    output_vec(|o| {
+        acc_mut(this).state_diff(acc(timer)).write_json(o).unwrap();
+    })
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn Layout_settings_as_json(this: *const Layout) -> Json {
     output_vec(|o| {
@@ -52,6 +60,13 @@ pub unsafe extern "C" fn Layout_settings_as_json(this: *const Layout) -> Json {
     })
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn Layout_save_as_lsl(this: *const Layout) -> *const c_char {
+    output_vec(|o| {
+        saver::save(acc(this), o).unwrap();
+    })
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn Layout_push(this: *mut Layout, component: OwnedComponent) {
     acc_mut(this).push(own(component));
@@ -73,6 +73,16 @@ pub unsafe extern "C" fn LayoutEditor_move_component(this: *mut LayoutEditor, ds
     acc_mut(this).move_component(dst_index);
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn LayoutEditor_move_component_into(this: *mut LayoutEditor) {
+    acc_mut(this).move_component_into();
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn LayoutEditor_move_component_out(this: *mut LayoutEditor) {
+    acc_mut(this).move_component_out();
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn LayoutEditor_duplicate_component(this: *mut LayoutEditor) {
     acc_mut(this).duplicate_component();
@@ -87,6 +97,15 @@ pub unsafe extern "C" fn LayoutEditor_set_component_settings_value(
     acc_mut(this).set_component_settings_value(index, own(value));
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn LayoutEditor_set_component_visibility_value(
+    this: *mut LayoutEditor,
+    index: usize,
+    value: OwnedSettingValue,
+) {
+    acc_mut(this).set_component_visibility_value(index, own(value));
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn LayoutEditor_set_general_settings_value(
     this: *mut LayoutEditor,
@@ -30,6 +30,9 @@ pub mod layout_editor;
 pub mod setting_value;
 pub mod blank_space_component_state;
 pub mod blank_space_component;
+pub mod comparison_table_component_state;
+pub mod comparison_table_component;
+pub mod container_component;
 pub mod current_comparison_component_state;
 pub mod current_comparison_component;
 pub mod current_pace_component_state;
@@ -40,11 +43,23 @@ pub mod detailed_timer_component;
 pub mod detailed_timer_component_state;
 pub mod graph_component_state;
 pub mod graph_component;
+pub mod pb_chance_component_state;
+pub mod pb_chance_component;
 pub mod possible_time_save_component_state;
 pub mod possible_time_save_component;
 pub mod previous_segment_component_state;
 pub mod previous_segment_component;
+pub mod run_metadata_component_state;
+pub mod run_metadata_component;
+pub mod run_prediction_component_state;
+pub mod run_prediction_component;
+pub mod segment_history_graph_component_state;
+pub mod segment_history_graph_component;
+pub mod segment_statistics_component_state;
+pub mod segment_statistics_component;
 pub mod separator_component;
+pub mod session_statistics_component_state;
+pub mod session_statistics_component;
 pub mod splits_component_state;
 pub mod splits_component;
 pub mod sum_of_best_component_state;
@@ -57,6 +72,8 @@ pub mod title_component_state;
 pub mod title_component;
 pub mod total_playtime_component_state;
 pub mod total_playtime_component;
+pub mod wall_clock_component_state;
+pub mod wall_clock_component;
 pub mod run_editor;
 pub mod sum_of_best_cleaner;
 pub mod shared_timer;
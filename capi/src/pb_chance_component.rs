@@ -0,0 +1,42 @@
+use livesplit_core::component::pb_chance::Component as PbChanceComponent;
+use livesplit_core::Timer;
+use super::{acc, acc_mut, alloc, output_vec, own, own_drop, Json};
+use pb_chance_component_state::OwnedPbChanceComponentState;
+use component::OwnedComponent;
+
+pub type OwnedPbChanceComponent = *mut PbChanceComponent;
+
+#[no_mangle]
+pub unsafe extern "C" fn PbChanceComponent_new() -> OwnedPbChanceComponent {
+    alloc(PbChanceComponent::new())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn PbChanceComponent_drop(this: OwnedPbChanceComponent) {
+    own_drop(this);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn PbChanceComponent_into_generic(
+    this: OwnedPbChanceComponent,
+) -> OwnedComponent {
+    alloc(own(this).into())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn PbChanceComponent_state_as_json(
+    this: *mut PbChanceComponent,
+    timer: *const Timer,
+) -> Json {
+    output_vec(|o| {
+        acc_mut(this).state(acc(timer)).write_json(o).unwrap();
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn PbChanceComponent_state(
+    this: *mut PbChanceComponent,
+    timer: *const Timer,
+) -> OwnedPbChanceComponentState {
+    alloc(acc_mut(this).state(acc(timer)))
+}
@@ -0,0 +1,24 @@
+use livesplit_core::component::pb_chance::State as PbChanceComponentState;
+use super::{acc, output_str, own_drop};
+use libc::c_char;
+
+pub type OwnedPbChanceComponentState = *mut PbChanceComponentState;
+
+#[no_mangle]
+pub unsafe extern "C" fn PbChanceComponentState_drop(this: OwnedPbChanceComponentState) {
+    own_drop(this);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn PbChanceComponentState_text(
+    this: *const PbChanceComponentState,
+) -> *const c_char {
+    output_str(&acc(this).text)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn PbChanceComponentState_chance(
+    this: *const PbChanceComponentState,
+) -> *const c_char {
+    output_str(&acc(this).chance)
+}
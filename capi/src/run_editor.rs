@@ -205,6 +205,17 @@ pub unsafe extern "C" fn RunEditor_import_comparison(
         .is_ok()
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn RunEditor_link_comparison(
+    this: *mut RunEditor,
+    comparison: *const c_char,
+    path: *const c_char,
+) -> bool {
+    acc_mut(this)
+        .link_comparison(str(comparison), str(path))
+        .is_ok()
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn RunEditor_remove_comparison(
     this: *mut RunEditor,
@@ -0,0 +1,42 @@
+use livesplit_core::component::run_metadata::Component as RunMetadataComponent;
+use livesplit_core::Timer;
+use super::{acc, alloc, output_vec, own, own_drop, Json};
+use run_metadata_component_state::OwnedRunMetadataComponentState;
+use component::OwnedComponent;
+
+pub type OwnedRunMetadataComponent = *mut RunMetadataComponent;
+
+#[no_mangle]
+pub unsafe extern "C" fn RunMetadataComponent_new() -> OwnedRunMetadataComponent {
+    alloc(RunMetadataComponent::new())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn RunMetadataComponent_drop(this: OwnedRunMetadataComponent) {
+    own_drop(this);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn RunMetadataComponent_into_generic(
+    this: OwnedRunMetadataComponent,
+) -> OwnedComponent {
+    alloc(own(this).into())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn RunMetadataComponent_state_as_json(
+    this: *const RunMetadataComponent,
+    timer: *const Timer,
+) -> Json {
+    output_vec(|o| {
+        acc(this).state(acc(timer)).write_json(o).unwrap();
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn RunMetadataComponent_state(
+    this: *const RunMetadataComponent,
+    timer: *const Timer,
+) -> OwnedRunMetadataComponentState {
+    alloc(acc(this).state(acc(timer)))
+}
@@ -0,0 +1,35 @@
+use livesplit_core::component::run_metadata::State as RunMetadataComponentState;
+use super::{acc, output_str, own_drop};
+use libc::c_char;
+
+pub type OwnedRunMetadataComponentState = *mut RunMetadataComponentState;
+
+#[no_mangle]
+pub unsafe extern "C" fn RunMetadataComponentState_drop(
+    this: OwnedRunMetadataComponentState,
+) {
+    own_drop(this);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn RunMetadataComponentState_len(
+    this: *const RunMetadataComponentState,
+) -> usize {
+    acc(this).rows.len()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn RunMetadataComponentState_name(
+    this: *const RunMetadataComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&acc(this).rows[index].name)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn RunMetadataComponentState_value(
+    this: *const RunMetadataComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&acc(this).rows[index].value)
+}
@@ -0,0 +1,47 @@
+use livesplit_core::component::run_prediction::Component as RunPredictionComponent;
+use livesplit_core::{GeneralLayoutSettings, Timer};
+use super::{acc, alloc, output_vec, own, own_drop, Json};
+use run_prediction_component_state::OwnedRunPredictionComponentState;
+use component::OwnedComponent;
+
+pub type OwnedRunPredictionComponent = *mut RunPredictionComponent;
+
+#[no_mangle]
+pub unsafe extern "C" fn RunPredictionComponent_new() -> OwnedRunPredictionComponent {
+    alloc(RunPredictionComponent::new())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn RunPredictionComponent_drop(this: OwnedRunPredictionComponent) {
+    own_drop(this);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn RunPredictionComponent_into_generic(
+    this: OwnedRunPredictionComponent,
+) -> OwnedComponent {
+    alloc(own(this).into())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn RunPredictionComponent_state_as_json(
+    this: *const RunPredictionComponent,
+    timer: *const Timer,
+    layout_settings: *const GeneralLayoutSettings,
+) -> Json {
+    output_vec(|o| {
+        acc(this)
+            .state(acc(timer), acc(layout_settings))
+            .write_json(o)
+            .unwrap();
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn RunPredictionComponent_state(
+    this: *const RunPredictionComponent,
+    timer: *const Timer,
+    layout_settings: *const GeneralLayoutSettings,
+) -> OwnedRunPredictionComponentState {
+    alloc(acc(this).state(acc(timer), acc(layout_settings)))
+}
@@ -0,0 +1,52 @@
+use livesplit_core::component::run_prediction::State as RunPredictionComponentState;
+use super::{acc, output_str, output_vec, own_drop};
+use libc::c_char;
+use std::io::Write;
+
+pub type OwnedRunPredictionComponentState = *mut RunPredictionComponentState;
+
+#[no_mangle]
+pub unsafe extern "C" fn RunPredictionComponentState_drop(
+    this: OwnedRunPredictionComponentState,
+) {
+    own_drop(this);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn RunPredictionComponentState_len(
+    this: *const RunPredictionComponentState,
+) -> usize {
+    acc(this).rows.len()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn RunPredictionComponentState_comparison(
+    this: *const RunPredictionComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&acc(this).rows[index].comparison)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn RunPredictionComponentState_time(
+    this: *const RunPredictionComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&acc(this).rows[index].time)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn RunPredictionComponentState_delta(
+    this: *const RunPredictionComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&acc(this).rows[index].delta)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn RunPredictionComponentState_semantic_color(
+    this: *const RunPredictionComponentState,
+    index: usize,
+) -> *const c_char {
+    output_vec(|f| write!(f, "{:?}", acc(this).rows[index].semantic_color).unwrap())
+}
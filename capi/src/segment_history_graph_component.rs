@@ -0,0 +1,49 @@
+use livesplit_core::component::segment_history_graph::Component as SegmentHistoryGraphComponent;
+use livesplit_core::{GeneralLayoutSettings, Timer};
+use super::{acc, alloc, output_vec, own, own_drop, Json};
+use segment_history_graph_component_state::OwnedSegmentHistoryGraphComponentState;
+use component::OwnedComponent;
+
+pub type OwnedSegmentHistoryGraphComponent = *mut SegmentHistoryGraphComponent;
+
+#[no_mangle]
+pub unsafe extern "C" fn SegmentHistoryGraphComponent_new() -> OwnedSegmentHistoryGraphComponent {
+    alloc(SegmentHistoryGraphComponent::new())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SegmentHistoryGraphComponent_drop(
+    this: OwnedSegmentHistoryGraphComponent,
+) {
+    own_drop(this);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SegmentHistoryGraphComponent_into_generic(
+    this: OwnedSegmentHistoryGraphComponent,
+) -> OwnedComponent {
+    alloc(own(this).into())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SegmentHistoryGraphComponent_state_as_json(
+    this: *const SegmentHistoryGraphComponent,
+    timer: *const Timer,
+    layout_settings: *const GeneralLayoutSettings,
+) -> Json {
+    output_vec(|o| {
+        acc(this)
+            .state(acc(timer), acc(layout_settings))
+            .write_json(o)
+            .unwrap();
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SegmentHistoryGraphComponent_state(
+    this: *const SegmentHistoryGraphComponent,
+    timer: *const Timer,
+    layout_settings: *const GeneralLayoutSettings,
+) -> OwnedSegmentHistoryGraphComponentState {
+    alloc(acc(this).state(acc(timer), acc(layout_settings)))
+}
@@ -0,0 +1,114 @@
+use livesplit_core::component::segment_history_graph::State as SegmentHistoryGraphComponentState;
+use super::{acc, output_str, own_drop};
+use libc::c_char;
+
+pub type OwnedSegmentHistoryGraphComponentState = *mut SegmentHistoryGraphComponentState;
+
+#[no_mangle]
+pub unsafe extern "C" fn SegmentHistoryGraphComponentState_drop(
+    this: OwnedSegmentHistoryGraphComponentState,
+) {
+    own_drop(this);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SegmentHistoryGraphComponentState_segment_name(
+    this: *const SegmentHistoryGraphComponentState,
+) -> *const c_char {
+    output_str(&acc(this).segment_name)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SegmentHistoryGraphComponentState_is_histogram(
+    this: *const SegmentHistoryGraphComponentState,
+) -> bool {
+    acc(this).is_histogram
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SegmentHistoryGraphComponentState_points_len(
+    this: *const SegmentHistoryGraphComponentState,
+) -> usize {
+    acc(this).points.len()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SegmentHistoryGraphComponentState_point_x(
+    this: *const SegmentHistoryGraphComponentState,
+    index: usize,
+) -> f32 {
+    acc(this).points[index].x
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SegmentHistoryGraphComponentState_point_y(
+    this: *const SegmentHistoryGraphComponentState,
+    index: usize,
+) -> f32 {
+    acc(this).points[index].y
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SegmentHistoryGraphComponentState_point_is_best_segment(
+    this: *const SegmentHistoryGraphComponentState,
+    index: usize,
+) -> bool {
+    acc(this).points[index].is_best_segment
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SegmentHistoryGraphComponentState_bars_len(
+    this: *const SegmentHistoryGraphComponentState,
+) -> usize {
+    acc(this).bars.len()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SegmentHistoryGraphComponentState_bar(
+    this: *const SegmentHistoryGraphComponentState,
+    index: usize,
+) -> f32 {
+    acc(this).bars[index]
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SegmentHistoryGraphComponentState_shows_personal_best_marker(
+    this: *const SegmentHistoryGraphComponentState,
+) -> bool {
+    acc(this).personal_best_marker.is_some()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SegmentHistoryGraphComponentState_personal_best_marker(
+    this: *const SegmentHistoryGraphComponentState,
+) -> f32 {
+    acc(this).personal_best_marker.unwrap_or_default()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SegmentHistoryGraphComponentState_shows_best_segment_marker(
+    this: *const SegmentHistoryGraphComponentState,
+) -> bool {
+    acc(this).best_segment_marker.is_some()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SegmentHistoryGraphComponentState_best_segment_marker(
+    this: *const SegmentHistoryGraphComponentState,
+) -> f32 {
+    acc(this).best_segment_marker.unwrap_or_default()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SegmentHistoryGraphComponentState_shows_live_segment_marker(
+    this: *const SegmentHistoryGraphComponentState,
+) -> bool {
+    acc(this).live_segment_marker.is_some()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SegmentHistoryGraphComponentState_live_segment_marker(
+    this: *const SegmentHistoryGraphComponentState,
+) -> f32 {
+    acc(this).live_segment_marker.unwrap_or_default()
+}
@@ -0,0 +1,42 @@
+use livesplit_core::component::segment_statistics::Component as SegmentStatisticsComponent;
+use livesplit_core::Timer;
+use super::{acc, alloc, output_vec, own, own_drop, Json};
+use segment_statistics_component_state::OwnedSegmentStatisticsComponentState;
+use component::OwnedComponent;
+
+pub type OwnedSegmentStatisticsComponent = *mut SegmentStatisticsComponent;
+
+#[no_mangle]
+pub unsafe extern "C" fn SegmentStatisticsComponent_new() -> OwnedSegmentStatisticsComponent {
+    alloc(SegmentStatisticsComponent::new())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SegmentStatisticsComponent_drop(this: OwnedSegmentStatisticsComponent) {
+    own_drop(this);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SegmentStatisticsComponent_into_generic(
+    this: OwnedSegmentStatisticsComponent,
+) -> OwnedComponent {
+    alloc(own(this).into())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SegmentStatisticsComponent_state_as_json(
+    this: *const SegmentStatisticsComponent,
+    timer: *const Timer,
+) -> Json {
+    output_vec(|o| {
+        acc(this).state(acc(timer)).write_json(o).unwrap();
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SegmentStatisticsComponent_state(
+    this: *const SegmentStatisticsComponent,
+    timer: *const Timer,
+) -> OwnedSegmentStatisticsComponentState {
+    alloc(acc(this).state(acc(timer)))
+}
@@ -0,0 +1,42 @@
+use livesplit_core::component::segment_statistics::State as SegmentStatisticsComponentState;
+use super::{acc, output_str, own_drop};
+use libc::c_char;
+
+pub type OwnedSegmentStatisticsComponentState = *mut SegmentStatisticsComponentState;
+
+#[no_mangle]
+pub unsafe extern "C" fn SegmentStatisticsComponentState_drop(
+    this: OwnedSegmentStatisticsComponentState,
+) {
+    own_drop(this);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SegmentStatisticsComponentState_segment_name(
+    this: *const SegmentStatisticsComponentState,
+) -> *const c_char {
+    output_str(&acc(this).segment_name)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SegmentStatisticsComponentState_len(
+    this: *const SegmentStatisticsComponentState,
+) -> usize {
+    acc(this).rows.len()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SegmentStatisticsComponentState_name(
+    this: *const SegmentStatisticsComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&acc(this).rows[index].name)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SegmentStatisticsComponentState_value(
+    this: *const SegmentStatisticsComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&acc(this).rows[index].value)
+}
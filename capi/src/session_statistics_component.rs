@@ -0,0 +1,42 @@
+use livesplit_core::component::session_statistics::Component as SessionStatisticsComponent;
+use livesplit_core::Timer;
+use super::{acc, alloc, output_vec, own, own_drop, Json};
+use session_statistics_component_state::OwnedSessionStatisticsComponentState;
+use component::OwnedComponent;
+
+pub type OwnedSessionStatisticsComponent = *mut SessionStatisticsComponent;
+
+#[no_mangle]
+pub unsafe extern "C" fn SessionStatisticsComponent_new() -> OwnedSessionStatisticsComponent {
+    alloc(SessionStatisticsComponent::new())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SessionStatisticsComponent_drop(this: OwnedSessionStatisticsComponent) {
+    own_drop(this);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SessionStatisticsComponent_into_generic(
+    this: OwnedSessionStatisticsComponent,
+) -> OwnedComponent {
+    alloc(own(this).into())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SessionStatisticsComponent_state_as_json(
+    this: *const SessionStatisticsComponent,
+    timer: *const Timer,
+) -> Json {
+    output_vec(|o| {
+        acc(this).state(acc(timer)).write_json(o).unwrap();
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SessionStatisticsComponent_state(
+    this: *const SessionStatisticsComponent,
+    timer: *const Timer,
+) -> OwnedSessionStatisticsComponentState {
+    alloc(acc(this).state(acc(timer)))
+}
@@ -0,0 +1,35 @@
+use livesplit_core::component::session_statistics::State as SessionStatisticsComponentState;
+use super::{acc, output_str, own_drop};
+use libc::c_char;
+
+pub type OwnedSessionStatisticsComponentState = *mut SessionStatisticsComponentState;
+
+#[no_mangle]
+pub unsafe extern "C" fn SessionStatisticsComponentState_drop(
+    this: OwnedSessionStatisticsComponentState,
+) {
+    own_drop(this);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SessionStatisticsComponentState_len(
+    this: *const SessionStatisticsComponentState,
+) -> usize {
+    acc(this).rows.len()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SessionStatisticsComponentState_name(
+    this: *const SessionStatisticsComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&acc(this).rows[index].name)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SessionStatisticsComponentState_value(
+    this: *const SessionStatisticsComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&acc(this).rows[index].value)
+}
@@ -1,4 +1,6 @@
-use livesplit_core::settings::{Alignment, Color, Gradient, Value as SettingValue};
+use livesplit_core::settings::{Alignment, Color, Font, FontStyle, FontWeight, Gradient,
+                               LayoutDirection, Value as SettingValue};
+use livesplit_core::component::splits::ColumnType;
 use livesplit_core::time::formatter::{Accuracy, DigitsFormat};
 use livesplit_core::TimingMethod;
 use {alloc, own_drop, str};
@@ -179,3 +181,72 @@ pub unsafe extern "C" fn SettingValue_from_alignment(
     };
     alloc(value.into())
 }
+
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_column_type(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value);
+    let value = match value {
+        "Delta" => ColumnType::Delta,
+        "SplitTime" => ColumnType::SplitTime,
+        "SegmentTime" => ColumnType::SegmentTime,
+        "SegmentDelta" => ColumnType::SegmentDelta,
+        "BestSegment" => ColumnType::BestSegment,
+        "PossibleTimeSave" => ColumnType::PossibleTimeSave,
+        _ => return ptr::null_mut(),
+    };
+    alloc(value.into())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_layout_direction(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value);
+    let value = match value {
+        "Vertical" => LayoutDirection::Vertical,
+        "Horizontal" => LayoutDirection::Horizontal,
+        _ => return ptr::null_mut(),
+    };
+    alloc(value.into())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_optional_font(
+    family: *const c_char,
+    style: *const c_char,
+    weight: *const c_char,
+    scale: f32,
+) -> NullableOwnedSettingValue {
+    let style = match str(style) {
+        "Normal" => FontStyle::Normal,
+        "Italic" => FontStyle::Italic,
+        _ => return ptr::null_mut(),
+    };
+    let weight = match str(weight) {
+        "Thin" => FontWeight::Thin,
+        "ExtraLight" => FontWeight::ExtraLight,
+        "Light" => FontWeight::Light,
+        "Normal" => FontWeight::Normal,
+        "Medium" => FontWeight::Medium,
+        "SemiBold" => FontWeight::SemiBold,
+        "Bold" => FontWeight::Bold,
+        "ExtraBold" => FontWeight::ExtraBold,
+        "Black" => FontWeight::Black,
+        _ => return ptr::null_mut(),
+    };
+    alloc(
+        Some(Font {
+            family: str(family).to_string(),
+            style,
+            weight,
+            scale,
+        }).into(),
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_optional_empty_font() -> OwnedSettingValue {
+    alloc(None::<Font>.into())
+}
@@ -54,28 +54,34 @@ pub unsafe extern "C" fn SplitsComponentState_name(
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn SplitsComponentState_delta(
+pub unsafe extern "C" fn SplitsComponentState_columns_len(
     this: *const SplitsComponentState,
     index: usize,
-) -> *const c_char {
-    output_str(&acc(this).splits[index].delta)
+) -> usize {
+    acc(this).splits[index].columns.len()
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn SplitsComponentState_time(
+pub unsafe extern "C" fn SplitsComponentState_column_value(
     this: *const SplitsComponentState,
     index: usize,
+    column_index: usize,
 ) -> *const c_char {
-    output_str(&acc(this).splits[index].time)
+    output_str(&acc(this).splits[index].columns[column_index].value)
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn SplitsComponentState_semantic_color(
+pub unsafe extern "C" fn SplitsComponentState_column_semantic_color(
     this: *const SplitsComponentState,
     index: usize,
+    column_index: usize,
 ) -> *const c_char {
     output_vec(|f| {
-        write!(f, "{:?}", acc(this).splits[index].semantic_color).unwrap()
+        write!(
+            f,
+            "{:?}",
+            acc(this).splits[index].columns[column_index].semantic_color
+        ).unwrap()
     })
 }
 
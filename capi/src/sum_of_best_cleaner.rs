@@ -1,5 +1,5 @@
 use livesplit_core::run::editor::cleaning::{PotentialCleanUp, SumOfBestCleaner};
-use super::{acc, acc_mut, alloc, output_vec, own, own_drop};
+use super::{acc, acc_mut, alloc, output_vec, own, own_drop, Json};
 use libc::c_char;
 use std::io::Write;
 use std::ptr;
@@ -30,6 +30,26 @@ pub unsafe extern "C" fn SumOfBestCleaner_apply(
     acc_mut(this).apply(own(clean_up).into());
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn SumOfBestCleaner_dry_run_as_json(
+    this: *mut SumOfBestCleaner<'static>,
+    threshold: f64,
+) -> Json {
+    output_vec(|o| {
+        acc_mut(this).dry_run(threshold).write_json(o).unwrap();
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SumOfBestCleaner_auto_clean_as_json(
+    this: *mut SumOfBestCleaner<'static>,
+    threshold: f64,
+) -> Json {
+    output_vec(|o| {
+        acc_mut(this).auto_clean(threshold).write_json(o).unwrap();
+    })
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn PotentialCleanUp_drop(this: OwnedPotentialCleanUp) {
     own_drop(this);
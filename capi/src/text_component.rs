@@ -1,6 +1,7 @@
 use livesplit_core::component::text::Component as TextComponent;
 use super::{acc, acc_mut, alloc, output_vec, own, own_drop, str, Json};
 use text_component_state::OwnedTextComponentState;
+use livesplit_core::Timer;
 use libc::c_char;
 use component::OwnedComponent;
 
@@ -22,9 +23,12 @@ pub unsafe extern "C" fn TextComponent_into_generic(this: OwnedTextComponent) ->
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn TextComponent_state_as_json(this: *const TextComponent) -> Json {
+pub unsafe extern "C" fn TextComponent_state_as_json(
+    this: *const TextComponent,
+    timer: *const Timer,
+) -> Json {
     output_vec(|o| {
-        acc(this).state().write_json(o).unwrap();
+        acc(this).state(acc(timer)).write_json(o).unwrap();
     })
 }
 
@@ -46,6 +50,7 @@ pub unsafe extern "C" fn TextComponent_set_right(this: *mut TextComponent, text:
 #[no_mangle]
 pub unsafe extern "C" fn TextComponent_state(
     this: *const TextComponent,
+    timer: *const Timer,
 ) -> OwnedTextComponentState {
-    alloc(acc(this).state())
+    alloc(acc(this).state(acc(timer)))
 }
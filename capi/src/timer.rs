@@ -128,6 +128,11 @@ pub unsafe extern "C" fn Timer_switch_to_previous_comparison(this: *mut Timer) {
     acc_mut(this).switch_to_previous_comparison();
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn Timer_update_linked_comparisons(this: *mut Timer) -> bool {
+    acc_mut(this).update_linked_comparisons()
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn Timer_is_game_time_initialized(this: *const Timer) -> bool {
     acc(this).is_game_time_initialized()
@@ -0,0 +1,42 @@
+use livesplit_core::component::wall_clock::Component as WallClockComponent;
+use livesplit_core::Timer;
+use super::{acc, alloc, output_vec, own, own_drop, Json};
+use wall_clock_component_state::OwnedWallClockComponentState;
+use component::OwnedComponent;
+
+pub type OwnedWallClockComponent = *mut WallClockComponent;
+
+#[no_mangle]
+pub unsafe extern "C" fn WallClockComponent_new() -> OwnedWallClockComponent {
+    alloc(WallClockComponent::new())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn WallClockComponent_drop(this: OwnedWallClockComponent) {
+    own_drop(this);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn WallClockComponent_into_generic(
+    this: OwnedWallClockComponent,
+) -> OwnedComponent {
+    alloc(own(this).into())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn WallClockComponent_state_as_json(
+    this: *const WallClockComponent,
+    timer: *const Timer,
+) -> Json {
+    output_vec(|o| {
+        acc(this).state(acc(timer)).write_json(o).unwrap();
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn WallClockComponent_state(
+    this: *const WallClockComponent,
+    timer: *const Timer,
+) -> OwnedWallClockComponentState {
+    alloc(acc(this).state(acc(timer)))
+}
@@ -0,0 +1,38 @@
+use livesplit_core::component::wall_clock::State as WallClockComponentState;
+use super::{acc, output_str, own_drop, Nullablec_char};
+use libc::c_char;
+use std::ptr;
+
+pub type OwnedWallClockComponentState = *mut WallClockComponentState;
+
+#[no_mangle]
+pub unsafe extern "C" fn WallClockComponentState_drop(this: OwnedWallClockComponentState) {
+    own_drop(this);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn WallClockComponentState_time(
+    this: *const WallClockComponentState,
+) -> *const c_char {
+    output_str(&acc(this).time)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn WallClockComponentState_time_since_start(
+    this: *const WallClockComponentState,
+) -> *const Nullablec_char {
+    acc(this)
+        .time_since_start
+        .as_ref()
+        .map_or_else(ptr::null, output_str)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn WallClockComponentState_session_time(
+    this: *const WallClockComponentState,
+) -> *const Nullablec_char {
+    acc(this)
+        .session_time
+        .as_ref()
+        .map_or_else(ptr::null, output_str)
+}
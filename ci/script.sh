@@ -25,6 +25,7 @@ main() {
 
     cross test -p livesplit-core --target $TARGET
     cross test -p livesplit-core --target $TARGET --release
+    cross test -p livesplit-core --target $TARGET --features software-rendering
 
     # cross run --target $TARGET
     # cross run --target $TARGET --release
@@ -3,7 +3,11 @@ pub mod current_pace;
 pub mod delta;
 pub mod sum_of_segments;
 pub mod total_playtime;
+pub mod pb_chance;
 pub mod possible_time_save;
+pub mod resets;
+pub mod session;
+pub mod statistics;
 
 pub use self::state_helper::*;
 
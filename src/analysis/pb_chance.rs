@@ -0,0 +1,220 @@
+use {TimeSpan, Timer, TimerPhase, TimingMethod};
+use analysis::statistics::segment_times;
+
+/// A small pseudo random number generator based on xorshift64*. It is good
+/// enough for the simulations and always produces the same numbers for the
+/// same seed, so estimates are reproducible.
+#[derive(Clone, Debug)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn with_seed(seed: u64) -> Self {
+        // The state must never be zero, as it would stay zero forever.
+        let state = seed ^ 0x9E37_79B9_7F4A_7C15;
+        Rng(if state == 0 { 0x9E37_79B9_7F4A_7C15 } else { state })
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Returns a random index that is smaller than `len`.
+    pub fn next_index(&mut self, len: usize) -> usize {
+        (self.next_u64() % len as u64) as usize
+    }
+}
+
+/// The result of simulating the remainder of a run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Estimate {
+    /// The chance of finishing the run faster than the target time.
+    pub probability: f64,
+    /// The 95% confidence interval of the probability.
+    pub probability_interval: (f64, f64),
+    /// The average final time of all the simulated runs.
+    pub expected_time: TimeSpan,
+    /// The range of final times that 95% of the simulated runs ended up in.
+    pub time_interval: (TimeSpan, TimeSpan),
+}
+
+/// Estimates the chance of finishing the run faster than the final time of a
+/// comparison.
+/// - `timer`: The current timer.
+/// - `comparison`: The comparison to beat.
+/// - `iterations`: The amount of runs to simulate.
+/// - `rng`: The random number generator to use for sampling.
+///
+/// Returns the estimate or None if the comparison doesn't have a final time or
+/// there's a segment without any times to sample from.
+pub fn calculate_for_comparison(
+    timer: &Timer,
+    comparison: &str,
+    iterations: usize,
+    rng: &mut Rng,
+) -> Option<Estimate> {
+    let method = timer.current_timing_method();
+    let target = timer.run().segments().last()?.comparison(comparison)[method]?;
+    calculate(timer, target, iterations, rng)
+}
+
+/// Estimates the chance of finishing the run faster than a target time by
+/// simulating the remainder of the run. The time of each remaining segment is
+/// sampled from the segment's history. The segment that is currently being
+/// run only samples the times that are longer than the time spent in it so
+/// far.
+/// - `timer`: The current timer.
+/// - `target`: The final time to beat.
+/// - `iterations`: The amount of runs to simulate.
+/// - `rng`: The random number generator to use for sampling.
+///
+/// Returns the estimate or None if there's a segment without any times to
+/// sample from.
+pub fn calculate(
+    timer: &Timer,
+    target: TimeSpan,
+    iterations: usize,
+    rng: &mut Rng,
+) -> Option<Estimate> {
+    let method = timer.current_timing_method();
+    let run = timer.run();
+
+    let (start_index, start_time, elapsed) = match timer.current_phase() {
+        TimerPhase::NotRunning => (0, TimeSpan::zero(), TimeSpan::zero()),
+        TimerPhase::Ended => {
+            let final_time = run.segments().last()?.split_time()[method]?;
+            return Some(finished_estimate(final_time, target));
+        }
+        TimerPhase::Running | TimerPhase::Paused => {
+            let (start_index, start_time) = current_start(timer, method)?;
+            let elapsed = timer.current_time()[method]? - start_time;
+            (start_index, start_time, elapsed)
+        }
+    };
+
+    let samples = (start_index..run.len())
+        .map(|index| sampled_times(timer, index, method))
+        .collect::<Option<Vec<_>>>()?;
+
+    // The segments that got skipped and the current segment are only known
+    // as a whole, so they are simulated as a single chunk.
+    let chunk_len = match timer.current_split_index() {
+        Some(current_index) if timer.current_phase() != TimerPhase::NotRunning => {
+            current_index + 1 - start_index
+        }
+        _ => 0,
+    };
+    let current_samples: Vec<_> = if chunk_len == 1 {
+        samples[0]
+            .iter()
+            .cloned()
+            .filter(|&t| t >= elapsed)
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let mut final_times = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let mut time = start_time;
+        let (chunk, remaining) = samples.split_at(chunk_len);
+
+        if chunk_len == 1 && !current_samples.is_empty() {
+            time += current_samples[rng.next_index(current_samples.len())];
+        } else if chunk_len > 0 {
+            let mut chunk_time = TimeSpan::zero();
+            for times in chunk {
+                chunk_time += times[rng.next_index(times.len())];
+            }
+            time += if chunk_time > elapsed {
+                chunk_time
+            } else {
+                elapsed
+            };
+        }
+
+        for times in remaining {
+            time += times[rng.next_index(times.len())];
+        }
+
+        final_times.push(time);
+    }
+
+    if final_times.is_empty() {
+        return None;
+    }
+    final_times.sort();
+
+    let len = final_times.len();
+    let successes = final_times.iter().filter(|&&t| t < target).count();
+    let probability = successes as f64 / len as f64;
+    let mean = final_times.iter().map(TimeSpan::total_seconds).sum::<f64>() / len as f64;
+
+    Some(Estimate {
+        probability,
+        probability_interval: wilson_interval(probability, len),
+        expected_time: TimeSpan::from_seconds(mean),
+        time_interval: (
+            percentile(&final_times, 0.025),
+            percentile(&final_times, 0.975),
+        ),
+    })
+}
+
+/// Determines where the part of the run that is currently in progress
+/// started. Skipped splits don't have a time, so this walks back to the last
+/// split that has one. The segments since then are only known as a whole.
+///
+/// Returns the index of the first of these segments and the split time it
+/// started at, or None if the timer hasn't been started.
+pub fn current_start(timer: &Timer, method: TimingMethod) -> Option<(usize, TimeSpan)> {
+    let current_index = timer.current_split_index()?;
+    let start = timer.run().segments()[..current_index]
+        .iter()
+        .enumerate()
+        .rev()
+        .filter_map(|(i, s)| s.split_time()[method].map(|t| (i + 1, t)))
+        .next()
+        .unwrap_or((0, TimeSpan::zero()));
+    Some(start)
+}
+
+fn sampled_times(timer: &Timer, index: usize, method: TimingMethod) -> Option<Vec<TimeSpan>> {
+    let times = segment_times(timer.run(), index, method)
+        .into_iter()
+        .map(|(_, t)| t)
+        .collect::<Vec<_>>();
+
+    if times.is_empty() {
+        timer.run().segment(index).best_segment_time()[method].map(|t| vec![t])
+    } else {
+        Some(times)
+    }
+}
+
+fn finished_estimate(final_time: TimeSpan, target: TimeSpan) -> Estimate {
+    let probability = if final_time < target { 1.0 } else { 0.0 };
+    Estimate {
+        probability,
+        probability_interval: (probability, probability),
+        expected_time: final_time,
+        time_interval: (final_time, final_time),
+    }
+}
+
+fn percentile(sorted_times: &[TimeSpan], percentile: f64) -> TimeSpan {
+    let index = (percentile * (sorted_times.len() - 1) as f64).round() as usize;
+    sorted_times[index]
+}
+
+fn wilson_interval(probability: f64, samples: usize) -> (f64, f64) {
+    const Z: f64 = 1.96;
+    let n = samples as f64;
+    let denominator = 1.0 + Z * Z / n;
+    let center = (probability + Z * Z / (2.0 * n)) / denominator;
+    let spread = Z * (probability * (1.0 - probability) / n + Z * Z / (4.0 * n * n)).sqrt()
+        / denominator;
+    ((center - spread).max(0.0), (center + spread).min(1.0))
+}
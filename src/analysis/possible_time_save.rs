@@ -1,13 +1,13 @@
-use {analysis, TimeSpan, Timer};
+use {analysis, TimeSpan, Timer, TimingMethod};
 
 pub fn calculate(
     timer: &Timer,
     segment_index: usize,
     comparison: &str,
     live: bool,
+    method: TimingMethod,
 ) -> Option<TimeSpan> {
     let segments = timer.run().segments();
-    let method = timer.current_timing_method();
     let mut prev_time = TimeSpan::zero();
     let segment = timer.run().segment(segment_index);
     let mut best_segments = segment.best_segment_time()[method];
@@ -51,11 +51,16 @@ pub fn calculate(
     }
 }
 
-pub fn calculate_total(timer: &Timer, segment_index: usize, comparison: &str) -> TimeSpan {
+pub fn calculate_total(
+    timer: &Timer,
+    segment_index: usize,
+    comparison: &str,
+    method: TimingMethod,
+) -> TimeSpan {
     let mut total = TimeSpan::zero();
 
     for index in segment_index..timer.run().len() {
-        if let Some(time_save) = calculate(timer, index, comparison, true) {
+        if let Some(time_save) = calculate(timer, index, comparison, true, method) {
             total += time_save;
         }
     }
@@ -0,0 +1,124 @@
+use {Attempt, DateTime, Run, Utc};
+use serde_json::{to_writer, Result};
+use std::io::Write;
+
+/// Limits the attempts that are considered by the reset analysis.
+#[derive(Default, Clone, Debug)]
+pub struct Filter {
+    /// Only consider attempts that started at or after this date.
+    pub started_after: Option<DateTime<Utc>>,
+    /// Only consider attempts that started before this date.
+    pub started_before: Option<DateTime<Utc>>,
+    /// Only consider the most recent attempts. This is applied after the
+    /// date range.
+    pub last_attempts: Option<usize>,
+}
+
+/// Describes where the attempts of a run got reset.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ResetAnalysis {
+    /// The amount of attempts that were analyzed.
+    pub attempts: u32,
+    /// The amount of attempts that finished the run.
+    pub finished: u32,
+    pub segments: Vec<SegmentResets>,
+}
+
+/// Describes how many attempts reached and got reset in a single segment.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SegmentResets {
+    pub name: String,
+    /// The amount of attempts that reached the segment.
+    pub reached: u32,
+    /// The amount of attempts that got reset during the segment.
+    pub resets: u32,
+    /// The share of all the attempts that reached the segment.
+    pub survival: f64,
+    /// The share of the attempts reaching the segment that got reset in it.
+    pub reset_probability: f64,
+}
+
+impl ResetAnalysis {
+    pub fn write_json<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        to_writer(writer, self)
+    }
+}
+
+/// Gets the amount of segments an attempt completed before it got reset.
+/// Skipped splits count as completed.
+/// - `run`: The run the attempt belongs to.
+/// - `attempt`: The attempt to check.
+///
+/// Returns the amount of completed segments, which is the length of the run
+/// if the attempt finished.
+pub fn progress(run: &Run, attempt: &Attempt) -> usize {
+    run.segments()
+        .iter()
+        .take_while(|s| s.segment_history().get(attempt.index()).is_some())
+        .count()
+}
+
+/// Analyzes where the attempts of the run got reset.
+/// - `run`: The run to analyze.
+/// - `filter`: Limits the attempts that get analyzed.
+///
+/// Returns a per segment survival curve and reset probabilities.
+pub fn calculate(run: &Run, filter: &Filter) -> ResetAnalysis {
+    let mut attempts = run.attempt_history()
+        .iter()
+        .filter(|a| {
+            filter.started_after.map_or(true, |after| {
+                a.started().map_or(false, |started| started.time >= after)
+            })
+                && filter.started_before.map_or(true, |before| {
+                    a.started().map_or(false, |started| started.time < before)
+                })
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(last_attempts) = filter.last_attempts {
+        attempts.sort_by_key(|a| a.index());
+        let skip = attempts.len().saturating_sub(last_attempts);
+        attempts.drain(..skip);
+    }
+
+    let mut reached = vec![0; run.len() + 1];
+    for attempt in &attempts {
+        for count in &mut reached[..progress(run, attempt) + 1] {
+            *count += 1;
+        }
+    }
+
+    let total = attempts.len() as u32;
+    let segments = run.segments()
+        .iter()
+        .zip(reached.windows(2))
+        .map(|(segment, counts)| {
+            let (reached, completed) = (counts[0], counts[1]);
+            SegmentResets {
+                name: segment.name().to_string(),
+                reached,
+                resets: reached - completed,
+                survival: ratio(reached, total),
+                reset_probability: ratio(reached - completed, reached),
+            }
+        })
+        .collect();
+
+    ResetAnalysis {
+        attempts: total,
+        finished: reached[run.len()],
+        segments,
+    }
+}
+
+fn ratio(count: u32, total: u32) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        f64::from(count) / f64::from(total)
+    }
+}
@@ -0,0 +1,92 @@
+use {DateTime, TimeSpan, Timer, TimerPhase, Utc};
+
+/// Describes the current session, which is the most recent series of attempts
+/// that aren't separated by breaks longer than the inactivity gap.
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct Session {
+    /// The date the first attempt of the session started at. This is None if
+    /// there's no ongoing session.
+    pub started: Option<DateTime<Utc>>,
+    /// The amount of attempts started during the session, including the
+    /// current one.
+    pub attempts: u32,
+    /// The amount of attempts that finished the run during the session.
+    pub finished: u32,
+    /// The time since the session started.
+    pub duration: TimeSpan,
+    /// The fastest final time of the session.
+    pub best_time: Option<TimeSpan>,
+}
+
+struct Activity {
+    started: DateTime<Utc>,
+    ended: DateTime<Utc>,
+    finished: bool,
+    final_time: Option<TimeSpan>,
+}
+
+/// Calculates the statistics of the current session. Sessions are derived
+/// from the start and end dates of the attempts in the attempt history, with
+/// the current attempt of the timer being part of the latest session.
+/// Attempts without a start date can't be placed and are ignored.
+/// - `timer`: The timer to calculate the session of.
+/// - `inactivity_gap`: The length of a break that ends a session.
+/// - `now`: The current date.
+///
+/// Returns the current session. If the last activity is longer ago than the
+/// inactivity gap, there's no ongoing session and an empty one is returned.
+pub fn calculate(timer: &Timer, inactivity_gap: TimeSpan, now: DateTime<Utc>) -> Session {
+    let method = timer.current_timing_method();
+    let run = timer.run();
+
+    let mut activities = run.attempt_history()
+        .iter()
+        .filter_map(|attempt| {
+            let started = attempt.started()?.time;
+            let ended = attempt.ended().map_or(started, |ended| ended.time);
+            Some(Activity {
+                started,
+                ended,
+                finished: attempt.time().real_time.is_some(),
+                final_time: attempt.time()[method],
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(started) = timer.attempt_started() {
+        let finished = timer.current_phase() == TimerPhase::Ended;
+        activities.push(Activity {
+            started: started.time,
+            ended: now,
+            finished,
+            final_time: if finished {
+                run.segments().last().and_then(|s| s.split_time()[method])
+            } else {
+                None
+            },
+        });
+    }
+
+    activities.sort_by_key(|a| a.started);
+
+    let gap = inactivity_gap.to_duration();
+    let is_ongoing = activities.last().map_or(false, |a| now - a.ended <= gap);
+    if !is_ongoing {
+        return Session::default();
+    }
+
+    let session_start = activities
+        .windows(2)
+        .rposition(|pair| pair[1].started - pair[0].ended > gap)
+        .map_or(0, |index| index + 1);
+    let session = &activities[session_start..];
+    let started = session[0].started;
+
+    Session {
+        started: Some(started),
+        attempts: session.len() as u32,
+        finished: session.iter().filter(|a| a.finished).count() as u32,
+        duration: (now - started).into(),
+        best_time: session.iter().filter_map(|a| a.final_time).min(),
+    }
+}
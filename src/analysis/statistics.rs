@@ -0,0 +1,202 @@
+use {Run, TimeSpan, TimingMethod};
+
+/// Describes the descriptive statistics of a single segment for a single
+/// timing method. Only actual attempts are considered, so the history that got
+/// imported from older Personal Bests doesn't influence these values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SegmentStatistics {
+    /// The amount of attempts that reached the segment.
+    pub attempts: u32,
+    /// The amount of attempts that completed the segment, including the ones
+    /// that skipped the split.
+    pub completions: u32,
+    /// The amount of attempts that got reset during the segment.
+    pub resets: u32,
+    pub mean: Option<TimeSpan>,
+    pub median: Option<TimeSpan>,
+    pub standard_deviation: Option<TimeSpan>,
+    pub best: Option<TimeSpan>,
+    pub worst: Option<TimeSpan>,
+    /// A value between 0 and 1 describing how consistent the segment is. It is
+    /// 1 minus the coefficient of variation of the segment times, so 1 means
+    /// that every attempt got the exact same time.
+    pub consistency: Option<f64>,
+    /// The amount of times a new Best Segment got achieved.
+    pub gold_count: u32,
+    /// The index of the attempt that achieved the most recent Best Segment.
+    pub last_gold_attempt: Option<i32>,
+}
+
+/// Gets the segment times of actual attempts for the segment provided, sorted
+/// by their attempt index. Times of attempts that skipped the previous split
+/// are not included, as they don't only cover this segment.
+/// - `run`: The run to get the segment times from.
+/// - `segment_index`: The index of the segment.
+/// - `method`: The timing method to use.
+///
+/// Returns a list of the attempt indices along with their segment times.
+pub fn segment_times(
+    run: &Run,
+    segment_index: usize,
+    method: TimingMethod,
+) -> Vec<(i32, TimeSpan)> {
+    let mut times = run.segment(segment_index)
+        .segment_history()
+        .iter_actual_runs()
+        .filter(|&&(index, _)| {
+            segment_index == 0
+                || run.segment(segment_index - 1)
+                    .segment_history()
+                    .get(index)
+                    .map_or(false, |t| t[method].is_some())
+        })
+        .filter_map(|&(index, time)| time[method].map(|t| (index, t)))
+        .collect::<Vec<_>>();
+
+    times.sort_by_key(|&(index, _)| index);
+
+    times
+}
+
+/// Determines which of the segment times achieved a new Best Segment, which is
+/// the case if it was the fastest time of the segment at the time it got
+/// achieved. The history that got imported from older Personal Bests counts
+/// as the initial Best Segment.
+/// - `run`: The run the segment times belong to.
+/// - `segment_index`: The index of the segment.
+/// - `method`: The timing method to use.
+/// - `times`: The segment times as returned by `segment_times`.
+///
+/// Returns whether each of the segment times is a Best Segment.
+pub fn golds(
+    run: &Run,
+    segment_index: usize,
+    method: TimingMethod,
+    times: &[(i32, TimeSpan)],
+) -> Vec<bool> {
+    let mut best = run.segment(segment_index)
+        .segment_history()
+        .iter()
+        .filter(|&&(index, _)| index < 1)
+        .filter_map(|&(_, time)| time[method])
+        .min();
+
+    times
+        .iter()
+        .map(|&(_, time)| {
+            let is_gold = best.map_or(true, |b| time < b);
+            if is_gold {
+                best = Some(time);
+            }
+            is_gold
+        })
+        .collect()
+}
+
+/// Calculates the statistics of a single segment.
+/// - `run`: The run to calculate the statistics for.
+/// - `segment_index`: The index of the segment.
+/// - `method`: The timing method to use.
+///
+/// Returns the statistics of the segment.
+pub fn calculate(run: &Run, segment_index: usize, method: TimingMethod) -> SegmentStatistics {
+    let mut attempts = 0;
+    let mut completions = 0;
+    for attempt in run.attempt_history() {
+        let index = attempt.index();
+        let reached = segment_index == 0
+            || run.segment(segment_index - 1)
+                .segment_history()
+                .get(index)
+                .is_some();
+        if reached {
+            attempts += 1;
+            if run.segment(segment_index)
+                .segment_history()
+                .get(index)
+                .is_some()
+            {
+                completions += 1;
+            }
+        }
+    }
+
+    let times = segment_times(run, segment_index, method);
+
+    let mut gold_count = 0;
+    let mut last_gold_attempt = None;
+    for (&(index, _), is_gold) in times.iter().zip(golds(run, segment_index, method, &times)) {
+        if is_gold {
+            gold_count += 1;
+            last_gold_attempt = Some(index);
+        }
+    }
+
+    let mut sorted = times.iter().map(|&(_, t)| t).collect::<Vec<_>>();
+    sorted.sort();
+
+    let mean = mean(&sorted);
+    let standard_deviation = standard_deviation(&sorted);
+    let consistency = catch! {
+        let mean = mean?.total_seconds();
+        let standard_deviation = standard_deviation?.total_seconds();
+        if mean > 0.0 {
+            (1.0 - standard_deviation / mean).max(0.0)
+        } else {
+            1.0
+        }
+    };
+
+    SegmentStatistics {
+        attempts,
+        completions,
+        resets: attempts - completions,
+        mean,
+        median: median(&sorted),
+        standard_deviation,
+        best: sorted.first().cloned(),
+        worst: sorted.last().cloned(),
+        consistency,
+        gold_count,
+        last_gold_attempt,
+    }
+}
+
+/// Calculates the statistics of all the segments of the run.
+pub fn calculate_all(run: &Run, method: TimingMethod) -> Vec<SegmentStatistics> {
+    (0..run.len()).map(|i| calculate(run, i, method)).collect()
+}
+
+/// Calculates the mean of the times provided.
+pub fn mean(times: &[TimeSpan]) -> Option<TimeSpan> {
+    if times.is_empty() {
+        return None;
+    }
+    let sum = times.iter().map(TimeSpan::total_seconds).sum::<f64>();
+    Some(TimeSpan::from_seconds(sum / times.len() as f64))
+}
+
+/// Calculates the median of the times provided, which need to be sorted.
+pub fn median(sorted_times: &[TimeSpan]) -> Option<TimeSpan> {
+    let len = sorted_times.len();
+    if len == 0 {
+        None
+    } else if len % 2 == 1 {
+        Some(sorted_times[len / 2])
+    } else {
+        let (a, b) = (sorted_times[len / 2 - 1], sorted_times[len / 2]);
+        Some(TimeSpan::from_seconds(
+            (a.total_seconds() + b.total_seconds()) / 2.0,
+        ))
+    }
+}
+
+/// Calculates the population standard deviation of the times provided.
+pub fn standard_deviation(times: &[TimeSpan]) -> Option<TimeSpan> {
+    let mean = mean(times)?.total_seconds();
+    let variance = times
+        .iter()
+        .map(|t| (t.total_seconds() - mean).powi(2))
+        .sum::<f64>() / times.len() as f64;
+    Some(TimeSpan::from_seconds(variance.sqrt()))
+}
@@ -1 +1,5 @@
 mod empty_run;
+mod statistics;
+mod resets;
+mod pb_chance;
+mod session;
@@ -0,0 +1,56 @@
+use {Run, Segment, Time, TimeSpan, Timer};
+use super::super::pb_chance::{calculate, Rng};
+
+fn timer(histories: &[&[f64]]) -> Timer {
+    let mut run = Run::new();
+    for (index, history) in histories.iter().enumerate() {
+        let mut segment = Segment::new(index.to_string());
+        for (attempt, &time) in history.iter().enumerate() {
+            let time = Time::new().with_real_time(Some(TimeSpan::from_seconds(time)));
+            segment
+                .segment_history_mut()
+                .insert(attempt as i32 + 1, time);
+        }
+        run.push_segment(segment);
+    }
+    Timer::new(run).unwrap()
+}
+
+fn seconds(seconds: f64) -> TimeSpan {
+    TimeSpan::from_seconds(seconds)
+}
+
+#[test]
+fn constant_segments() {
+    let timer = timer(&[&[10.0, 10.0], &[20.0], &[30.0, 30.0, 30.0]]);
+
+    let estimate = calculate(&timer, seconds(61.0), 100, &mut Rng::with_seed(1)).unwrap();
+    assert_eq!(estimate.probability, 1.0);
+    assert_eq!(estimate.expected_time, seconds(60.0));
+    assert_eq!(estimate.time_interval, (seconds(60.0), seconds(60.0)));
+
+    let estimate = calculate(&timer, seconds(60.0), 100, &mut Rng::with_seed(1)).unwrap();
+    assert_eq!(estimate.probability, 0.0);
+    assert!(estimate.probability_interval.1 > 0.0);
+}
+
+#[test]
+fn reproducible() {
+    let timer = timer(&[&[10.0, 20.0], &[10.0, 20.0]]);
+
+    let first = calculate(&timer, seconds(35.0), 1000, &mut Rng::with_seed(42)).unwrap();
+    let second = calculate(&timer, seconds(35.0), 1000, &mut Rng::with_seed(42)).unwrap();
+    assert_eq!(first, second);
+
+    // Only both segments being slow leads to a time above the target.
+    assert!((first.probability - 0.75).abs() < 0.05);
+    assert!(first.probability_interval.0 < first.probability);
+    assert!(first.probability_interval.1 > first.probability);
+    assert_eq!(first.time_interval, (seconds(20.0), seconds(40.0)));
+}
+
+#[test]
+fn missing_history() {
+    let timer = timer(&[&[10.0], &[]]);
+    assert!(calculate(&timer, seconds(30.0), 100, &mut Rng::with_seed(0)).is_none());
+}
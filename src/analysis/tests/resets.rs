@@ -0,0 +1,79 @@
+use {AtomicDateTime, DateTime, Run, Segment, Time, TimeSpan, Utc};
+use super::super::resets::{calculate, Filter};
+
+fn date(day: u32, hour: u32) -> DateTime<Utc> {
+    format!("2017-10-{:02}T{:02}:00:00Z", day, hour)
+        .parse()
+        .unwrap()
+}
+
+fn run() -> Run {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    run.push_segment(Segment::new("B"));
+    run.push_segment(Segment::new("C"));
+
+    // Attempt 1 finishes, attempt 2 resets in C, attempt 3 in B, attempt 4
+    // skips A and finishes, attempt 5 resets in A.
+    let progress = [3, 2, 1, 3, 0];
+
+    for (index, &progress) in progress.iter().enumerate() {
+        let day = index as u32 + 1;
+        let started = AtomicDateTime::new(date(day, 12), false);
+        let index = index as i32 + 1;
+        run.add_attempt_with_index(Time::default(), index, Some(started), None, None);
+        for (segment_index, segment) in run.segments_mut()[..progress].iter_mut().enumerate() {
+            let time = if index == 4 && segment_index == 0 {
+                None
+            } else {
+                Some(TimeSpan::from_seconds(10.0))
+            };
+            segment
+                .segment_history_mut()
+                .insert(index, Time::new().with_real_time(time));
+        }
+    }
+
+    run
+}
+
+#[test]
+fn all_attempts() {
+    let analysis = calculate(&run(), &Filter::default());
+    assert_eq!(analysis.attempts, 5);
+    assert_eq!(analysis.finished, 2);
+
+    let reached = analysis.segments.iter().map(|s| s.reached).collect::<Vec<_>>();
+    assert_eq!(reached, [5, 4, 3]);
+    let resets = analysis.segments.iter().map(|s| s.resets).collect::<Vec<_>>();
+    assert_eq!(resets, [1, 1, 1]);
+
+    assert_eq!(analysis.segments[1].survival, 0.8);
+    assert_eq!(analysis.segments[2].reset_probability, 1.0 / 3.0);
+}
+
+#[test]
+fn filtered_attempts() {
+    let run = run();
+
+    let analysis = calculate(
+        &run,
+        &Filter {
+            last_attempts: Some(2),
+            ..Filter::default()
+        },
+    );
+    assert_eq!(analysis.attempts, 2);
+    assert_eq!(analysis.finished, 1);
+
+    let analysis = calculate(
+        &run,
+        &Filter {
+            started_after: Some(date(2, 0)),
+            started_before: Some(date(4, 0)),
+            ..Filter::default()
+        },
+    );
+    let resets = analysis.segments.iter().map(|s| s.resets).collect::<Vec<_>>();
+    assert_eq!(resets, [0, 1, 1]);
+}
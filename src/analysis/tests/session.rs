@@ -0,0 +1,78 @@
+use {AtomicDateTime, DateTime, Run, Segment, Time, TimeSpan, Timer, Utc};
+use super::super::session::calculate;
+
+fn date(hour: u32, minute: u32) -> DateTime<Utc> {
+    format!("2017-10-01T{:02}:{:02}:00Z", hour, minute)
+        .parse()
+        .unwrap()
+}
+
+fn minutes(minutes: f64) -> TimeSpan {
+    TimeSpan::from_seconds(60.0 * minutes)
+}
+
+fn timer() -> Timer {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+
+    // Two attempts in the morning, then three in the evening, two of which
+    // finish the run.
+    let attempts = [
+        ((9, 0), (9, 20), Some(20.0)),
+        ((9, 25), (9, 30), None),
+        ((18, 0), (18, 15), Some(15.0)),
+        ((18, 20), (18, 22), None),
+        ((18, 30), (18, 42), Some(12.0)),
+    ];
+
+    for (index, &(started, ended, time)) in attempts.iter().enumerate() {
+        let started = AtomicDateTime::new(date(started.0, started.1), false);
+        let ended = AtomicDateTime::new(date(ended.0, ended.1), false);
+        run.add_attempt_with_index(
+            Time::new().with_real_time(time.map(minutes)),
+            index as i32 + 1,
+            Some(started),
+            Some(ended),
+            None,
+        );
+    }
+
+    Timer::new(run).unwrap()
+}
+
+#[test]
+fn latest_session() {
+    let session = calculate(&timer(), minutes(60.0), date(19, 0));
+    assert_eq!(session.started, Some(date(18, 0)));
+    assert_eq!(session.attempts, 3);
+    assert_eq!(session.finished, 2);
+    assert_eq!(session.duration, minutes(60.0));
+    assert_eq!(session.best_time, Some(minutes(12.0)));
+}
+
+#[test]
+fn longer_gap_merges_sessions() {
+    let session = calculate(&timer(), minutes(600.0), date(19, 0));
+    assert_eq!(session.started, Some(date(9, 0)));
+    assert_eq!(session.attempts, 5);
+    assert_eq!(session.finished, 3);
+}
+
+#[test]
+fn session_ends_after_inactivity() {
+    let session = calculate(&timer(), minutes(60.0), date(20, 0));
+    assert_eq!(session.started, None);
+    assert_eq!(session.attempts, 0);
+}
+
+#[test]
+fn current_attempt_is_part_of_the_session() {
+    let mut timer = timer();
+    timer.start();
+    let now = timer.attempt_started().unwrap().time;
+
+    let session = calculate(&timer, minutes(60.0), now);
+    assert_eq!(session.started, Some(now));
+    assert_eq!(session.attempts, 1);
+    assert_eq!(session.finished, 0);
+}
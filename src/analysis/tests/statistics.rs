@@ -0,0 +1,77 @@
+use {Run, Segment, Time, TimeSpan, TimingMethod};
+use super::super::statistics::{calculate, calculate_all, golds, segment_times};
+
+fn seconds(seconds: f64) -> Option<TimeSpan> {
+    Some(TimeSpan::from_seconds(seconds))
+}
+
+fn run() -> Run {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    run.push_segment(Segment::new("B"));
+    run.push_segment(Segment::new("C"));
+
+    let attempts: &[&[Option<f64>]] = &[
+        &[Some(10.0), Some(20.0), Some(30.0)],
+        &[Some(8.0), Some(25.0)],
+        &[Some(12.0)],
+        &[None, Some(40.0), Some(30.0)],
+    ];
+
+    for (index, times) in attempts.iter().enumerate() {
+        let index = index as i32 + 1;
+        run.add_attempt_with_index(Time::default(), index, None, None, None);
+        for (segment, &time) in run.segments_mut().iter_mut().zip(times.iter()) {
+            let time = Time::new().with_real_time(time.map(TimeSpan::from_seconds));
+            segment.segment_history_mut().insert(index, time);
+        }
+    }
+
+    run
+}
+
+#[test]
+fn counts() {
+    let statistics = calculate_all(&run(), TimingMethod::RealTime);
+    let counts = statistics
+        .iter()
+        .map(|s| (s.attempts, s.completions, s.resets))
+        .collect::<Vec<_>>();
+    assert_eq!(counts, [(4, 4, 0), (4, 3, 1), (3, 2, 1)]);
+}
+
+#[test]
+fn times() {
+    let run = run();
+
+    let first = calculate(&run, 0, TimingMethod::RealTime);
+    assert_eq!(first.mean, seconds(10.0));
+    assert_eq!(first.median, seconds(10.0));
+    assert_eq!(first.best, seconds(8.0));
+    assert_eq!(first.worst, seconds(12.0));
+    assert_eq!(first.standard_deviation, seconds((8.0f64 / 3.0).sqrt()));
+    assert_eq!(first.gold_count, 2);
+    assert_eq!(first.last_gold_attempt, Some(2));
+
+    // The combined segment time of the attempt that skipped the first split
+    // is ignored.
+    let second = calculate(&run, 1, TimingMethod::RealTime);
+    assert_eq!(second.median, seconds(22.5));
+    assert_eq!(second.worst, seconds(25.0));
+
+    let last = calculate(&run, 2, TimingMethod::RealTime);
+    assert_eq!(last.consistency, Some(1.0));
+    assert_eq!(last.gold_count, 1);
+    assert_eq!(last.last_gold_attempt, Some(1));
+}
+
+#[test]
+fn imported_history_is_the_initial_gold() {
+    let mut run = run();
+    let imported = Time::new().with_real_time(seconds(9.0));
+    run.segment_mut(0).segment_history_mut().insert(0, imported);
+
+    let method = TimingMethod::RealTime;
+    let times = segment_times(&run, 0, method);
+    assert_eq!(golds(&run, 0, method, &times), [false, true, false]);
+}
@@ -0,0 +1,218 @@
+use {comparison, GeneralLayoutSettings, Segment, TimeSpan, Timer, TimingMethod};
+use serde_json::{to_writer, Result};
+use std::io::Write;
+use std::borrow::Cow;
+use time::formatter::{Accuracy, Delta, Regular, TimeFormatter};
+use settings::{Color, Field, Gradient, SemanticColor, SettingsDescription, Value};
+use super::DEFAULT_INFO_TEXT_GRADIENT;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub background: Gradient,
+    /// The first comparison. The current comparison is used if it isn't set.
+    pub comparison1: Option<String>,
+    /// The comparison the first comparison gets compared against. The Best
+    /// Segments are used if it isn't set.
+    pub comparison2: Option<String>,
+    pub label_color: Option<Color>,
+    pub value_color: Option<Color>,
+    pub drop_decimals: bool,
+    pub accuracy: Accuracy,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: DEFAULT_INFO_TEXT_GRADIENT,
+            comparison1: None,
+            comparison2: None,
+            label_color: None,
+            value_color: None,
+            drop_decimals: true,
+            accuracy: Accuracy::Tenths,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct State {
+    pub background: Gradient,
+    pub label_color: Option<Color>,
+    pub value_color: Option<Color>,
+    pub comparison1: String,
+    pub comparison2: String,
+    pub rows: Vec<RowState>,
+}
+
+/// The state of a single segment. The differences describe how much slower
+/// the first comparison is than the second one.
+#[derive(Serialize, Deserialize)]
+pub struct RowState {
+    pub name: String,
+    pub segment_time1: String,
+    pub segment_time2: String,
+    pub segment_difference: String,
+    pub segment_semantic_color: SemanticColor,
+    pub segment_visual_color: Color,
+    pub total_difference: String,
+    pub total_semantic_color: SemanticColor,
+    pub total_visual_color: Color,
+}
+
+impl State {
+    pub fn write_json<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        to_writer(writer, self)
+    }
+}
+
+impl Component {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn with_settings(settings: Settings) -> Self {
+        Self {
+            settings,
+            ..Default::default()
+        }
+    }
+
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    pub fn name(&self) -> Cow<str> {
+        match (&self.settings.comparison1, &self.settings.comparison2) {
+            (&None, &None) => "Comparison Table".into(),
+            (comparison1, comparison2) => format!(
+                "Comparison Table ({} vs {})",
+                comparison1
+                    .as_ref()
+                    .map_or("Current", |c| comparison::shorten(c)),
+                comparison2
+                    .as_ref()
+                    .map_or(comparison::best_segments::SHORT_NAME, |c| {
+                        comparison::shorten(c)
+                    }),
+            ).into(),
+        }
+    }
+
+    pub fn state(&self, timer: &Timer, layout_settings: &GeneralLayoutSettings) -> State {
+        let comparison1 = comparison::resolve(&self.settings.comparison1, timer);
+        let comparison1 = comparison::or_current(comparison1, timer);
+        let comparison2 = comparison::resolve(&self.settings.comparison2, timer)
+            .unwrap_or(comparison::best_segments::NAME);
+
+        let method = timer.current_timing_method();
+        let segments = timer.run().segments();
+        let time_formatter = Regular::with_accuracy(self.settings.accuracy);
+        let delta_formatter = Delta::custom(self.settings.drop_decimals, self.settings.accuracy);
+
+        let rows = segments
+            .iter()
+            .enumerate()
+            .map(|(index, segment)| {
+                let segment_time1 = segment_time(segments, index, comparison1, method);
+                let segment_time2 = segment_time(segments, index, comparison2, method);
+                let segment_difference = catch! { segment_time1? - segment_time2? };
+                let total_difference = catch! {
+                    segment.comparison(comparison1)[method]?
+                        - segment.comparison(comparison2)[method]?
+                };
+                let segment_semantic_color = difference_color(segment_difference);
+                let total_semantic_color = difference_color(total_difference);
+
+                RowState {
+                    name: segment.name().to_string(),
+                    segment_time1: time_formatter.format(segment_time1).to_string(),
+                    segment_time2: time_formatter.format(segment_time2).to_string(),
+                    segment_difference: delta_formatter.format(segment_difference).to_string(),
+                    segment_semantic_color,
+                    segment_visual_color: segment_semantic_color.visualize(layout_settings),
+                    total_difference: delta_formatter.format(total_difference).to_string(),
+                    total_semantic_color,
+                    total_visual_color: total_semantic_color.visualize(layout_settings),
+                }
+            })
+            .collect();
+
+        State {
+            background: self.settings.background,
+            label_color: self.settings.label_color,
+            value_color: self.settings.value_color,
+            comparison1: comparison1.to_string(),
+            comparison2: comparison2.to_string(),
+            rows,
+        }
+    }
+
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new(
+                "Comparison 1".into(),
+                self.settings.comparison1.clone().into(),
+            ),
+            Field::new(
+                "Comparison 2".into(),
+                self.settings.comparison2.clone().into(),
+            ),
+            Field::new("Label Color".into(), self.settings.label_color.into()),
+            Field::new("Value Color".into(), self.settings.value_color.into()),
+            Field::new("Drop Decimals".into(), self.settings.drop_decimals.into()),
+            Field::new("Accuracy".into(), self.settings.accuracy.into()),
+        ])
+    }
+
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.comparison1 = value.into(),
+            2 => self.settings.comparison2 = value.into(),
+            3 => self.settings.label_color = value.into(),
+            4 => self.settings.value_color = value.into(),
+            5 => self.settings.drop_decimals = value.into(),
+            6 => self.settings.accuracy = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
+
+fn segment_time(
+    segments: &[Segment],
+    index: usize,
+    comparison: &str,
+    method: TimingMethod,
+) -> Option<TimeSpan> {
+    let split_time = segments[index].comparison(comparison)[method]?;
+    if index == 0 {
+        Some(split_time)
+    } else {
+        Some(split_time - segments[index - 1].comparison(comparison)[method]?)
+    }
+}
+
+fn difference_color(difference: Option<TimeSpan>) -> SemanticColor {
+    match difference {
+        Some(difference) if difference < TimeSpan::zero() => SemanticColor::AheadGainingTime,
+        Some(difference) if difference > TimeSpan::zero() => SemanticColor::BehindLosingTime,
+        _ => SemanticColor::Default,
+    }
+}
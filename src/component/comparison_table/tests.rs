@@ -0,0 +1,53 @@
+use {GeneralLayoutSettings, Timer};
+use settings::SemanticColor;
+use tests_helper::timer_with_splits;
+use super::Component;
+
+fn timer() -> Timer {
+    timer_with_splits(&[("A", 10.0, 9.0), ("B", 25.0, 15.0), ("C", 30.0, 5.0)])
+}
+
+#[test]
+fn personal_best_versus_best_segments() {
+    let component = Component::new();
+    assert_eq!(component.name(), "Comparison Table");
+
+    let state = component.state(&timer(), &GeneralLayoutSettings::default());
+    assert_eq!(state.comparison1, "Personal Best");
+    assert_eq!(state.comparison2, "Best Segments");
+
+    let rows = state
+        .rows
+        .iter()
+        .map(|r| {
+            (
+                r.segment_time1.as_str(),
+                r.segment_time2.as_str(),
+                r.segment_difference.as_str(),
+                r.total_difference.as_str(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        rows,
+        [
+            ("0:10.0", "0:09.0", "+1.0", "+1.0"),
+            ("0:15.0", "0:15.0", "+0.0", "+1.0"),
+            ("0:05.0", "0:05.0", "+0.0", "+1.0"),
+        ]
+    );
+    assert_eq!(state.rows[0].segment_semantic_color, SemanticColor::BehindLosingTime);
+    assert_eq!(state.rows[1].segment_semantic_color, SemanticColor::Default);
+}
+
+#[test]
+fn swapped_comparisons() {
+    let mut component = Component::new();
+    component.settings_mut().comparison1 = Some(String::from("Best Segments"));
+    component.settings_mut().comparison2 = Some(String::from("Personal Best"));
+    assert_eq!(component.name(), "Comparison Table (Best vs PB)");
+
+    let state = component.state(&timer(), &GeneralLayoutSettings::default());
+    assert_eq!(state.rows[2].total_difference, "−1.0");
+    assert_eq!(state.rows[2].total_semantic_color, SemanticColor::AheadGainingTime);
+}
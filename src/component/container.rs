@@ -0,0 +1,207 @@
+use Timer;
+use serde_json::{to_writer, Result};
+use std::io::Write;
+use std::borrow::Cow;
+use layout::{Component as LayoutComponent, ComponentSettings, ComponentState, GeneralSettings,
+             Visibility};
+use settings::{Field, LayoutDirection, SettingsDescription, Value};
+
+/// The container component groups other components in a row or a column. In a
+/// row, every child gets a share of the container's width that is relative to
+/// its size. In a column, the children are stacked on top of each other and
+/// keep their own heights.
+#[derive(Clone)]
+pub struct Component {
+    direction: LayoutDirection,
+    pub children: Vec<Child>,
+}
+
+/// A component held by a container, together with its relative size and
+/// when it is visible.
+#[derive(Clone)]
+pub struct Child {
+    pub component: LayoutComponent,
+    pub size: f32,
+    pub visibility: Visibility,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub direction: LayoutDirection,
+    pub children: Vec<ChildSettings>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ChildSettings {
+    pub settings: ComponentSettings,
+    pub size: f32,
+    #[serde(default)]
+    pub visibility: Visibility,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            direction: LayoutDirection::Horizontal,
+            children: Vec::new(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct State {
+    pub direction: LayoutDirection,
+    pub children: Vec<ChildState>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ChildState {
+    pub size: f32,
+    #[serde(with = "::layout::with_arrangement")]
+    pub state: ComponentState,
+}
+
+impl State {
+    pub fn write_json<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        to_writer(writer, self)
+    }
+}
+
+impl Child {
+    pub fn new<C: Into<LayoutComponent>>(component: C) -> Self {
+        Self {
+            component: component.into(),
+            size: 1.0,
+            visibility: Visibility::default(),
+        }
+    }
+}
+
+impl Default for Component {
+    fn default() -> Self {
+        Self::with_settings(Default::default())
+    }
+}
+
+impl Component {
+    /// Creates an empty container that places its children next to each
+    /// other.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn with_direction(direction: LayoutDirection) -> Self {
+        Self {
+            direction,
+            children: Vec::new(),
+        }
+    }
+
+    pub fn with_settings(settings: Settings) -> Self {
+        Self {
+            direction: settings.direction,
+            children: settings
+                .children
+                .into_iter()
+                .map(|child| Child {
+                    component: child.settings.into(),
+                    size: child.size,
+                    visibility: child.visibility,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn settings(&self) -> Settings {
+        Settings {
+            direction: self.direction,
+            children: self.children
+                .iter()
+                .map(|child| ChildSettings {
+                    settings: child.component.settings(),
+                    size: child.size,
+                    visibility: child.visibility.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    pub fn direction(&self) -> LayoutDirection {
+        self.direction
+    }
+
+    pub fn set_direction(&mut self, direction: LayoutDirection) {
+        self.direction = direction;
+    }
+
+    /// Adds the component as the last child with a relative size of 1.
+    pub fn push<C: Into<LayoutComponent>>(&mut self, component: C) {
+        self.children.push(Child::new(component));
+    }
+
+    pub fn name(&self) -> Cow<str> {
+        match self.direction {
+            LayoutDirection::Vertical => "Column".into(),
+            LayoutDirection::Horizontal => "Row".into(),
+        }
+    }
+
+    pub fn state(&mut self, timer: &Timer, layout_settings: &GeneralSettings) -> State {
+        State {
+            direction: self.direction,
+            children: self.children
+                .iter_mut()
+                .filter(|child| child.visibility.is_visible(timer))
+                .map(|child| ChildState {
+                    size: child.size,
+                    state: child.component.state(timer, layout_settings),
+                })
+                .collect(),
+        }
+    }
+
+    pub fn scroll_up(&mut self) {
+        for child in &mut self.children {
+            child.component.scroll_up();
+        }
+    }
+
+    pub fn scroll_down(&mut self) {
+        for child in &mut self.children {
+            child.component.scroll_down();
+        }
+    }
+
+    pub fn remount(&mut self) {
+        for child in &mut self.children {
+            child.component.remount();
+        }
+    }
+
+    /// The first setting is the direction, followed by the relative size of
+    /// each of the children.
+    pub fn settings_description(&self) -> SettingsDescription {
+        let mut fields = vec![Field::new("Direction".into(), self.direction.into())];
+        fields.extend(self.children.iter().map(|child| {
+            Field::new(
+                format!("Size of {}", child.component.name()),
+                (child.size as f64).into(),
+            )
+        }));
+        SettingsDescription::with_fields(fields)
+    }
+
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.direction = value.into(),
+            _ => match self.children.get_mut(index - 1) {
+                Some(child) => child.size = value.into_float().unwrap().max(0.0) as f32,
+                None => panic!("Unsupported Setting Index"),
+            },
+        }
+    }
+}
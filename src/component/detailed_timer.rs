@@ -6,7 +6,7 @@ use comparison::{self, best_segments, none};
 use serde_json::{to_writer, Result};
 use std::io::Write;
 use std::borrow::Cow;
-use settings::{Field, Gradient, SemanticColor, SettingsDescription, Value};
+use settings::{Field, Font, Gradient, SemanticColor, SettingsDescription, Value};
 
 #[derive(Default, Clone)]
 pub struct Component {
@@ -192,6 +192,7 @@ impl Component {
                 top_color,
                 bottom_color,
                 height: self.settings.segment_timer.height,
+                timer_font: self.settings.segment_timer.timer_font.clone(),
             },
             None => timer::State {
                 background,
@@ -201,6 +202,7 @@ impl Component {
                 top_color,
                 bottom_color,
                 height: self.settings.segment_timer.height,
+                timer_font: self.settings.segment_timer.timer_font.clone(),
             },
         };
 
@@ -298,6 +300,14 @@ impl Component {
                 self.settings.show_segment_name.into(),
             ),
             Field::new("Display Icon".into(), self.settings.display_icon.into()),
+            Field::new(
+                "Timer Font".into(),
+                self.settings.timer.timer_font.clone().into(),
+            ),
+            Field::new(
+                "Segment Timer Font".into(),
+                self.settings.segment_timer.timer_font.clone().into(),
+            ),
         ])
     }
 
@@ -328,6 +338,12 @@ impl Component {
             10 => self.settings.segment_timer.accuracy = value.into(),
             11 => self.settings.show_segment_name = value.into(),
             12 => self.settings.display_icon = value.into(),
+            13 => {
+                let value: Option<Font> = value.into();
+                self.settings.timer.timer_font = value.clone();
+                self.timer.settings_mut().timer_font = value;
+            }
+            14 => self.settings.segment_timer.timer_font = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
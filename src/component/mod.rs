@@ -1,18 +1,27 @@
 pub mod blank_space;
+pub mod comparison_table;
+pub mod container;
 pub mod current_comparison;
 pub mod current_pace;
 pub mod delta;
 pub mod detailed_timer;
 pub mod graph;
+pub mod pb_chance;
 pub mod possible_time_save;
 pub mod previous_segment;
+pub mod run_metadata;
+pub mod run_prediction;
+pub mod segment_history_graph;
+pub mod segment_statistics;
 pub mod separator;
+pub mod session_statistics;
 pub mod splits;
 pub mod sum_of_best;
 pub mod text;
 pub mod timer;
 pub mod title;
 pub mod total_playtime;
+pub mod wall_clock;
 
 use settings::{Color, Gradient};
 use palette::{Alpha, Rgb};
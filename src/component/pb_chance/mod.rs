@@ -0,0 +1,209 @@
+use {comparison, Timer, TimerPhase, TimingMethod};
+use analysis::pb_chance::{self, Rng};
+use analysis::statistics::segment_times;
+use serde_json::{to_writer, Result};
+use std::io::Write;
+use std::borrow::Cow;
+use time::formatter::Accuracy;
+use settings::{Color, Field, Gradient, SettingsDescription, Value};
+use super::DEFAULT_INFO_TEXT_GRADIENT;
+
+#[cfg(test)]
+mod tests;
+
+/// The amount of runs that get simulated for each estimate.
+const ITERATIONS: usize = 10_000;
+const SEED: u64 = 0x5EED;
+
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+    cache: Option<(CacheKey, Option<f64>)>,
+}
+
+/// Describes everything the simulation depends on. The estimate only gets
+/// recalculated when any of these change, which is on every split, whenever
+/// the current segment outlasts one of its historical times and whenever the
+/// times of the run change outside of the current attempt.
+#[derive(Clone, PartialEq)]
+struct CacheKey {
+    comparison: String,
+    method: TimingMethod,
+    phase: TimerPhase,
+    split_index: Option<usize>,
+    run_version: u64,
+    exceeded_times: usize,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub background: Gradient,
+    pub comparison_override: Option<String>,
+    pub label_color: Option<Color>,
+    pub value_color: Option<Color>,
+    pub accuracy: Accuracy,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: DEFAULT_INFO_TEXT_GRADIENT,
+            comparison_override: None,
+            label_color: None,
+            value_color: None,
+            accuracy: Accuracy::Tenths,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct State {
+    pub background: Gradient,
+    pub label_color: Option<Color>,
+    pub value_color: Option<Color>,
+    pub text: String,
+    pub chance: String,
+}
+
+impl State {
+    pub fn write_json<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        to_writer(writer, self)
+    }
+}
+
+impl Component {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn with_settings(settings: Settings) -> Self {
+        Self {
+            settings,
+            ..Default::default()
+        }
+    }
+
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    pub fn name(&self) -> Cow<str> {
+        self.text(
+            self.settings
+                .comparison_override
+                .as_ref()
+                .map(String::as_str),
+        )
+    }
+
+    fn text(&self, comparison: Option<&str>) -> Cow<str> {
+        match comparison {
+            None | Some(comparison::personal_best::NAME) => "PB Chance".into(),
+            Some(comparison) => {
+                format!("Chance to Beat {}", comparison::shorten(comparison)).into()
+            }
+        }
+    }
+
+    pub fn state(&mut self, timer: &Timer) -> State {
+        let comparison = comparison::resolve(&self.settings.comparison_override, timer);
+        let comparison = comparison::or_current(comparison, timer);
+        let text = self.text(Some(comparison)).into_owned();
+
+        let key = cache_key(timer, comparison);
+        let probability = match self.cache {
+            Some((ref cached_key, probability)) if *cached_key == key => probability,
+            _ => {
+                let mut rng = Rng::with_seed(SEED);
+                let probability =
+                    pb_chance::calculate_for_comparison(timer, comparison, ITERATIONS, &mut rng)
+                        .map(|e| e.probability);
+                self.cache = Some((key, probability));
+                probability
+            }
+        };
+
+        let chance = probability.map_or_else(
+            || String::from("-"),
+            |p| format_percentage(p, self.settings.accuracy),
+        );
+
+        State {
+            background: self.settings.background,
+            label_color: self.settings.label_color,
+            value_color: self.settings.value_color,
+            text,
+            chance,
+        }
+    }
+
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new(
+                "Comparison".into(),
+                self.settings.comparison_override.clone().into(),
+            ),
+            Field::new("Label Color".into(), self.settings.label_color.into()),
+            Field::new("Value Color".into(), self.settings.value_color.into()),
+            Field::new("Accuracy".into(), self.settings.accuracy.into()),
+        ])
+    }
+
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.comparison_override = value.into(),
+            2 => self.settings.label_color = value.into(),
+            3 => self.settings.value_color = value.into(),
+            4 => self.settings.accuracy = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
+
+fn cache_key(timer: &Timer, comparison: &str) -> CacheKey {
+    let method = timer.current_timing_method();
+    let phase = timer.current_phase();
+    let split_index = timer.current_split_index();
+
+    let exceeded_times = if phase == TimerPhase::Running || phase == TimerPhase::Paused {
+        catch! {
+            let index = split_index?;
+            let (_, start_time) = pb_chance::current_start(timer, method)?;
+            let elapsed = timer.current_time()[method]? - start_time;
+            segment_times(timer.run(), index, method)
+                .into_iter()
+                .filter(|&(_, time)| time < elapsed)
+                .count()
+        }
+    } else {
+        None
+    };
+
+    CacheKey {
+        comparison: comparison.to_string(),
+        method,
+        phase,
+        split_index,
+        run_version: timer.run_version(),
+        exceeded_times: exceeded_times.unwrap_or(0),
+    }
+}
+
+fn format_percentage(probability: f64, accuracy: Accuracy) -> String {
+    let decimals = match accuracy {
+        Accuracy::Seconds => 0,
+        Accuracy::Tenths => 1,
+        Accuracy::Hundredths => 2,
+    };
+    format!("{:.*}%", decimals, 100.0 * probability)
+}
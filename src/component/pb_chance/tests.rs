@@ -0,0 +1,96 @@
+use {Run, Segment, TimeSpan, Timer, TimingMethod};
+use settings::Value;
+use tests_helper::{split_at, start_run, time};
+use super::{cache_key, Component};
+
+fn run(histories: &[&[f64]]) -> Run {
+    let mut run = Run::new();
+    let mut split_time = 0.0;
+    for (index, history) in histories.iter().enumerate() {
+        let mut segment = Segment::new(index.to_string());
+        for (attempt, &seconds) in history.iter().enumerate() {
+            segment
+                .segment_history_mut()
+                .insert(attempt as i32 + 1, time(seconds));
+        }
+        split_time += history[0];
+        segment.set_personal_best_split_time(time(split_time));
+        run.push_segment(segment);
+    }
+    run
+}
+
+fn chance(component: &mut Component, timer: &Timer) -> String {
+    component.state(timer).chance
+}
+
+#[test]
+fn chance_before_starting() {
+    let timer = Timer::new(run(&[&[10.0, 10.0], &[30.0, 20.0]])).unwrap();
+    let mut component = Component::new();
+    assert_eq!(component.state(&timer).text, "PB Chance");
+    // The simulation always uses the same seed, so the estimate is stable.
+    assert_eq!(chance(&mut component, &timer), "50.9%");
+
+    component.set_value(
+        1,
+        Value::OptionalString(Some(String::from("Best Segments"))),
+    );
+    assert_eq!(component.state(&timer).text, "Chance to Beat Best");
+}
+
+#[test]
+fn edited_times_update_the_chance() {
+    let mut run = run(&[&[10.0, 10.0], &[30.0, 20.0]]);
+    let mut timer = Timer::new(run.clone()).unwrap();
+    let mut component = Component::new();
+    assert_eq!(chance(&mut component, &timer), "50.9%");
+
+    // The amount of attempts stays the same, only the times change.
+    *run.segment_mut(1).segment_history_mut().get_mut(1).unwrap() = time(15.0);
+    timer.set_run(run).unwrap();
+    assert_eq!(chance(&mut component, &timer), "100.0%");
+}
+
+#[test]
+fn skipped_splits_use_the_last_split_time() {
+    let run = run(&[&[10.0, 10.0], &[5.0, 20.0], &[12.0, 30.0]]);
+    let mut timer = Timer::new(run).unwrap();
+    start_run(&mut timer);
+    split_at(&mut timer, 10.0);
+    timer.skip_split();
+
+    // Only 11 seconds have passed since the first split, so the current
+    // segment hasn't outlasted any of its times yet.
+    timer.set_game_time(TimeSpan::from_seconds(21.0));
+    let key = cache_key(&timer, "Personal Best");
+    assert_eq!(key.exceeded_times, 0);
+
+    timer.set_game_time(TimeSpan::from_seconds(23.0));
+    let key = cache_key(&timer, "Personal Best");
+    assert_eq!(key.exceeded_times, 1);
+}
+
+#[test]
+fn finished_attempts() {
+    let mut timer = Timer::new(run(&[&[10.0, 10.0]])).unwrap();
+    let mut component = Component::new();
+    start_run(&mut timer);
+    split_at(&mut timer, 9.0);
+    assert_eq!(chance(&mut component, &timer), "100.0%");
+}
+
+#[test]
+fn reset_attempts_update_the_chance() {
+    let mut timer = Timer::new(run(&[&[10.0, 10.0], &[30.0, 20.0]])).unwrap();
+    let mut component = Component::new();
+    timer.set_current_timing_method(TimingMethod::GameTime);
+    assert_eq!(chance(&mut component, &timer), "50.9%");
+
+    // The new Personal Best is faster than any other attempt.
+    start_run(&mut timer);
+    split_at(&mut timer, 5.0);
+    split_at(&mut timer, 15.0);
+    timer.reset(true);
+    assert_eq!(chance(&mut component, &timer), "0.0%");
+}
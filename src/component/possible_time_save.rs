@@ -103,15 +103,23 @@ impl Component {
         let comparison = comparison::resolve(&self.settings.comparison_override, timer);
         let text = self.text(comparison);
         let comparison = comparison::or_current(comparison, timer);
+        let method = timer.current_timing_method();
 
         let time = if self.settings.total_possible_time_save {
             Some(possible_time_save::calculate_total(
                 timer,
                 segment_index.unwrap_or(0),
                 comparison,
+                method,
             ))
         } else if current_phase == TimerPhase::Running || current_phase == TimerPhase::Paused {
-            possible_time_save::calculate(timer, segment_index.unwrap(), comparison, false)
+            possible_time_save::calculate(
+                timer,
+                segment_index.unwrap(),
+                comparison,
+                false,
+                method,
+            )
         } else {
             None
         };
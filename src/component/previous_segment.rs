@@ -118,6 +118,7 @@ impl Component {
                         split_index,
                         comparison,
                         false,
+                        method,
                     );
                 }
             } else if let Some(prev_split_index) = split_index.checked_sub(1) {
@@ -129,6 +130,7 @@ impl Component {
                         prev_split_index,
                         comparison,
                         false,
+                        method,
                     );
                 }
             };
@@ -0,0 +1,188 @@
+use Timer;
+use serde_json::{to_writer, Result};
+use std::io::Write;
+use std::borrow::Cow;
+use settings::{Color, Field, Gradient, SettingsDescription, Value};
+use super::DEFAULT_INFO_TEXT_GRADIENT;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub background: Gradient,
+    pub label_color: Option<Color>,
+    pub value_color: Option<Color>,
+    pub show_platform: bool,
+    pub show_region: bool,
+    pub show_emulator_usage: bool,
+    pub hide_empty_values: bool,
+    /// The names of the speedrun.com variables to show, in the order they are
+    /// shown in. If there are none, all the variables of the run are shown.
+    pub variables: Vec<String>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: DEFAULT_INFO_TEXT_GRADIENT,
+            label_color: None,
+            value_color: None,
+            show_platform: true,
+            show_region: true,
+            show_emulator_usage: false,
+            hide_empty_values: true,
+            variables: Vec::new(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct State {
+    pub background: Gradient,
+    pub label_color: Option<Color>,
+    pub value_color: Option<Color>,
+    pub rows: Vec<RowState>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RowState {
+    pub name: String,
+    pub value: String,
+}
+
+impl State {
+    pub fn write_json<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        to_writer(writer, self)
+    }
+}
+
+impl Component {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn with_settings(settings: Settings) -> Self {
+        Self {
+            settings,
+            ..Default::default()
+        }
+    }
+
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    pub fn name(&self) -> Cow<str> {
+        "Run Metadata".into()
+    }
+
+    pub fn state(&self, timer: &Timer) -> State {
+        let metadata = timer.run().metadata();
+        let mut rows = Vec::new();
+
+        if self.settings.show_platform {
+            rows.push(("Platform".to_string(), metadata.platform_name().to_string()));
+        }
+        if self.settings.show_region {
+            rows.push(("Region".to_string(), metadata.region_name().to_string()));
+        }
+        if self.settings.show_emulator_usage {
+            let uses_emulator = if metadata.uses_emulator() { "Yes" } else { "No" };
+            rows.push(("Emulator".to_string(), uses_emulator.to_string()));
+        }
+
+        if self.settings.variables.is_empty() {
+            rows.extend(
+                metadata
+                    .variables()
+                    .map(|(name, value)| (name.clone(), value.clone())),
+            );
+        } else {
+            rows.extend(self.settings.variables.iter().map(|name| {
+                let value = metadata
+                    .variables()
+                    .find(|&(n, _)| n == name)
+                    .map(|(_, value)| value.clone())
+                    .unwrap_or_default();
+                (name.clone(), value)
+            }));
+        }
+
+        if self.settings.hide_empty_values {
+            rows.retain(|row| !row.1.trim().is_empty());
+        }
+
+        State {
+            background: self.settings.background,
+            label_color: self.settings.label_color,
+            value_color: self.settings.value_color,
+            rows: rows.into_iter()
+                .map(|(name, value)| RowState { name, value })
+                .collect(),
+        }
+    }
+
+    pub fn settings_description(&self) -> SettingsDescription {
+        let mut fields = vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new("Label Color".into(), self.settings.label_color.into()),
+            Field::new("Value Color".into(), self.settings.value_color.into()),
+            Field::new("Show Platform".into(), self.settings.show_platform.into()),
+            Field::new("Show Region".into(), self.settings.show_region.into()),
+            Field::new(
+                "Show Emulator Usage".into(),
+                self.settings.show_emulator_usage.into(),
+            ),
+            Field::new(
+                "Hide Empty Values".into(),
+                self.settings.hide_empty_values.into(),
+            ),
+            Field::new(
+                "Variables".into(),
+                Value::UInt(self.settings.variables.len() as _),
+            ),
+        ];
+
+        for variable in &self.settings.variables {
+            fields.push(Field::new("Variable".into(), variable.clone().into()));
+        }
+
+        SettingsDescription::with_fields(fields)
+    }
+
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.label_color = value.into(),
+            2 => self.settings.value_color = value.into(),
+            3 => self.settings.show_platform = value.into(),
+            4 => self.settings.show_region = value.into(),
+            5 => self.settings.show_emulator_usage = value.into(),
+            6 => self.settings.hide_empty_values = value.into(),
+            7 => {
+                let count = value.into_uint().unwrap() as usize;
+                self.settings.variables.resize(count, String::new());
+            }
+            index => {
+                *self.settings
+                    .variables
+                    .get_mut(index - 8)
+                    .expect("Unsupported Setting Index") = value.into();
+            }
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use {Run, Segment, Timer};
+use settings::Value;
+use super::Component;
+
+fn timer() -> Timer {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    run.metadata_mut().set_platform_name("PC");
+    run.metadata_mut().set_emulator_usage(true);
+    run.metadata_mut().add_variable("Difficulty", "Hard");
+    run.metadata_mut().add_variable("Version", "1.0");
+    run.metadata_mut().add_variable("Glitches", "");
+    Timer::new(run).unwrap()
+}
+
+fn rows(component: &Component, timer: &Timer) -> Vec<(String, String)> {
+    component
+        .state(timer)
+        .rows
+        .into_iter()
+        .map(|r| (r.name, r.value))
+        .collect()
+}
+
+#[test]
+fn all_variables() {
+    let timer = timer();
+    let mut component = Component::new();
+    assert_eq!(
+        rows(&component, &timer),
+        [
+            ("Platform".to_string(), "PC".to_string()),
+            ("Difficulty".to_string(), "Hard".to_string()),
+            ("Version".to_string(), "1.0".to_string()),
+        ]
+    );
+
+    component.set_value(5, Value::Bool(true));
+    component.set_value(6, Value::Bool(false));
+    assert_eq!(rows(&component, &timer).len(), 6);
+}
+
+#[test]
+fn selected_variables() {
+    let timer = timer();
+    let mut component = Component::new();
+    component.set_value(3, Value::Bool(false));
+    component.set_value(7, Value::UInt(3));
+    component.set_value(8, Value::String("Version".to_string()));
+    component.set_value(9, Value::String("Missing".to_string()));
+    component.set_value(10, Value::String("Difficulty".to_string()));
+    assert_eq!(
+        rows(&component, &timer),
+        [
+            ("Version".to_string(), "1.0".to_string()),
+            ("Difficulty".to_string(), "Hard".to_string()),
+        ]
+    );
+}
@@ -0,0 +1,177 @@
+use {comparison, GeneralLayoutSettings, TimeSpan, Timer};
+use analysis::current_pace;
+use serde_json::{to_writer, Result};
+use std::io::Write;
+use std::borrow::Cow;
+use time::formatter::{Accuracy, Delta, Regular, TimeFormatter};
+use settings::{Color, Field, Gradient, SemanticColor, SettingsDescription, Value};
+use super::DEFAULT_INFO_TEXT_GRADIENT;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub background: Gradient,
+    pub label_color: Option<Color>,
+    pub value_color: Option<Color>,
+    pub accuracy: Accuracy,
+    /// The comparisons to show a predicted final time for. Comparisons that
+    /// the run doesn't have are not shown.
+    pub comparisons: Vec<String>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: DEFAULT_INFO_TEXT_GRADIENT,
+            label_color: None,
+            value_color: None,
+            accuracy: Accuracy::Seconds,
+            comparisons: vec![
+                String::from(comparison::personal_best::NAME),
+                String::from(comparison::best_segments::NAME),
+                String::from(comparison::average_segments::NAME),
+            ],
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct State {
+    pub background: Gradient,
+    pub label_color: Option<Color>,
+    pub value_color: Option<Color>,
+    pub rows: Vec<RowState>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RowState {
+    pub comparison: String,
+    pub time: String,
+    pub delta: String,
+    pub semantic_color: SemanticColor,
+    pub visual_color: Color,
+}
+
+impl State {
+    pub fn write_json<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        to_writer(writer, self)
+    }
+}
+
+impl Component {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn with_settings(settings: Settings) -> Self {
+        Self {
+            settings,
+            ..Default::default()
+        }
+    }
+
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    pub fn name(&self) -> Cow<str> {
+        "Run Prediction".into()
+    }
+
+    pub fn state(&self, timer: &Timer, layout_settings: &GeneralLayoutSettings) -> State {
+        let method = timer.current_timing_method();
+        let pb_time = timer
+            .run()
+            .segments()
+            .last()
+            .and_then(|s| s.personal_best_split_time()[method]);
+
+        let time_formatter = Regular::with_accuracy(self.settings.accuracy);
+        let delta_formatter = Delta::custom(true, self.settings.accuracy);
+
+        let rows = self.settings
+            .comparisons
+            .iter()
+            .filter_map(|comparison| timer.run().comparisons().find(|&c| c == comparison))
+            .map(|comparison| {
+                let time = current_pace::calculate(timer, comparison);
+                let delta = catch! { time? - pb_time? };
+                let semantic_color = match delta {
+                    Some(delta) if delta < TimeSpan::zero() => SemanticColor::AheadGainingTime,
+                    Some(delta) if delta > TimeSpan::zero() => SemanticColor::BehindLosingTime,
+                    _ => SemanticColor::Default,
+                };
+
+                RowState {
+                    comparison: comparison.to_string(),
+                    time: time_formatter.format(time).to_string(),
+                    delta: delta_formatter.format(delta).to_string(),
+                    semantic_color,
+                    visual_color: semantic_color.visualize(layout_settings),
+                }
+            })
+            .collect();
+
+        State {
+            background: self.settings.background,
+            label_color: self.settings.label_color,
+            value_color: self.settings.value_color,
+            rows,
+        }
+    }
+
+    pub fn settings_description(&self) -> SettingsDescription {
+        let mut fields = vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new("Label Color".into(), self.settings.label_color.into()),
+            Field::new("Value Color".into(), self.settings.value_color.into()),
+            Field::new("Accuracy".into(), self.settings.accuracy.into()),
+            Field::new(
+                "Comparisons".into(),
+                Value::UInt(self.settings.comparisons.len() as _),
+            ),
+        ];
+
+        for comparison in &self.settings.comparisons {
+            fields.push(Field::new("Comparison".into(), comparison.clone().into()));
+        }
+
+        SettingsDescription::with_fields(fields)
+    }
+
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.label_color = value.into(),
+            2 => self.settings.value_color = value.into(),
+            3 => self.settings.accuracy = value.into(),
+            4 => {
+                let count = value.into_uint().unwrap() as usize;
+                self.settings
+                    .comparisons
+                    .resize(count, String::from(comparison::personal_best::NAME));
+            }
+            index => {
+                *self.settings
+                    .comparisons
+                    .get_mut(index - 5)
+                    .expect("Unsupported Setting Index") = value.into();
+            }
+        }
+    }
+}
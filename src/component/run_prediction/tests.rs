@@ -0,0 +1,44 @@
+use {GeneralLayoutSettings, Timer};
+use settings::{SemanticColor, Value};
+use tests_helper::timer_with_splits;
+use super::Component;
+
+fn timer() -> Timer {
+    timer_with_splits(&[("A", 10.0, 9.0), ("B", 25.0, 12.0)])
+}
+
+fn rows(component: &Component, timer: &Timer) -> Vec<(String, String, String, SemanticColor)> {
+    component
+        .state(timer, &GeneralLayoutSettings::default())
+        .rows
+        .into_iter()
+        .map(|r| (r.comparison, r.time, r.delta, r.semantic_color))
+        .collect()
+}
+
+#[test]
+fn predictions_before_starting() {
+    let timer = timer();
+    let mut component = Component::new();
+    component.set_value(4, Value::UInt(3));
+    component.set_value(6, Value::String(String::from("Best Segments")));
+    component.set_value(7, Value::String(String::from("Fake Comparison")));
+
+    assert_eq!(
+        rows(&component, &timer),
+        [
+            (
+                String::from("Personal Best"),
+                String::from("0:25"),
+                String::from("+0"),
+                SemanticColor::Default,
+            ),
+            (
+                String::from("Best Segments"),
+                String::from("0:21"),
+                String::from("−4"),
+                SemanticColor::AheadGainingTime,
+            ),
+        ]
+    );
+}
@@ -0,0 +1,344 @@
+use {GeneralLayoutSettings, TimeSpan, Timer, TimerPhase, TimingMethod};
+use analysis::statistics::{golds, segment_times};
+use serde_json::{to_writer, Result};
+use std::io::Write;
+use std::borrow::Cow;
+use settings::{Color, Field, Gradient, SettingsDescription, Value};
+use super::DEFAULT_INFO_TEXT_GRADIENT;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub background: Gradient,
+    /// Shows a histogram of all the segment times instead of a sparkline of
+    /// the most recent ones.
+    pub show_histogram: bool,
+    /// The amount of recent segment times the sparkline shows.
+    pub sparkline_count: u32,
+    /// The amount of bars the histogram consists of.
+    pub histogram_bars: u32,
+    pub graph_color: Color,
+    pub live_segment_color: Color,
+    pub height: u32,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: DEFAULT_INFO_TEXT_GRADIENT,
+            show_histogram: false,
+            sparkline_count: 20,
+            histogram_bars: 10,
+            graph_color: (1.0, 1.0, 1.0, 1.0).into(),
+            live_segment_color: (1.0, 1.0, 1.0, 0.5).into(),
+            height: 40,
+        }
+    }
+}
+
+/// The state of the component. All the coordinates are normalized to be
+/// between 0 and 1, with y going from the top to the bottom. In the sparkline
+/// the markers describe the y coordinate of a horizontal line, while in the
+/// histogram they describe the x coordinate of a vertical line.
+#[derive(Serialize, Deserialize)]
+pub struct State {
+    pub background: Gradient,
+    pub segment_name: String,
+    pub is_histogram: bool,
+    /// The points of the sparkline, ordered from the oldest to the most recent
+    /// segment time.
+    pub points: Vec<Point>,
+    /// The heights of the bars of the histogram, ordered from the fastest to
+    /// the slowest segment times.
+    pub bars: Vec<f32>,
+    pub personal_best_marker: Option<f32>,
+    pub best_segment_marker: Option<f32>,
+    pub live_segment_marker: Option<f32>,
+    pub graph_color: Color,
+    pub personal_best_color: Color,
+    pub best_segment_color: Color,
+    pub live_segment_color: Color,
+    pub height: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Point {
+    pub x: f32,
+    pub y: f32,
+    pub is_best_segment: bool,
+}
+
+impl State {
+    pub fn write_json<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        to_writer(writer, self)
+    }
+}
+
+/// Maps segment times onto the range between 0 and 1.
+struct Scale {
+    min: f64,
+    range: f64,
+}
+
+impl Scale {
+    fn new<I>(times: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = TimeSpan>,
+    {
+        let (mut min, mut max) = (None, None);
+        for time in times {
+            let time = time.total_seconds();
+            min = Some(min.map_or(time, |m: f64| m.min(time)));
+            max = Some(max.map_or(time, |m: f64| m.max(time)));
+        }
+        let (min, max) = (min?, max?);
+        Some(Self {
+            min,
+            range: max - min,
+        })
+    }
+
+    fn position(&self, time: TimeSpan) -> f32 {
+        if self.range > 0.0 {
+            ((time.total_seconds() - self.min) / self.range) as f32
+        } else {
+            0.5
+        }
+    }
+}
+
+impl Component {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn with_settings(settings: Settings) -> Self {
+        Self {
+            settings,
+            ..Default::default()
+        }
+    }
+
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    pub fn name(&self) -> Cow<str> {
+        if self.settings.show_histogram {
+            "Segment History (Histogram)".into()
+        } else {
+            "Segment History".into()
+        }
+    }
+
+    pub fn state(&self, timer: &Timer, layout_settings: &GeneralLayoutSettings) -> State {
+        let method = timer.current_timing_method();
+        let run = timer.run();
+        let phase = timer.current_phase();
+        let segment_index = if phase == TimerPhase::Ended {
+            run.len() - 1
+        } else {
+            timer.current_split_index().unwrap_or(0)
+        };
+
+        let times = segment_times(run, segment_index, method);
+        let personal_best = personal_best_segment_time(timer, segment_index, method);
+        let best_segment = run.segment(segment_index).best_segment_time()[method];
+        let live_segment = if phase == TimerPhase::Running || phase == TimerPhase::Paused {
+            live_segment_time(timer, segment_index, method)
+        } else {
+            None
+        };
+        let markers = [personal_best, best_segment, live_segment];
+
+        let mut points = Vec::new();
+        let mut bars = Vec::new();
+
+        let scale = if self.settings.show_histogram {
+            let scale = Scale::new(
+                times
+                    .iter()
+                    .map(|&(_, t)| t)
+                    .chain(markers.iter().filter_map(|&t| t)),
+            );
+            if let Some(ref scale) = scale {
+                bars = histogram(&times, scale, self.settings.histogram_bars as usize);
+            }
+            scale
+        } else {
+            let skipped = times
+                .len()
+                .saturating_sub(self.settings.sparkline_count as usize);
+            let recent = &times[skipped..];
+            let scale = Scale::new(
+                recent
+                    .iter()
+                    .map(|&(_, t)| t)
+                    .chain(markers.iter().filter_map(|&t| t)),
+            );
+            if let Some(ref scale) = scale {
+                points = sparkline(timer, segment_index, method, &times, recent, scale);
+            }
+            scale
+        };
+
+        let is_histogram = self.settings.show_histogram;
+        let marker = |time: Option<TimeSpan>| {
+            catch! {
+                let position = scale.as_ref()?.position(time?);
+                if is_histogram {
+                    position
+                } else {
+                    1.0 - position
+                }
+            }
+        };
+
+        State {
+            background: self.settings.background,
+            segment_name: run.segment(segment_index).name().to_string(),
+            is_histogram,
+            points,
+            bars,
+            personal_best_marker: marker(personal_best),
+            best_segment_marker: marker(best_segment),
+            live_segment_marker: marker(live_segment),
+            graph_color: self.settings.graph_color,
+            personal_best_color: layout_settings.personal_best_color,
+            best_segment_color: layout_settings.best_segment_color,
+            live_segment_color: self.settings.live_segment_color,
+            height: self.settings.height,
+        }
+    }
+
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new("Height".into(), (self.settings.height as u64).into()),
+            Field::new(
+                "Show Histogram".into(),
+                self.settings.show_histogram.into(),
+            ),
+            Field::new(
+                "Sparkline Segment Times".into(),
+                (self.settings.sparkline_count as u64).into(),
+            ),
+            Field::new(
+                "Histogram Bars".into(),
+                (self.settings.histogram_bars as u64).into(),
+            ),
+            Field::new("Graph Color".into(), self.settings.graph_color.into()),
+            Field::new(
+                "Live Segment Color".into(),
+                self.settings.live_segment_color.into(),
+            ),
+        ])
+    }
+
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.height = value.into_uint().unwrap() as _,
+            2 => self.settings.show_histogram = value.into(),
+            3 => self.settings.sparkline_count = value.into_uint().unwrap() as _,
+            4 => self.settings.histogram_bars = value.into_uint().unwrap() as _,
+            5 => self.settings.graph_color = value.into(),
+            6 => self.settings.live_segment_color = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
+
+fn personal_best_segment_time(
+    timer: &Timer,
+    segment_index: usize,
+    method: TimingMethod,
+) -> Option<TimeSpan> {
+    let segments = timer.run().segments();
+    let split_time = segments[segment_index].personal_best_split_time()[method]?;
+    if segment_index == 0 {
+        Some(split_time)
+    } else {
+        Some(split_time - segments[segment_index - 1].personal_best_split_time()[method]?)
+    }
+}
+
+fn live_segment_time(
+    timer: &Timer,
+    segment_index: usize,
+    method: TimingMethod,
+) -> Option<TimeSpan> {
+    let start_time = if segment_index == 0 {
+        TimeSpan::zero()
+    } else {
+        timer.run().segment(segment_index - 1).split_time()[method]?
+    };
+    Some(timer.current_time()[method]? - start_time)
+}
+
+fn sparkline(
+    timer: &Timer,
+    segment_index: usize,
+    method: TimingMethod,
+    times: &[(i32, TimeSpan)],
+    recent: &[(i32, TimeSpan)],
+    scale: &Scale,
+) -> Vec<Point> {
+    let skipped = times.len() - recent.len();
+    let is_best_segment = golds(timer.run(), segment_index, method, times);
+
+    let last_index = recent.len().saturating_sub(1).max(1) as f32;
+    recent
+        .iter()
+        .zip(&is_best_segment[skipped..])
+        .enumerate()
+        .map(|(i, (&(_, time), &is_best_segment))| Point {
+            x: if recent.len() == 1 {
+                0.5
+            } else {
+                i as f32 / last_index
+            },
+            y: 1.0 - scale.position(time),
+            is_best_segment,
+        })
+        .collect()
+}
+
+fn histogram(times: &[(i32, TimeSpan)], scale: &Scale, bar_count: usize) -> Vec<f32> {
+    if bar_count == 0 {
+        return Vec::new();
+    }
+
+    let mut counts = vec![0u32; bar_count];
+    for &(_, time) in times {
+        let bar = (scale.position(time) * bar_count as f32) as usize;
+        counts[bar.min(bar_count - 1)] += 1;
+    }
+
+    let max_count = counts.iter().cloned().max().unwrap_or(0);
+    counts
+        .into_iter()
+        .map(|count| {
+            if max_count == 0 {
+                0.0
+            } else {
+                count as f32 / max_count as f32
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,52 @@
+use {GeneralLayoutSettings, Time, Timer};
+use tests_helper::{run_with_splits, time};
+use super::Component;
+
+fn timer() -> Timer {
+    let mut run = run_with_splits(&[("A", 9.0, 8.0)]);
+    for (index, &seconds) in [10.0, 8.0, 12.0, 9.0, 8.5].iter().enumerate() {
+        run.segment_mut(0)
+            .segment_history_mut()
+            .insert(index as i32 + 1, time(seconds));
+    }
+    for index in 1..6 {
+        run.add_attempt_with_index(Time::default(), index, None, None, None);
+    }
+    Timer::new(run).unwrap()
+}
+
+#[test]
+fn sparkline() {
+    let mut component = Component::new();
+    component.settings_mut().sparkline_count = 3;
+    let state = component.state(&timer(), &GeneralLayoutSettings::default());
+
+    assert!(!state.is_histogram);
+    assert!(state.bars.is_empty());
+    let points = state
+        .points
+        .iter()
+        .map(|p| (p.x, p.y, p.is_best_segment))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        points,
+        [(0.0, 0.0, false), (0.5, 0.75, false), (1.0, 0.875, false)]
+    );
+    assert_eq!(state.personal_best_marker, Some(0.75));
+    assert_eq!(state.best_segment_marker, Some(1.0));
+    assert_eq!(state.live_segment_marker, None);
+}
+
+#[test]
+fn histogram() {
+    let mut component = Component::new();
+    component.settings_mut().show_histogram = true;
+    component.settings_mut().histogram_bars = 4;
+    let state = component.state(&timer(), &GeneralLayoutSettings::default());
+
+    assert!(state.is_histogram);
+    assert!(state.points.is_empty());
+    assert_eq!(state.bars, [1.0, 0.5, 0.5, 0.5]);
+    assert_eq!(state.personal_best_marker, Some(0.25));
+    assert_eq!(state.best_segment_marker, Some(0.0));
+}
@@ -0,0 +1,179 @@
+use {Timer, TimerPhase};
+use analysis::statistics;
+use time::formatter::{Accuracy, Regular, TimeFormatter};
+use serde_json::{to_writer, Result};
+use std::io::Write;
+use std::borrow::Cow;
+use settings::{Color, Field, Gradient, SettingsDescription, Value};
+use chrono::Local;
+use super::DEFAULT_INFO_TEXT_GRADIENT;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub background: Gradient,
+    pub label_color: Option<Color>,
+    pub value_color: Option<Color>,
+    pub accuracy: Accuracy,
+    /// The segment to show the statistics for, starting at 1. A value of 0
+    /// shows the statistics of the current segment.
+    pub segment: u64,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: DEFAULT_INFO_TEXT_GRADIENT,
+            label_color: None,
+            value_color: None,
+            accuracy: Accuracy::Hundredths,
+            segment: 0,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct State {
+    pub background: Gradient,
+    pub label_color: Option<Color>,
+    pub value_color: Option<Color>,
+    pub segment_name: String,
+    pub rows: Vec<RowState>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RowState {
+    pub name: String,
+    pub value: String,
+}
+
+impl State {
+    pub fn write_json<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        to_writer(writer, self)
+    }
+}
+
+impl Component {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn with_settings(settings: Settings) -> Self {
+        Self {
+            settings,
+            ..Default::default()
+        }
+    }
+
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    pub fn name(&self) -> Cow<str> {
+        if self.settings.segment == 0 {
+            "Segment Statistics".into()
+        } else {
+            format!("Segment Statistics (Segment {})", self.settings.segment).into()
+        }
+    }
+
+    fn segment_index(&self, timer: &Timer) -> usize {
+        let last_index = timer.run().len() - 1;
+        if self.settings.segment == 0 {
+            if timer.current_phase() == TimerPhase::Ended {
+                last_index
+            } else {
+                timer.current_split_index().unwrap_or(0)
+            }
+        } else {
+            (self.settings.segment as usize - 1).min(last_index)
+        }
+    }
+
+    pub fn state(&self, timer: &Timer) -> State {
+        let run = timer.run();
+        let segment_index = self.segment_index(timer);
+        let stats = statistics::calculate(run, segment_index, timer.current_timing_method());
+        let formatter = Regular::with_accuracy(self.settings.accuracy);
+
+        let completion_rate = if stats.attempts == 0 {
+            String::from("-")
+        } else {
+            format!(
+                "{:.1}%",
+                100.0 * f64::from(stats.completions) / f64::from(stats.attempts)
+            )
+        };
+
+        let last_gold_date = catch! {
+            let index = stats.last_gold_attempt?;
+            let attempt = run.attempt_history().iter().find(|a| a.index() == index)?;
+            let date = attempt.ended().or_else(|| attempt.started())?;
+            date.time.with_timezone(&Local).format("%F").to_string()
+        };
+
+        let rows = vec![
+            ("Attempts", stats.attempts.to_string()),
+            ("Completion Rate", completion_rate),
+            ("Average", formatter.format(stats.mean).to_string()),
+            (
+                "Standard Deviation",
+                formatter.format(stats.standard_deviation).to_string(),
+            ),
+            ("Gold Count", stats.gold_count.to_string()),
+            (
+                "Last Gold",
+                last_gold_date.unwrap_or_else(|| String::from("-")),
+            ),
+        ];
+
+        State {
+            background: self.settings.background,
+            label_color: self.settings.label_color,
+            value_color: self.settings.value_color,
+            segment_name: run.segment(segment_index).name().to_string(),
+            rows: rows.into_iter()
+                .map(|(name, value)| RowState {
+                    name: String::from(name),
+                    value,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new("Label Color".into(), self.settings.label_color.into()),
+            Field::new("Value Color".into(), self.settings.value_color.into()),
+            Field::new("Accuracy".into(), self.settings.accuracy.into()),
+            Field::new("Segment".into(), self.settings.segment.into()),
+        ])
+    }
+
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.label_color = value.into(),
+            2 => self.settings.value_color = value.into(),
+            3 => self.settings.accuracy = value.into(),
+            4 => self.settings.segment = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use {AtomicDateTime, Run, Segment, Time, Timer};
+use tests_helper::time;
+use super::Component;
+
+fn run() -> Run {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    run.push_segment(Segment::new("B"));
+
+    let attempts: &[&[f64]] = &[&[10.0, 20.0], &[8.0, 25.0], &[12.0], &[10.0]];
+
+    for (index, times) in attempts.iter().enumerate() {
+        let index = index as i32 + 1;
+        // The attempts end at noon, so the dates are the same in most time
+        // zones.
+        let ended = format!("2017-10-0{}T12:00:00Z", index).parse().unwrap();
+        let ended = AtomicDateTime::new(ended, false);
+        run.add_attempt_with_index(Time::default(), index, None, Some(ended), None);
+        for (segment, &seconds) in run.segments_mut().iter_mut().zip(times.iter()) {
+            segment.segment_history_mut().insert(index, time(seconds));
+        }
+    }
+
+    run
+}
+
+fn rows(component: &Component, timer: &Timer) -> Vec<(String, String)> {
+    component
+        .state(timer)
+        .rows
+        .into_iter()
+        .map(|r| (r.name, r.value))
+        .collect()
+}
+
+#[test]
+fn current_segment() {
+    let timer = Timer::new(run()).unwrap();
+    let component = Component::new();
+
+    let state = component.state(&timer);
+    assert_eq!(state.segment_name, "A");
+    assert_eq!(
+        rows(&component, &timer),
+        [
+            ("Attempts".to_string(), "4".to_string()),
+            ("Completion Rate".to_string(), "100.0%".to_string()),
+            ("Average".to_string(), "0:10.00".to_string()),
+            ("Standard Deviation".to_string(), "0:01.41".to_string()),
+            ("Gold Count".to_string(), "2".to_string()),
+            ("Last Gold".to_string(), "2017-10-02".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn selected_segment() {
+    let timer = Timer::new(run()).unwrap();
+    let mut component = Component::new();
+    component.settings_mut().segment = 2;
+    assert_eq!(component.name(), "Segment Statistics (Segment 2)");
+
+    let state = component.state(&timer);
+    assert_eq!(state.segment_name, "B");
+    assert_eq!(state.rows[0].value, "4");
+    assert_eq!(state.rows[1].value, "50.0%");
+    assert_eq!(state.rows[2].value, "0:22.50");
+}
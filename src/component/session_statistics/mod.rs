@@ -0,0 +1,144 @@
+use {Timer, TimeSpan, Utc};
+use analysis::session;
+use time::formatter::{Accuracy, Regular, TimeFormatter};
+use serde_json::{to_writer, Result};
+use std::io::Write;
+use std::borrow::Cow;
+use settings::{Color, Field, Gradient, SettingsDescription, Value};
+use super::DEFAULT_INFO_TEXT_GRADIENT;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub background: Gradient,
+    pub label_color: Option<Color>,
+    pub value_color: Option<Color>,
+    pub accuracy: Accuracy,
+    /// The amount of minutes without any attempts after which a session is
+    /// considered over.
+    pub inactivity_gap: u64,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: DEFAULT_INFO_TEXT_GRADIENT,
+            label_color: None,
+            value_color: None,
+            accuracy: Accuracy::Seconds,
+            inactivity_gap: 60,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct State {
+    pub background: Gradient,
+    pub label_color: Option<Color>,
+    pub value_color: Option<Color>,
+    pub rows: Vec<RowState>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RowState {
+    pub name: String,
+    pub value: String,
+}
+
+impl State {
+    pub fn write_json<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        to_writer(writer, self)
+    }
+}
+
+impl Component {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn with_settings(settings: Settings) -> Self {
+        Self {
+            settings,
+            ..Default::default()
+        }
+    }
+
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    pub fn name(&self) -> Cow<str> {
+        "Session Statistics".into()
+    }
+
+    pub fn state(&self, timer: &Timer) -> State {
+        let inactivity_gap = TimeSpan::from_seconds(60.0 * self.settings.inactivity_gap as f64);
+        let session = session::calculate(timer, inactivity_gap, Utc::now());
+
+        let rows = vec![
+            ("Attempts", session.attempts.to_string()),
+            ("Finished Runs", session.finished.to_string()),
+            (
+                "Session Duration",
+                Regular::new().format(session.duration).to_string(),
+            ),
+            (
+                "Best Time",
+                Regular::with_accuracy(self.settings.accuracy)
+                    .format(session.best_time)
+                    .to_string(),
+            ),
+        ];
+
+        State {
+            background: self.settings.background,
+            label_color: self.settings.label_color,
+            value_color: self.settings.value_color,
+            rows: rows.into_iter()
+                .map(|(name, value)| RowState {
+                    name: String::from(name),
+                    value,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new("Label Color".into(), self.settings.label_color.into()),
+            Field::new("Value Color".into(), self.settings.value_color.into()),
+            Field::new("Accuracy".into(), self.settings.accuracy.into()),
+            Field::new(
+                "Inactivity Gap (Minutes)".into(),
+                self.settings.inactivity_gap.into(),
+            ),
+        ])
+    }
+
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.label_color = value.into(),
+            2 => self.settings.value_color = value.into(),
+            3 => self.settings.accuracy = value.into(),
+            4 => self.settings.inactivity_gap = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
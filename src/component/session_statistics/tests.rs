@@ -0,0 +1,74 @@
+use {AtomicDateTime, Run, Segment, Time, TimeSpan, Timer};
+use super::Component;
+
+fn timer() -> Timer {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    Timer::new(run).unwrap()
+}
+
+fn values(component: &Component, timer: &Timer) -> Vec<String> {
+    component
+        .state(timer)
+        .rows
+        .into_iter()
+        .map(|r| r.value)
+        .collect()
+}
+
+#[test]
+fn empty_history() {
+    let component = Component::new();
+    let state = component.state(&timer());
+    let names = state.rows.iter().map(|r| r.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        ["Attempts", "Finished Runs", "Session Duration", "Best Time"]
+    );
+    assert_eq!(values(&component, &timer()), ["0", "0", "0:00", "—"]);
+}
+
+#[test]
+fn reset_and_finished_attempts() {
+    let component = Component::new();
+    let mut timer = timer();
+
+    timer.start();
+    assert_eq!(values(&component, &timer)[..2], ["1", "0"]);
+    timer.reset(true);
+    assert_eq!(values(&component, &timer)[..2], ["1", "0"]);
+    assert_eq!(values(&component, &timer)[3], "—");
+
+    timer.start();
+    timer.split();
+    assert_eq!(values(&component, &timer)[..2], ["2", "1"]);
+    assert_ne!(values(&component, &timer)[3], "—");
+
+    // Once the finished attempt is reset, it's part of the history and must
+    // not be counted twice.
+    timer.reset(true);
+    assert_eq!(values(&component, &timer)[..2], ["2", "1"]);
+    assert_ne!(values(&component, &timer)[3], "—");
+}
+
+#[test]
+fn old_attempts_are_not_part_of_the_session() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    let date = |hour| {
+        let date = format!("2017-10-01T{:02}:00:00Z", hour).parse().unwrap();
+        Some(AtomicDateTime::new(date, false))
+    };
+    run.add_attempt(
+        Time::new().with_real_time(Some(TimeSpan::from_seconds(60.0))),
+        date(9),
+        date(10),
+        None,
+    );
+    let mut timer = Timer::new(run).unwrap();
+    let component = Component::new();
+
+    assert_eq!(values(&component, &timer), ["0", "0", "0:00", "—"]);
+    timer.start();
+    assert_eq!(values(&component, &timer)[..2], ["1", "0"]);
+}
@@ -0,0 +1,485 @@
+use std::cmp::{max, min};
+use std::io::Write;
+use serde_json::{to_writer, Result};
+use {analysis, comparison, GeneralLayoutSettings, Segment, TimeSpan, Timer, TimingMethod};
+use analysis::{possible_time_save, split_color};
+use time::formatter::{Delta, PossibleTimeSave, Regular, TimeFormatter};
+use time::formatter::none_wrapper::{DashWrapper, EmptyWrapper};
+use std::borrow::Cow;
+use settings::{Color, Field, Font, Gradient, SemanticColor, SettingsDescription, Value};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Default, Clone)]
+pub struct Component {
+    icon_ids: Vec<usize>,
+    settings: Settings,
+    current_split_index: Option<usize>,
+    scroll_offset: isize,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub visual_split_count: usize,
+    pub split_preview_count: usize,
+    pub always_show_last_split: bool,
+    pub separator_last_split: bool,
+    pub current_split_gradient: Gradient,
+    pub show_column_labels: bool,
+    pub columns: Vec<ColumnSettings>,
+    pub text_font: Option<Font>,
+    pub times_font: Option<Font>,
+}
+
+/// Describes which value a column of the Splits Component shows.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ColumnType {
+    /// The difference between the split time and the comparison's split time.
+    Delta,
+    /// The split time, or the comparison's split time for upcoming splits.
+    SplitTime,
+    /// The segment time, or the comparison's segment time for upcoming
+    /// splits.
+    SegmentTime,
+    /// The difference between the segment time and the comparison's segment
+    /// time.
+    SegmentDelta,
+    /// The Best Segment time of the segment.
+    BestSegment,
+    /// The time that could be saved on the segment compared to the
+    /// comparison.
+    PossibleTimeSave,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ColumnSettings {
+    pub name: String,
+    pub column_type: ColumnType,
+    pub comparison_override: Option<String>,
+    pub timing_method: Option<TimingMethod>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ColumnState {
+    pub value: String,
+    pub semantic_color: SemanticColor,
+    pub visual_color: Color,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SplitState {
+    pub name: String,
+    pub columns: Vec<ColumnState>,
+    pub is_current_split: bool,
+    pub index: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct IconChange {
+    pub segment_index: usize,
+    pub icon: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct State {
+    pub splits: Vec<SplitState>,
+    pub icon_changes: Vec<IconChange>,
+    pub show_final_separator: bool,
+    pub current_split_gradient: Gradient,
+    pub column_labels: Option<Vec<String>>,
+    pub text_font: Option<Font>,
+    pub times_font: Option<Font>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            visual_split_count: 16,
+            split_preview_count: 1,
+            always_show_last_split: true,
+            separator_last_split: true,
+            current_split_gradient: Gradient::Vertical(
+                Color::from((51.0 / 255.0, 115.0 / 255.0, 244.0 / 255.0, 1.0)),
+                Color::from((21.0 / 255.0, 53.0 / 255.0, 116.0 / 255.0, 1.0)),
+            ),
+            show_column_labels: false,
+            columns: vec![
+                ColumnSettings {
+                    name: String::from("+/-"),
+                    column_type: ColumnType::Delta,
+                    comparison_override: None,
+                    timing_method: None,
+                },
+                ColumnSettings {
+                    name: String::from("Time"),
+                    column_type: ColumnType::SplitTime,
+                    comparison_override: None,
+                    timing_method: None,
+                },
+            ],
+            text_font: None,
+            times_font: None,
+        }
+    }
+}
+
+impl Default for ColumnSettings {
+    fn default() -> Self {
+        ColumnSettings {
+            name: String::from("Column"),
+            column_type: ColumnType::Delta,
+            comparison_override: None,
+            timing_method: None,
+        }
+    }
+}
+
+impl State {
+    pub fn write_json<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        to_writer(writer, self)
+    }
+}
+
+impl Component {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn with_settings(settings: Settings) -> Self {
+        Self {
+            settings,
+            ..Default::default()
+        }
+    }
+
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll_offset = self.scroll_offset.saturating_sub(1);
+    }
+
+    pub fn scroll_down(&mut self) {
+        self.scroll_offset = self.scroll_offset.saturating_add(1);
+    }
+
+    pub fn remount(&mut self) {
+        self.icon_ids.clear();
+    }
+
+    pub fn name(&self) -> Cow<str> {
+        "Splits".into()
+    }
+
+    pub fn state(&mut self, timer: &Timer, layout_settings: &GeneralLayoutSettings) -> State {
+        // Reset Scroll Offset when any movement of the split index is observed.
+        if self.current_split_index != timer.current_split_index() {
+            self.current_split_index = timer.current_split_index();
+            self.scroll_offset = 0;
+        }
+
+        self.icon_ids.resize(timer.run().len(), 0);
+
+        let current_split = timer.current_split_index();
+
+        let always_show_last_split = if self.settings.always_show_last_split {
+            0
+        } else {
+            1
+        };
+        let skip_count = min(
+            current_split.map_or(0, |c_s| {
+                c_s.saturating_sub(
+                    self.settings
+                        .visual_split_count
+                        .saturating_sub(2)
+                        .saturating_sub(self.settings.split_preview_count)
+                        .saturating_add(always_show_last_split),
+                ) as isize
+            }),
+            timer.run().len() as isize - self.settings.visual_split_count as isize,
+        );
+        self.scroll_offset = min(
+            max(self.scroll_offset, -skip_count),
+            timer.run().len() as isize - skip_count - self.settings.visual_split_count as isize,
+        );
+        let skip_count = max(0, skip_count + self.scroll_offset) as usize;
+        let take_count = self.settings.visual_split_count + always_show_last_split as usize - 1;
+        let always_show_last_split = self.settings.always_show_last_split;
+
+        let show_final_separator = self.settings.separator_last_split && always_show_last_split
+            && skip_count + take_count + 1 < timer.run().len();
+
+        let mut icon_changes = Vec::new();
+        let column_settings = &self.settings.columns;
+
+        State {
+            splits: timer
+                .run()
+                .segments()
+                .iter()
+                .enumerate()
+                .zip(self.icon_ids.iter_mut())
+                .skip(skip_count)
+                .filter(|&((i, _), _)| {
+                    i - skip_count < take_count
+                        || (always_show_last_split && i + 1 == timer.run().len())
+                })
+                .map(|((i, segment), icon_id)| {
+                    let columns = column_settings
+                        .iter()
+                        .map(|column| {
+                            column_state(column, timer, layout_settings, segment, i, current_split)
+                        })
+                        .collect();
+
+                    if let Some(icon_change) = segment.icon().check_for_change(icon_id) {
+                        icon_changes.push(IconChange {
+                            segment_index: i,
+                            icon: icon_change.to_owned(),
+                        });
+                    }
+
+                    SplitState {
+                        name: segment.name().to_string(),
+                        columns,
+                        is_current_split: Some(i) == current_split,
+                        index: i,
+                    }
+                })
+                .collect(),
+            icon_changes,
+            show_final_separator: show_final_separator,
+            current_split_gradient: self.settings.current_split_gradient,
+            column_labels: if self.settings.show_column_labels {
+                Some(self.settings.columns.iter().map(|c| c.name.clone()).collect())
+            } else {
+                None
+            },
+            text_font: self.settings.text_font.clone(),
+            times_font: self.settings.times_font.clone(),
+        }
+    }
+
+    pub fn settings_description(&self) -> SettingsDescription {
+        let mut fields = vec![
+            Field::new(
+                "Total Splits".into(),
+                Value::UInt(self.settings.visual_split_count as _),
+            ),
+            Field::new(
+                "Upcoming Splits".into(),
+                Value::UInt(self.settings.split_preview_count as _),
+            ),
+            Field::new(
+                "Always Show Last Split".into(),
+                self.settings.always_show_last_split.into(),
+            ),
+            Field::new(
+                "Show Separator Before Last Split".into(),
+                self.settings.separator_last_split.into(),
+            ),
+            Field::new(
+                "Current Split Gradient".into(),
+                self.settings.current_split_gradient.into(),
+            ),
+            Field::new(
+                "Show Column Labels".into(),
+                self.settings.show_column_labels.into(),
+            ),
+            Field::new(
+                "Columns".into(),
+                Value::UInt(self.settings.columns.len() as _),
+            ),
+        ];
+
+        for column in &self.settings.columns {
+            fields.push(Field::new("Column Name".into(), column.name.clone().into()));
+            fields.push(Field::new("Column Type".into(), column.column_type.into()));
+            fields.push(Field::new(
+                "Comparison".into(),
+                column.comparison_override.clone().into(),
+            ));
+            fields.push(Field::new(
+                "Timing Method".into(),
+                column.timing_method.into(),
+            ));
+        }
+
+        fields.push(Field::new(
+            "Text Font".into(),
+            self.settings.text_font.clone().into(),
+        ));
+        fields.push(Field::new(
+            "Times Font".into(),
+            self.settings.times_font.clone().into(),
+        ));
+
+        SettingsDescription::with_fields(fields)
+    }
+
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.visual_split_count = value.into_uint().unwrap() as _,
+            1 => self.settings.split_preview_count = value.into_uint().unwrap() as _,
+            2 => self.settings.always_show_last_split = value.into(),
+            3 => self.settings.separator_last_split = value.into(),
+            4 => self.settings.current_split_gradient = value.into(),
+            5 => self.settings.show_column_labels = value.into(),
+            6 => {
+                let count = value.into_uint().unwrap() as usize;
+                self.settings.columns.resize(count, ColumnSettings::default());
+            }
+            // The fonts follow the settings of the columns.
+            index if index >= 7 + 4 * self.settings.columns.len() => {
+                match index - 7 - 4 * self.settings.columns.len() {
+                    0 => self.settings.text_font = value.into(),
+                    1 => self.settings.times_font = value.into(),
+                    _ => panic!("Unsupported Setting Index"),
+                }
+            }
+            index => {
+                let index = index - 7;
+                let column = self.settings
+                    .columns
+                    .get_mut(index / 4)
+                    .expect("Unsupported Setting Index");
+                match index % 4 {
+                    0 => column.name = value.into(),
+                    1 => column.column_type = value.into(),
+                    2 => column.comparison_override = value.into(),
+                    _ => column.timing_method = value.into(),
+                }
+            }
+        }
+    }
+}
+
+fn column_state(
+    column: &ColumnSettings,
+    timer: &Timer,
+    layout_settings: &GeneralLayoutSettings,
+    segment: &Segment,
+    index: usize,
+    current_split: Option<usize>,
+) -> ColumnState {
+    let method = column
+        .timing_method
+        .unwrap_or_else(|| timer.current_timing_method());
+    let resolved_comparison = comparison::resolve(&column.comparison_override, timer);
+    let comparison = comparison::or_current(resolved_comparison, timer);
+
+    let is_completed = current_split > Some(index);
+    let is_current = current_split == Some(index);
+
+    let (value, semantic_color) = match column.column_type {
+        ColumnType::Delta | ColumnType::SegmentDelta => {
+            let is_split_delta = column.column_type == ColumnType::Delta;
+            let (delta, semantic_color) = if is_completed {
+                let delta = if is_split_delta {
+                    catch! {
+                        segment.split_time()[method]? - segment.comparison(comparison)[method]?
+                    }
+                } else {
+                    analysis::previous_segment_delta(timer, index, comparison, method)
+                };
+                let color = split_color(
+                    timer,
+                    delta,
+                    index,
+                    is_split_delta,
+                    true,
+                    comparison,
+                    method,
+                );
+                (delta, color)
+            } else if is_current {
+                let delta = analysis::check_live_delta(timer, is_split_delta, comparison, method);
+                (delta, SemanticColor::Default)
+            } else {
+                (None, SemanticColor::Default)
+            };
+
+            let value = if is_completed {
+                DashWrapper::new(Delta::with_decimal_dropping())
+                    .format(delta)
+                    .to_string()
+            } else {
+                EmptyWrapper::new(Delta::with_decimal_dropping())
+                    .format(delta)
+                    .to_string()
+            };
+
+            (value, semantic_color)
+        }
+        ColumnType::SplitTime => {
+            let time = if is_completed {
+                segment.split_time()[method]
+            } else {
+                segment.comparison(comparison)[method]
+            };
+            (
+                Regular::new().format(time).to_string(),
+                SemanticColor::Default,
+            )
+        }
+        ColumnType::SegmentTime => {
+            let time = if is_completed {
+                analysis::previous_segment_time(timer, index, method)
+            } else {
+                comparison_segment_time(timer, index, comparison, method)
+            };
+            (
+                Regular::new().format(time).to_string(),
+                SemanticColor::Default,
+            )
+        }
+        ColumnType::BestSegment => (
+            Regular::new()
+                .format(segment.best_segment_time()[method])
+                .to_string(),
+            SemanticColor::Default,
+        ),
+        ColumnType::PossibleTimeSave => {
+            let time = possible_time_save::calculate(timer, index, comparison, is_current, method);
+            (
+                PossibleTimeSave::new().format(time).to_string(),
+                SemanticColor::Default,
+            )
+        }
+    };
+
+    ColumnState {
+        value,
+        semantic_color,
+        visual_color: semantic_color.visualize(layout_settings),
+    }
+}
+
+fn comparison_segment_time(
+    timer: &Timer,
+    index: usize,
+    comparison: &str,
+    method: TimingMethod,
+) -> Option<TimeSpan> {
+    let segments = timer.run().segments();
+    let split_time = segments[index].comparison(comparison)[method]?;
+    let previous_split_time = segments[..index]
+        .iter()
+        .rev()
+        .filter_map(|s| s.comparison(comparison)[method])
+        .next()
+        .unwrap_or_default();
+    Some(split_time - previous_split_time)
+}
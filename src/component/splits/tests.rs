@@ -0,0 +1,181 @@
+use {GeneralLayoutSettings, TimeSpan, Timer, TimingMethod};
+use settings::{SemanticColor, Value};
+use tests_helper::{split_at, start_run, timer_with_splits};
+use super::{ColumnSettings, ColumnType, Component};
+
+fn timer() -> Timer {
+    timer_with_splits(&[("A", 10.0, 8.0), ("B", 25.0, 12.0)])
+}
+
+fn column_values(component: &mut Component, timer: &Timer) -> Vec<Vec<String>> {
+    component
+        .state(timer, &GeneralLayoutSettings::default())
+        .splits
+        .into_iter()
+        .map(|s| s.columns.into_iter().map(|c| c.value).collect())
+        .collect()
+}
+
+#[test]
+fn default_columns() {
+    let timer = timer();
+    let mut component = Component::new();
+    assert_eq!(
+        column_values(&mut component, &timer),
+        [["", "0:10"], ["", "0:25"]]
+    );
+}
+
+#[test]
+fn configured_columns() {
+    let timer = timer();
+    let mut component = Component::new();
+
+    // Replace the default columns with a Segment Time, a Delta and a Best
+    // Segment column.
+    component.set_value(6, Value::UInt(3));
+    component.set_value(8, Value::ColumnType(ColumnType::SegmentTime));
+    component.set_value(12, Value::ColumnType(ColumnType::Delta));
+    component.set_value(13, Value::OptionalString(Some("Best Segments".into())));
+    component.set_value(15, Value::String("Best".into()));
+    component.set_value(16, Value::ColumnType(ColumnType::BestSegment));
+
+    assert_eq!(component.settings_description().fields.len(), 7 + 3 * 4 + 2);
+    assert_eq!(
+        column_values(&mut component, &timer),
+        [["0:10", "", "0:08"], ["0:15", "", "0:12"]]
+    );
+}
+
+fn column(column_type: ColumnType) -> ColumnSettings {
+    ColumnSettings {
+        column_type,
+        ..Default::default()
+    }
+}
+
+/// A component with a column for each kind of time that depends on the
+/// progress of the attempt.
+fn component() -> Component {
+    let mut component = Component::new();
+    component.settings_mut().columns = vec![
+        column(ColumnType::Delta),
+        column(ColumnType::SegmentDelta),
+        column(ColumnType::SplitTime),
+        column(ColumnType::SegmentTime),
+    ];
+    component
+}
+
+fn columns(component: &mut Component, timer: &Timer) -> Vec<Vec<(String, SemanticColor)>> {
+    component
+        .state(timer, &GeneralLayoutSettings::default())
+        .splits
+        .into_iter()
+        .map(|s| {
+            s.columns
+                .into_iter()
+                .map(|c| (c.value, c.semantic_color))
+                .collect()
+        })
+        .collect()
+}
+
+fn cells(cells: &[&[(&str, SemanticColor)]]) -> Vec<Vec<(String, SemanticColor)>> {
+    cells
+        .iter()
+        .map(|r| r.iter().map(|&(v, c)| (v.to_owned(), c)).collect())
+        .collect()
+}
+
+#[test]
+fn running() {
+    let mut timer = timer_with_splits(&[("A", 10.0, 8.0), ("B", 25.0, 12.0), ("C", 40.0, 14.0)]);
+    let mut component = component();
+    start_run(&mut timer);
+    split_at(&mut timer, 9.0);
+    split_at(&mut timer, 27.0);
+    timer.set_game_time(TimeSpan::from_seconds(28.0));
+
+    use self::SemanticColor::*;
+    assert_eq!(
+        columns(&mut component, &timer),
+        cells(&[
+            &[
+                ("−1.0", AheadGainingTime),
+                ("−1.0", AheadGainingTime),
+                ("0:09", Default),
+                ("0:09", Default),
+            ],
+            &[
+                ("+2.0", BehindLosingTime),
+                ("+3.0", BehindLosingTime),
+                ("0:27", Default),
+                ("0:18", Default),
+            ],
+            &[("", Default), ("", Default), ("0:40", Default), ("0:15", Default)],
+        ])
+    );
+}
+
+#[test]
+fn completed() {
+    let mut timer = timer_with_splits(&[("A", 10.0, 8.0), ("B", 25.0, 12.0), ("C", 40.0, 14.0)]);
+    let mut component = component();
+    start_run(&mut timer);
+    split_at(&mut timer, 7.0);
+    split_at(&mut timer, 27.0);
+    split_at(&mut timer, 40.0);
+
+    use self::SemanticColor::*;
+    assert_eq!(
+        columns(&mut component, &timer),
+        cells(&[
+            &[
+                ("−3.0", BestSegment),
+                ("−3.0", BestSegment),
+                ("0:07", Default),
+                ("0:07", Default),
+            ],
+            &[
+                ("+2.0", BehindLosingTime),
+                ("+5.0", BehindLosingTime),
+                ("0:27", Default),
+                ("0:20", Default),
+            ],
+            &[
+                ("+0.0", BestSegment),
+                ("−2.0", BestSegment),
+                ("0:40", Default),
+                ("0:13", Default),
+            ],
+        ])
+    );
+}
+
+#[test]
+fn comparison_override_and_timing_method() {
+    let mut timer = timer();
+    let mut component = Component::new();
+    component.settings_mut().columns = vec![
+        ColumnSettings {
+            comparison_override: Some(String::from("Best Segments")),
+            ..column(ColumnType::Delta)
+        },
+        ColumnSettings {
+            timing_method: Some(TimingMethod::RealTime),
+            ..column(ColumnType::SplitTime)
+        },
+    ];
+    start_run(&mut timer);
+    split_at(&mut timer, 9.0);
+
+    use self::SemanticColor::*;
+    assert_eq!(
+        columns(&mut component, &timer),
+        cells(&[
+            &[("+1.0", BehindLosingTime), ("0:00", Default)],
+            &[("", Default), ("0:25", Default)],
+        ])
+    );
+}
@@ -1,9 +1,15 @@
+use Timer;
 use std::io::Write;
 use serde_json::{to_writer, Result};
 use std::borrow::Cow;
 use settings::{Field, SettingsDescription, Value};
 use std::mem::replace;
 
+pub mod template;
+
+#[cfg(test)]
+mod tests;
+
 #[derive(Default, Clone)]
 pub struct Component {
     settings: Settings,
@@ -111,8 +117,17 @@ impl Component {
         }
     }
 
-    pub fn state(&self) -> State {
-        State(self.settings.text.clone())
+    /// Calculates the state of the component. The placeholders of the text
+    /// are replaced with their current values, as described in the
+    /// `template` module.
+    pub fn state(&self, timer: &Timer) -> State {
+        State(match self.settings.text {
+            Text::Center(ref text) => Text::Center(template::render(text, timer).into_owned()),
+            Text::Split(ref left, ref right) => Text::Split(
+                template::render(left, timer).into_owned(),
+                template::render(right, timer).into_owned(),
+            ),
+        })
     }
 
     pub fn settings_description(&self) -> SettingsDescription {
@@ -0,0 +1,105 @@
+//! Templates allow text to contain placeholders that get replaced by
+//! information about the run and the timer. A placeholder is a name enclosed
+//! in braces, optionally followed by an argument, like `{game}` or
+//! `{delta:Best Segments}`. Literal braces are written as `{{` and `}}`.
+//! Placeholders that aren't known are kept as they are.
+//!
+//! The following placeholders are available:
+//! - `{game}`: The name of the game.
+//! - `{category}`: The name of the category.
+//! - `{attempts}`: The amount of attempts.
+//! - `{variable:Name}`: The value of the speedrun.com variable with the name
+//!   provided.
+//! - `{sum_of_best}`: The Sum of Best Segments.
+//! - `{pb}`: The final time of the Personal Best.
+//! - `{current_segment}`: The name of the segment that is currently being run.
+//! - `{delta}` or `{delta:Comparison}`: The current delta to the current
+//!   comparison or the comparison provided.
+
+use {comparison, Timer, TimerPhase};
+use analysis::{delta, sum_of_segments};
+use time::formatter::{Delta, Regular, TimeFormatter};
+use std::borrow::Cow;
+
+/// Replaces all the placeholders in the template with their current values.
+/// - `template`: The text containing the placeholders.
+/// - `timer`: The timer to get the values from.
+///
+/// Returns the text with the placeholders replaced.
+pub fn render<'a>(template: &'a str, timer: &Timer) -> Cow<'a, str> {
+    if !template.contains(&['{', '}'][..]) {
+        return template.into();
+    }
+
+    let mut text = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(position) = rest.find(&['{', '}'][..]) {
+        text.push_str(&rest[..position]);
+        rest = &rest[position..];
+
+        if rest.starts_with("{{") || rest.starts_with("}}") {
+            text.push_str(&rest[..1]);
+            rest = &rest[2..];
+        } else if rest.starts_with('}') {
+            text.push('}');
+            rest = &rest[1..];
+        } else if let Some(end) = rest.find('}') {
+            match value(&rest[1..end], timer) {
+                Some(value) => text.push_str(&value),
+                None => text.push_str(&rest[..end + 1]),
+            }
+            rest = &rest[end + 1..];
+        } else {
+            break;
+        }
+    }
+
+    text.push_str(rest);
+
+    text.into()
+}
+
+fn value(placeholder: &str, timer: &Timer) -> Option<String> {
+    let (name, argument) = match placeholder.find(':') {
+        Some(index) => (&placeholder[..index], Some(&placeholder[index + 1..])),
+        None => (placeholder, None),
+    };
+
+    let run = timer.run();
+    let method = timer.current_timing_method();
+
+    Some(match (name, argument) {
+        ("game", None) => run.game_name().to_owned(),
+        ("category", None) => run.category_name().to_owned(),
+        ("attempts", None) => run.attempt_count().to_string(),
+        ("variable", Some(variable)) => run.metadata()
+            .variables()
+            .find(|&(name, _)| name == variable)
+            .map(|(_, value)| value.clone())
+            .unwrap_or_default(),
+        ("sum_of_best", None) => {
+            let time = sum_of_segments::calculate_best(run.segments(), false, true, method);
+            Regular::new().format(time).to_string()
+        }
+        ("pb", None) => {
+            let time = run.segments().last()?.personal_best_split_time()[method];
+            Regular::new().format(time).to_string()
+        }
+        ("current_segment", None) => match timer.current_phase() {
+            TimerPhase::Running | TimerPhase::Paused => {
+                run.segment(timer.current_split_index()?).name().to_owned()
+            }
+            _ => String::new(),
+        },
+        ("delta", comparison) => {
+            let comparison = match comparison {
+                Some(comparison) => run.comparisons().find(|&c| c == comparison)?,
+                None => comparison::or_current(None, timer),
+            };
+            let (delta, _) = delta::calculate(timer, comparison);
+            Delta::new().format(delta).to_string()
+        }
+        _ => return None,
+    })
+}
@@ -0,0 +1,57 @@
+use Timer;
+use tests_helper::run_with_splits;
+use super::Component;
+use super::template::render;
+
+fn timer() -> Timer {
+    let mut run = run_with_splits(&[("A", 10.0, 9.0), ("B", 25.0, 12.0)]);
+    run.set_game_name("Game");
+    run.set_category_name("Any%");
+    run.set_attempt_count(42);
+    run.metadata_mut().add_variable("Difficulty", "Hard");
+
+    Timer::new(run).unwrap()
+}
+
+#[test]
+fn run_information() {
+    let timer = timer();
+    assert_eq!(
+        render("{game} - {category} ({attempts} attempts)", &timer),
+        "Game - Any% (42 attempts)"
+    );
+    assert_eq!(render("{variable:Difficulty}", &timer), "Hard");
+    assert_eq!(render("{variable:Region}", &timer), "");
+    assert_eq!(render("{pb} / {sum_of_best}", &timer), "0:25 / 0:21");
+}
+
+#[test]
+fn current_segment() {
+    let mut timer = timer();
+    assert_eq!(render("[{current_segment}]", &timer), "[]");
+    timer.start();
+    assert_eq!(render("[{current_segment}]", &timer), "[A]");
+    timer.split();
+    assert_eq!(render("[{current_segment}]", &timer), "[B]");
+}
+
+#[test]
+fn escaping_and_unknown_placeholders() {
+    let timer = timer();
+    assert_eq!(render("{{game}}", &timer), "{game}");
+    assert_eq!(render("{unknown} {game", &timer), "{unknown} {game");
+    assert_eq!(render("{delta:Fake Comparison}", &timer), "{delta:Fake Comparison}");
+    assert_eq!(render("}{game}", &timer), "}Game");
+}
+
+#[test]
+fn state_renders_both_sides() {
+    let timer = timer();
+    let mut component = Component::new();
+    component.settings_mut().text.set_left("{game}");
+    component.settings_mut().text.set_right("{category}");
+    assert_eq!(component.name(), "{game} {category}");
+
+    let state = component.state(&timer);
+    assert_eq!(serde_json::to_string(&state).unwrap(), r#"{"Split":["Game","Any%"]}"#);
+}
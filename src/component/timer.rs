@@ -4,7 +4,7 @@ use analysis::split_color;
 use serde_json::{to_writer, Result};
 use std::io::Write;
 use std::borrow::Cow;
-use settings::{Color, Field, Gradient, SemanticColor, SettingsDescription, Value};
+use settings::{Color, Field, Font, Gradient, SemanticColor, SettingsDescription, Value};
 use palette::{Hsv, Rgb};
 
 #[derive(Default, Clone)]
@@ -22,6 +22,7 @@ pub struct Settings {
     pub show_gradient: bool,
     pub digits_format: DigitsFormat,
     pub accuracy: Accuracy,
+    pub timer_font: Option<Font>,
 }
 
 impl Default for Settings {
@@ -34,6 +35,7 @@ impl Default for Settings {
             show_gradient: true,
             digits_format: DigitsFormat::SingleDigitSeconds,
             accuracy: Accuracy::Hundredths,
+            timer_font: None,
         }
     }
 }
@@ -47,6 +49,8 @@ pub struct State {
     pub top_color: Color,
     pub bottom_color: Color,
     pub height: u32,
+    /// Overrides the timer font of the layout.
+    pub timer_font: Option<Font>,
 }
 
 impl State {
@@ -153,6 +157,7 @@ impl Component {
             top_color,
             bottom_color,
             height: self.settings.height,
+            timer_font: self.settings.timer_font.clone(),
         }
     }
 
@@ -165,6 +170,7 @@ impl Component {
             Field::new("Show Gradient".into(), self.settings.show_gradient.into()),
             Field::new("Digits Format".into(), self.settings.digits_format.into()),
             Field::new("Accuracy".into(), self.settings.accuracy.into()),
+            Field::new("Timer Font".into(), self.settings.timer_font.clone().into()),
         ])
     }
 
@@ -177,6 +183,7 @@ impl Component {
             4 => self.settings.show_gradient = value.into(),
             5 => self.settings.digits_format = value.into(),
             6 => self.settings.accuracy = value.into(),
+            7 => self.settings.timer_font = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
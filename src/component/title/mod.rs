@@ -2,7 +2,7 @@ use {Timer, TimerPhase};
 use serde_json::{to_writer, Result};
 use std::io::Write;
 use std::borrow::Cow;
-use settings::{Alignment, Color, Field, Gradient, SettingsDescription, Value};
+use settings::{Alignment, Color, Field, Font, Gradient, SettingsDescription, Value};
 
 #[cfg(test)]
 mod tests;
@@ -28,12 +28,14 @@ pub struct Settings {
     pub show_region: bool,
     pub show_platform: bool,
     pub show_variables: bool,
+    pub text_font: Option<Font>,
 }
 
 #[derive(Serialize, Deserialize)]
 pub struct State {
     pub background: Gradient,
     pub text_color: Option<Color>,
+    pub text_font: Option<Font>,
     pub icon_change: Option<String>,
     pub line1: String,
     pub line2: Option<String>,
@@ -60,6 +62,7 @@ impl Default for Settings {
             show_region: false,
             show_platform: false,
             show_variables: true,
+            text_font: None,
         }
     }
 }
@@ -176,6 +179,7 @@ impl Component {
         State {
             background: self.settings.background,
             text_color: self.settings.text_color,
+            text_font: self.settings.text_font.clone(),
             icon_change,
             finished_runs,
             attempts,
@@ -218,6 +222,7 @@ impl Component {
             Field::new("Show Region".into(), self.settings.show_region.into()),
             Field::new("Show Platform".into(), self.settings.show_platform.into()),
             Field::new("Show Variables".into(), self.settings.show_variables.into()),
+            Field::new("Text Font".into(), self.settings.text_font.clone().into()),
         ])
     }
 
@@ -235,6 +240,7 @@ impl Component {
             9 => self.settings.show_region = value.into(),
             10 => self.settings.show_platform = value.into(),
             11 => self.settings.show_variables = value.into(),
+            12 => self.settings.text_font = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
@@ -0,0 +1,221 @@
+use {DateTime, TimeSpan, TimeStamp, Timer, Utc};
+use analysis::session;
+use chrono::{FixedOffset, Local, TimeZone};
+use time::formatter::{Regular, TimeFormatter};
+use serde_json::{to_writer, Result};
+use std::io::Write;
+use std::fmt::{Display, Write as FmtWrite};
+use std::borrow::Cow;
+use settings::{Color, Field, Gradient, SettingsDescription, Value};
+use super::DEFAULT_INFO_TEXT_GRADIENT;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Clone)]
+pub struct Component {
+    settings: Settings,
+    created: TimeStamp,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub background: Gradient,
+    pub label_color: Option<Color>,
+    pub value_color: Option<Color>,
+    /// The chrono format string the wall clock is formatted with.
+    pub format: String,
+    /// The time zone of the wall clock. This is either `Local`, `UTC` or an
+    /// offset like `+02:00`.
+    pub time_zone: String,
+    /// Shows the time that passed since the component got created, which
+    /// usually is when the application started.
+    pub show_time_since_start: bool,
+    /// Shows the time that passed since the first attempt of the session.
+    pub show_session_time: bool,
+    /// The amount of minutes without any attempts after which a session is
+    /// considered over.
+    pub inactivity_gap: u64,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: DEFAULT_INFO_TEXT_GRADIENT,
+            label_color: None,
+            value_color: None,
+            format: String::from("%H:%M:%S"),
+            time_zone: String::from("Local"),
+            show_time_since_start: false,
+            show_session_time: false,
+            inactivity_gap: 60,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct State {
+    pub background: Gradient,
+    pub label_color: Option<Color>,
+    pub value_color: Option<Color>,
+    pub time: String,
+    pub time_since_start: Option<String>,
+    pub session_time: Option<String>,
+}
+
+impl State {
+    pub fn write_json<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        to_writer(writer, self)
+    }
+}
+
+impl Default for Component {
+    fn default() -> Self {
+        Self {
+            settings: Default::default(),
+            created: TimeStamp::now(),
+        }
+    }
+}
+
+impl Component {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn with_settings(settings: Settings) -> Self {
+        Self {
+            settings,
+            ..Default::default()
+        }
+    }
+
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    pub fn name(&self) -> Cow<str> {
+        "Wall Clock".into()
+    }
+
+    pub fn state(&self, timer: &Timer) -> State {
+        let now = Utc::now();
+
+        let time_since_start = if self.settings.show_time_since_start {
+            Some(Regular::new().format(TimeStamp::now() - self.created).to_string())
+        } else {
+            None
+        };
+
+        let session_time = if self.settings.show_session_time {
+            let inactivity_gap =
+                TimeSpan::from_seconds(60.0 * self.settings.inactivity_gap as f64);
+            let session = session::calculate(timer, inactivity_gap, now);
+            Some(Regular::new().format(session.duration).to_string())
+        } else {
+            None
+        };
+
+        State {
+            background: self.settings.background,
+            label_color: self.settings.label_color,
+            value_color: self.settings.value_color,
+            time: format_time(now, &self.settings.format, &self.settings.time_zone),
+            time_since_start,
+            session_time,
+        }
+    }
+
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new("Label Color".into(), self.settings.label_color.into()),
+            Field::new("Value Color".into(), self.settings.value_color.into()),
+            Field::new("Format".into(), self.settings.format.clone().into()),
+            Field::new("Time Zone".into(), self.settings.time_zone.clone().into()),
+            Field::new(
+                "Show Time Since Start".into(),
+                self.settings.show_time_since_start.into(),
+            ),
+            Field::new(
+                "Show Session Time".into(),
+                self.settings.show_session_time.into(),
+            ),
+            Field::new(
+                "Inactivity Gap (Minutes)".into(),
+                self.settings.inactivity_gap.into(),
+            ),
+        ])
+    }
+
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.label_color = value.into(),
+            2 => self.settings.value_color = value.into(),
+            3 => self.settings.format = value.into(),
+            4 => self.settings.time_zone = value.into(),
+            5 => self.settings.show_time_since_start = value.into(),
+            6 => self.settings.show_session_time = value.into(),
+            7 => self.settings.inactivity_gap = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
+
+/// Formats the date in the time zone provided. Unknown time zones fall back
+/// to the local time zone and invalid format strings result in an empty
+/// string.
+fn format_time(now: DateTime<Utc>, format: &str, time_zone: &str) -> String {
+    if time_zone.eq_ignore_ascii_case("UTC") {
+        format_in(now, &Utc, format)
+    } else if let Some(offset) = parse_offset(time_zone) {
+        format_in(now, &offset, format)
+    } else {
+        format_in(now, &Local, format)
+    }
+}
+
+fn format_in<Tz>(now: DateTime<Utc>, time_zone: &Tz, format: &str) -> String
+where
+    Tz: TimeZone,
+    Tz::Offset: Display,
+{
+    let mut time = String::new();
+    if write!(time, "{}", now.with_timezone(time_zone).format(format)).is_err() {
+        time.clear();
+    }
+    time
+}
+
+fn parse_offset(offset: &str) -> Option<FixedOffset> {
+    let sign = match offset.chars().next()? {
+        '+' => 1,
+        '-' => -1,
+        _ => return None,
+    };
+    let mut parts = offset[1..].splitn(2, ':');
+    let hours = parse_digits(parts.next()?)?;
+    let minutes = parts.next().map_or(Some(0), parse_digits)?;
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+    FixedOffset::east_opt(sign * (3600 * hours + 60 * minutes))
+}
+
+/// Parses a number of one or two ASCII digits. Anything else, like a second
+/// sign after the sign of the offset, is rejected.
+fn parse_digits(digits: &str) -> Option<i32> {
+    if digits.is_empty() || digits.len() > 2 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
@@ -0,0 +1,26 @@
+use {DateTime, Utc};
+use super::{format_time, parse_offset};
+
+fn now() -> DateTime<Utc> {
+    "2017-10-01T12:34:56Z".parse().unwrap()
+}
+
+#[test]
+fn fixed_time_zones() {
+    assert_eq!(format_time(now(), "%H:%M:%S", "UTC"), "12:34:56");
+    assert_eq!(format_time(now(), "%H:%M", "+02:00"), "14:34");
+    assert_eq!(format_time(now(), "%H:%M", "-05:30"), "07:04");
+    assert_eq!(format_time(now(), "%d.%m. %H", "+12"), "02.10. 00");
+}
+
+#[test]
+fn invalid_settings() {
+    assert_eq!(parse_offset("Local"), None);
+    assert_eq!(parse_offset("+25:00"), None);
+    assert_eq!(parse_offset("+02:xx"), None);
+    assert_eq!(parse_offset("+-5"), None);
+    assert_eq!(parse_offset("--3"), None);
+    assert_eq!(parse_offset("+02:-5"), None);
+    assert_eq!(parse_offset("+"), None);
+    assert_eq!(format_time(now(), "%Q", "UTC"), "");
+}
@@ -2,27 +2,38 @@ use std::borrow::Cow;
 use Timer;
 use super::{ComponentSettings, ComponentState, GeneralSettings};
 use settings::{SettingsDescription, Value};
-use component::{blank_space, current_comparison, current_pace, delta, detailed_timer, graph,
-                possible_time_save, previous_segment, separator, splits, sum_of_best, text, timer,
-                title, total_playtime};
+use component::{blank_space, comparison_table, container, current_comparison, current_pace,
+                delta, detailed_timer, graph, pb_chance, possible_time_save, previous_segment,
+                run_metadata, run_prediction, segment_history_graph, segment_statistics,
+                separator, session_statistics, splits, sum_of_best, text, timer, title,
+                total_playtime, wall_clock};
 
 #[derive(From, Clone)]
 pub enum Component {
     BlankSpace(blank_space::Component),
+    ComparisonTable(comparison_table::Component),
+    Container(container::Component),
     CurrentComparison(current_comparison::Component),
     CurrentPace(current_pace::Component),
     Delta(delta::Component),
     DetailedTimer(detailed_timer::Component),
     Graph(graph::Component),
+    PbChance(pb_chance::Component),
     PossibleTimeSave(possible_time_save::Component),
     PreviousSegment(previous_segment::Component),
+    RunMetadata(run_metadata::Component),
+    RunPrediction(run_prediction::Component),
+    SegmentHistoryGraph(segment_history_graph::Component),
+    SegmentStatistics(segment_statistics::Component),
     Separator(separator::Component),
+    SessionStatistics(session_statistics::Component),
     Splits(splits::Component),
     SumOfBest(sum_of_best::Component),
     Text(text::Component),
     Timer(timer::Component),
     Title(title::Component),
     TotalPlaytime(total_playtime::Component),
+    WallClock(wall_clock::Component),
 }
 
 impl Component {
@@ -31,6 +42,12 @@ impl Component {
             Component::BlankSpace(ref mut component) => {
                 ComponentState::BlankSpace(component.state(timer))
             }
+            Component::ComparisonTable(ref mut component) => {
+                ComponentState::ComparisonTable(component.state(timer, layout_settings))
+            }
+            Component::Container(ref mut component) => {
+                ComponentState::Container(component.state(timer, layout_settings))
+            }
             Component::CurrentComparison(ref mut component) => {
                 ComponentState::CurrentComparison(component.state(timer))
             }
@@ -46,22 +63,40 @@ impl Component {
             Component::Graph(ref mut component) => {
                 ComponentState::Graph(component.state(timer, layout_settings))
             }
+            Component::PbChance(ref mut component) => {
+                ComponentState::PbChance(component.state(timer))
+            }
             Component::PossibleTimeSave(ref mut component) => {
                 ComponentState::PossibleTimeSave(component.state(timer))
             }
             Component::PreviousSegment(ref mut component) => {
                 ComponentState::PreviousSegment(component.state(timer, layout_settings))
             }
+            Component::RunMetadata(ref mut component) => {
+                ComponentState::RunMetadata(component.state(timer))
+            }
+            Component::RunPrediction(ref mut component) => {
+                ComponentState::RunPrediction(component.state(timer, layout_settings))
+            }
+            Component::SegmentHistoryGraph(ref mut component) => {
+                ComponentState::SegmentHistoryGraph(component.state(timer, layout_settings))
+            }
+            Component::SegmentStatistics(ref mut component) => {
+                ComponentState::SegmentStatistics(component.state(timer))
+            }
             Component::Separator(ref mut component) => {
                 ComponentState::Separator(component.state(timer))
             }
+            Component::SessionStatistics(ref mut component) => {
+                ComponentState::SessionStatistics(component.state(timer))
+            }
             Component::Splits(ref mut component) => {
                 ComponentState::Splits(component.state(timer, layout_settings))
             }
             Component::SumOfBest(ref mut component) => {
                 ComponentState::SumOfBest(component.state(timer))
             }
-            Component::Text(ref mut component) => ComponentState::Text(component.state()),
+            Component::Text(ref mut component) => ComponentState::Text(component.state(timer)),
             Component::Timer(ref mut component) => {
                 ComponentState::Timer(component.state(timer, layout_settings))
             }
@@ -69,6 +104,9 @@ impl Component {
             Component::TotalPlaytime(ref mut component) => {
                 ComponentState::TotalPlaytime(component.state(timer))
             }
+            Component::WallClock(ref mut component) => {
+                ComponentState::WallClock(component.state(timer))
+            }
         }
     }
 
@@ -77,6 +115,12 @@ impl Component {
             Component::BlankSpace(ref component) => {
                 ComponentSettings::BlankSpace(component.settings().clone())
             }
+            Component::ComparisonTable(ref component) => {
+                ComponentSettings::ComparisonTable(component.settings().clone())
+            }
+            Component::Container(ref component) => {
+                ComponentSettings::Container(component.settings())
+            }
             Component::CurrentComparison(ref component) => {
                 ComponentSettings::CurrentComparison(component.settings().clone())
             }
@@ -92,13 +136,31 @@ impl Component {
             Component::Graph(ref component) => {
                 ComponentSettings::Graph(component.settings().clone())
             }
+            Component::PbChance(ref component) => {
+                ComponentSettings::PbChance(component.settings().clone())
+            }
             Component::PossibleTimeSave(ref component) => {
                 ComponentSettings::PossibleTimeSave(component.settings().clone())
             }
             Component::PreviousSegment(ref component) => {
                 ComponentSettings::PreviousSegment(component.settings().clone())
             }
+            Component::RunMetadata(ref component) => {
+                ComponentSettings::RunMetadata(component.settings().clone())
+            }
+            Component::RunPrediction(ref component) => {
+                ComponentSettings::RunPrediction(component.settings().clone())
+            }
+            Component::SegmentHistoryGraph(ref component) => {
+                ComponentSettings::SegmentHistoryGraph(component.settings().clone())
+            }
+            Component::SegmentStatistics(ref component) => {
+                ComponentSettings::SegmentStatistics(component.settings().clone())
+            }
             Component::Separator(_) => ComponentSettings::Separator,
+            Component::SessionStatistics(ref component) => {
+                ComponentSettings::SessionStatistics(component.settings().clone())
+            }
             Component::Splits(ref component) => {
                 ComponentSettings::Splits(component.settings().clone())
             }
@@ -115,43 +177,60 @@ impl Component {
             Component::TotalPlaytime(ref component) => {
                 ComponentSettings::TotalPlaytime(component.settings().clone())
             }
+            Component::WallClock(ref component) => {
+                ComponentSettings::WallClock(component.settings().clone())
+            }
         }
     }
 
     pub fn name(&self) -> Cow<str> {
         match *self {
             Component::BlankSpace(ref component) => component.name(),
+            Component::ComparisonTable(ref component) => component.name(),
+            Component::Container(ref component) => component.name(),
             Component::CurrentComparison(ref component) => component.name(),
             Component::CurrentPace(ref component) => component.name(),
             Component::Delta(ref component) => component.name(),
             Component::DetailedTimer(ref component) => component.name(),
             Component::Graph(ref component) => component.name(),
+            Component::PbChance(ref component) => component.name(),
             Component::PossibleTimeSave(ref component) => component.name(),
             Component::PreviousSegment(ref component) => component.name(),
+            Component::RunMetadata(ref component) => component.name(),
+            Component::RunPrediction(ref component) => component.name(),
+            Component::SegmentHistoryGraph(ref component) => component.name(),
+            Component::SegmentStatistics(ref component) => component.name(),
             Component::Separator(ref component) => component.name(),
+            Component::SessionStatistics(ref component) => component.name(),
             Component::Splits(ref component) => component.name(),
             Component::SumOfBest(ref component) => component.name(),
             Component::Text(ref component) => component.name(),
             Component::Timer(ref component) => component.name(),
             Component::Title(ref component) => component.name(),
             Component::TotalPlaytime(ref component) => component.name(),
+            Component::WallClock(ref component) => component.name(),
         }
     }
 
     pub fn scroll_up(&mut self) {
-        if let Component::Splits(ref mut component) = *self {
-            component.scroll_up();
+        match *self {
+            Component::Container(ref mut component) => component.scroll_up(),
+            Component::Splits(ref mut component) => component.scroll_up(),
+            _ => {}
         }
     }
 
     pub fn scroll_down(&mut self) {
-        if let Component::Splits(ref mut component) = *self {
-            component.scroll_down();
+        match *self {
+            Component::Container(ref mut component) => component.scroll_down(),
+            Component::Splits(ref mut component) => component.scroll_down(),
+            _ => {}
         }
     }
 
     pub fn remount(&mut self) {
         match *self {
+            Component::Container(ref mut component) => component.remount(),
             Component::DetailedTimer(ref mut component) => component.remount(),
             Component::Splits(ref mut component) => component.remount(),
             Component::Title(ref mut component) => component.remount(),
@@ -162,40 +241,64 @@ impl Component {
     pub fn settings_description(&self) -> SettingsDescription {
         match *self {
             Component::BlankSpace(ref component) => component.settings_description(),
+            Component::ComparisonTable(ref component) => component.settings_description(),
+            Component::Container(ref component) => component.settings_description(),
             Component::CurrentComparison(ref component) => component.settings_description(),
             Component::CurrentPace(ref component) => component.settings_description(),
             Component::Delta(ref component) => component.settings_description(),
             Component::DetailedTimer(ref component) => component.settings_description(),
             Component::Graph(ref component) => component.settings_description(),
+            Component::PbChance(ref component) => component.settings_description(),
             Component::PossibleTimeSave(ref component) => component.settings_description(),
             Component::PreviousSegment(ref component) => component.settings_description(),
+            Component::RunMetadata(ref component) => component.settings_description(),
+            Component::RunPrediction(ref component) => component.settings_description(),
+            Component::SegmentHistoryGraph(ref component) => component.settings_description(),
+            Component::SegmentStatistics(ref component) => component.settings_description(),
             Component::Separator(ref component) => component.settings_description(),
+            Component::SessionStatistics(ref component) => component.settings_description(),
             Component::Splits(ref component) => component.settings_description(),
             Component::SumOfBest(ref component) => component.settings_description(),
             Component::Text(ref component) => component.settings_description(),
             Component::Timer(ref component) => component.settings_description(),
             Component::Title(ref component) => component.settings_description(),
             Component::TotalPlaytime(ref component) => component.settings_description(),
+            Component::WallClock(ref component) => component.settings_description(),
         }
     }
 
     pub fn set_value(&mut self, index: usize, value: Value) {
         match *self {
             Component::BlankSpace(ref mut component) => component.set_value(index, value),
+            Component::ComparisonTable(ref mut component) => component.set_value(index, value),
+            Component::Container(ref mut component) => component.set_value(index, value),
             Component::CurrentComparison(ref mut component) => component.set_value(index, value),
             Component::CurrentPace(ref mut component) => component.set_value(index, value),
             Component::Delta(ref mut component) => component.set_value(index, value),
             Component::DetailedTimer(ref mut component) => component.set_value(index, value),
             Component::Graph(ref mut component) => component.set_value(index, value),
+            Component::PbChance(ref mut component) => component.set_value(index, value),
             Component::PossibleTimeSave(ref mut component) => component.set_value(index, value),
             Component::PreviousSegment(ref mut component) => component.set_value(index, value),
+            Component::RunMetadata(ref mut component) => component.set_value(index, value),
+            Component::RunPrediction(ref mut component) => component.set_value(index, value),
+            Component::SegmentHistoryGraph(ref mut component) => {
+                component.set_value(index, value)
+            }
+            Component::SegmentStatistics(ref mut component) => {
+                component.set_value(index, value)
+            }
             Component::Separator(ref mut component) => component.set_value(index, value),
+            Component::SessionStatistics(ref mut component) => {
+                component.set_value(index, value)
+            }
             Component::Splits(ref mut component) => component.set_value(index, value),
             Component::SumOfBest(ref mut component) => component.set_value(index, value),
             Component::Text(ref mut component) => component.set_value(index, value),
             Component::Timer(ref mut component) => component.set_value(index, value),
             Component::Title(ref mut component) => component.set_value(index, value),
             Component::TotalPlaytime(ref mut component) => component.set_value(index, value),
+            Component::WallClock(ref mut component) => component.set_value(index, value),
         }
     }
 }
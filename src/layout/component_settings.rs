@@ -1,25 +1,36 @@
 use super::Component;
-use component::{blank_space, current_comparison, current_pace, delta, detailed_timer, graph,
-                possible_time_save, previous_segment, separator, splits, sum_of_best, text, timer,
-                title, total_playtime};
+use component::{blank_space, comparison_table, container, current_comparison, current_pace,
+                delta, detailed_timer, graph, pb_chance, possible_time_save, previous_segment,
+                run_metadata, run_prediction, segment_history_graph, segment_statistics,
+                separator, session_statistics, splits, sum_of_best, text, timer, title,
+                total_playtime, wall_clock};
 
 #[derive(Clone, Serialize, Deserialize)]
 pub enum ComponentSettings {
     BlankSpace(blank_space::Settings),
+    ComparisonTable(comparison_table::Settings),
+    Container(container::Settings),
     CurrentComparison(current_comparison::Settings),
     CurrentPace(current_pace::Settings),
     Delta(delta::Settings),
     DetailedTimer(detailed_timer::Settings),
     Graph(graph::Settings),
+    PbChance(pb_chance::Settings),
     PossibleTimeSave(possible_time_save::Settings),
     PreviousSegment(previous_segment::Settings),
+    RunMetadata(run_metadata::Settings),
+    RunPrediction(run_prediction::Settings),
+    SegmentHistoryGraph(segment_history_graph::Settings),
+    SegmentStatistics(segment_statistics::Settings),
     Separator,
+    SessionStatistics(session_statistics::Settings),
     Splits(splits::Settings),
     SumOfBest(sum_of_best::Settings),
     Text(text::Settings),
     Timer(timer::Settings),
     Title(title::Settings),
     TotalPlaytime(total_playtime::Settings),
+    WallClock(wall_clock::Settings),
 }
 
 impl From<ComponentSettings> for Component {
@@ -28,6 +39,12 @@ impl From<ComponentSettings> for Component {
             ComponentSettings::BlankSpace(settings) => {
                 Component::BlankSpace(blank_space::Component::with_settings(settings))
             }
+            ComponentSettings::ComparisonTable(settings) => {
+                Component::ComparisonTable(comparison_table::Component::with_settings(settings))
+            }
+            ComponentSettings::Container(settings) => {
+                Component::Container(container::Component::with_settings(settings))
+            }
             ComponentSettings::CurrentComparison(settings) => {
                 Component::CurrentComparison(current_comparison::Component::with_settings(settings))
             }
@@ -43,13 +60,31 @@ impl From<ComponentSettings> for Component {
             ComponentSettings::Graph(settings) => {
                 Component::Graph(graph::Component::with_settings(settings))
             }
+            ComponentSettings::PbChance(settings) => {
+                Component::PbChance(pb_chance::Component::with_settings(settings))
+            }
             ComponentSettings::PossibleTimeSave(settings) => {
                 Component::PossibleTimeSave(possible_time_save::Component::with_settings(settings))
             }
             ComponentSettings::PreviousSegment(settings) => {
                 Component::PreviousSegment(previous_segment::Component::with_settings(settings))
             }
+            ComponentSettings::RunMetadata(settings) => {
+                Component::RunMetadata(run_metadata::Component::with_settings(settings))
+            }
+            ComponentSettings::RunPrediction(settings) => {
+                Component::RunPrediction(run_prediction::Component::with_settings(settings))
+            }
+            ComponentSettings::SegmentHistoryGraph(settings) => Component::SegmentHistoryGraph(
+                segment_history_graph::Component::with_settings(settings),
+            ),
+            ComponentSettings::SegmentStatistics(settings) => Component::SegmentStatistics(
+                segment_statistics::Component::with_settings(settings),
+            ),
             ComponentSettings::Separator => Component::Separator(separator::Component::new()),
+            ComponentSettings::SessionStatistics(settings) => Component::SessionStatistics(
+                session_statistics::Component::with_settings(settings),
+            ),
             ComponentSettings::Splits(settings) => {
                 Component::Splits(splits::Component::with_settings(settings))
             }
@@ -68,6 +103,9 @@ impl From<ComponentSettings> for Component {
             ComponentSettings::TotalPlaytime(settings) => {
                 Component::TotalPlaytime(total_playtime::Component::with_settings(settings))
             }
+            ComponentSettings::WallClock(settings) => {
+                Component::WallClock(wall_clock::Component::with_settings(settings))
+            }
         }
     }
 }
@@ -1,22 +1,135 @@
-use component::{blank_space, current_comparison, current_pace, delta, detailed_timer, graph,
-                possible_time_save, previous_segment, separator, splits, sum_of_best, text, timer,
-                title, total_playtime};
+use component::{blank_space, comparison_table, container, current_comparison, current_pace,
+                delta, detailed_timer, graph, pb_chance, possible_time_save, previous_segment,
+                run_metadata, run_prediction, segment_history_graph, segment_statistics,
+                separator, session_statistics, splits, sum_of_best, text, timer, title,
+                total_playtime, wall_clock};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 #[derive(Serialize, Deserialize)]
 pub enum ComponentState {
     BlankSpace(blank_space::State),
+    ComparisonTable(comparison_table::State),
+    Container(container::State),
     CurrentComparison(current_comparison::State),
     CurrentPace(current_pace::State),
     Delta(delta::State),
     DetailedTimer(detailed_timer::State),
     Graph(graph::State),
+    PbChance(pb_chance::State),
     PossibleTimeSave(possible_time_save::State),
     PreviousSegment(previous_segment::State),
+    RunMetadata(run_metadata::State),
+    RunPrediction(run_prediction::State),
+    SegmentHistoryGraph(segment_history_graph::State),
+    SegmentStatistics(segment_statistics::State),
     Separator(separator::State),
+    SessionStatistics(session_statistics::State),
     Splits(splits::State),
     SumOfBest(sum_of_best::State),
     Text(text::State),
     Timer(timer::State),
     Title(title::State),
     TotalPlaytime(total_playtime::State),
+    WallClock(wall_clock::State),
+}
+
+/// Describes how a component wants to be arranged when the components of a
+/// layout are placed next to each other.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HorizontalArrangement {
+    /// The component is drawn the same way as in a vertical layout.
+    Block,
+    /// Every label is stacked on top of its value and the resulting pairs are
+    /// placed next to each other.
+    Stacked,
+    /// Every row of the component becomes a column of its own.
+    Columns,
+}
+
+impl ComponentState {
+    pub fn horizontal_arrangement(&self) -> HorizontalArrangement {
+        match *self {
+            ComponentState::CurrentComparison(_)
+            | ComponentState::CurrentPace(_)
+            | ComponentState::Delta(_)
+            | ComponentState::PbChance(_)
+            | ComponentState::PossibleTimeSave(_)
+            | ComponentState::PreviousSegment(_)
+            | ComponentState::RunMetadata(_)
+            | ComponentState::SessionStatistics(_)
+            | ComponentState::SumOfBest(_)
+            | ComponentState::Text(text::State(text::Text::Split(..)))
+            | ComponentState::TotalPlaytime(_)
+            | ComponentState::WallClock(_) => HorizontalArrangement::Stacked,
+            ComponentState::Splits(_) => HorizontalArrangement::Columns,
+            _ => HorizontalArrangement::Block,
+        }
+    }
+}
+
+/// A component state the way it is serialized as part of a layout state. The
+/// horizontal arrangement is stored right next to the state, so frontends
+/// that place the components next to each other can arrange them the same
+/// way the renderer does.
+#[derive(Serialize)]
+struct SerializedState<'a> {
+    #[serde(flatten)]
+    state: &'a ComponentState,
+    horizontal_arrangement: HorizontalArrangement,
+}
+
+impl<'a> From<&'a ComponentState> for SerializedState<'a> {
+    fn from(state: &'a ComponentState) -> Self {
+        Self {
+            state,
+            horizontal_arrangement: state.horizontal_arrangement(),
+        }
+    }
+}
+
+/// The horizontal arrangement is derived from the state, so it is ignored
+/// when deserializing.
+#[derive(Deserialize)]
+struct DeserializedState {
+    #[serde(flatten)]
+    state: ComponentState,
+}
+
+/// Serializes a component state together with its horizontal arrangement.
+pub mod with_arrangement {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        state: &ComponentState,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        SerializedState::from(state).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<ComponentState, D::Error> {
+        DeserializedState::deserialize(deserializer).map(|s| s.state)
+    }
+}
+
+/// Serializes a list of component states together with their horizontal
+/// arrangements.
+pub mod with_arrangements {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        states: &[ComponentState],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(states.iter().map(SerializedState::from))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<ComponentState>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::<DeserializedState>::deserialize(deserializer)
+            .map(|states| states.into_iter().map(|s| s.state).collect())
+    }
 }
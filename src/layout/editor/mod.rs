@@ -1,15 +1,22 @@
-use super::{Component, Layout, LayoutState};
+use super::{Component, Entry, Layout, LayoutState, Visibility};
+use component::container::Child;
 use settings::Value;
 use std::result::Result as StdResult;
 use Timer;
 
 mod state;
+#[cfg(test)]
+mod tests;
 
 pub use self::state::{Buttons as ButtonsState, State};
 
+/// The layout editor allows modifying the components of a layout. Components
+/// inside of containers are addressed by their path, which consists of the
+/// indices of all the containers leading up to the component, followed by
+/// the index of the component itself.
 pub struct Editor {
     layout: Layout,
-    selected_component: usize,
+    selected_component: Vec<usize>,
 }
 
 quick_error! {
@@ -21,6 +28,98 @@ quick_error! {
 
 pub type Result<T> = StdResult<T, Error>;
 
+/// The list of components that the selected component is part of, which is
+/// either the layout itself or a container.
+enum Siblings<'a> {
+    Layout(&'a mut Layout),
+    Container(&'a mut Vec<Child>),
+}
+
+impl<'a> Siblings<'a> {
+    fn len(&self) -> usize {
+        match *self {
+            Siblings::Layout(ref layout) => layout.components.len(),
+            Siblings::Container(ref children) => children.len(),
+        }
+    }
+
+    fn get_mut(self, index: usize) -> &'a mut Component {
+        match self {
+            Siblings::Layout(layout) => &mut layout.components[index].component,
+            Siblings::Container(children) => &mut children[index].component,
+        }
+    }
+
+    fn visibility_mut(self, index: usize) -> &'a mut Visibility {
+        match self {
+            Siblings::Layout(layout) => layout.visibility_mut(index),
+            Siblings::Container(children) => &mut children[index].visibility,
+        }
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        match *self {
+            Siblings::Layout(ref mut layout) => layout.swap(a, b),
+            Siblings::Container(ref mut children) => children.swap(a, b),
+        }
+    }
+
+    fn insert(&mut self, index: usize, child: Child) {
+        match *self {
+            Siblings::Layout(ref mut layout) => {
+                layout.insert(index, child.component, child.visibility)
+            }
+            Siblings::Container(ref mut children) => children.insert(index, child),
+        }
+    }
+
+    fn remove(&mut self, index: usize) -> Child {
+        match *self {
+            Siblings::Layout(ref mut layout) => {
+                let (component, visibility) = layout.remove(index);
+                let mut child = Child::new(component);
+                child.visibility = visibility;
+                child
+            }
+            Siblings::Container(ref mut children) => children.remove(index),
+        }
+    }
+
+    fn duplicate(&mut self, index: usize) {
+        match *self {
+            Siblings::Layout(ref mut layout) => {
+                let entry = layout.components[index].clone();
+                layout.components.insert(index + 1, entry);
+            }
+            Siblings::Container(ref mut children) => {
+                let child = children[index].clone();
+                children.insert(index + 1, child);
+            }
+        }
+    }
+}
+
+/// Collects the paths of all the components in the order they are displayed,
+/// with the children of a container directly following the container.
+fn flatten(components: &[Entry]) -> Vec<Vec<usize>> {
+    fn visit(component: &Component, path: &mut Vec<usize>, paths: &mut Vec<Vec<usize>>) {
+        paths.push(path.clone());
+        if let Component::Container(ref container) = *component {
+            for (index, child) in container.children.iter().enumerate() {
+                path.push(index);
+                visit(&child.component, path, paths);
+                path.pop();
+            }
+        }
+    }
+
+    let mut paths = Vec::new();
+    for (index, entry) in components.iter().enumerate() {
+        visit(&entry.component, &mut vec![index], &mut paths);
+    }
+    paths
+}
+
 impl Editor {
     pub fn new(mut layout: Layout) -> Result<Self> {
         if layout.components.is_empty() {
@@ -31,7 +130,7 @@ impl Editor {
 
         Ok(Self {
             layout,
-            selected_component: 0,
+            selected_component: vec![0],
         })
     }
 
@@ -43,84 +142,234 @@ impl Editor {
         self.layout.state(timer)
     }
 
+    /// Selects the component at the index provided. Components are indexed
+    /// in the order they are displayed in, with the children of a container
+    /// directly following the container.
     pub fn select(&mut self, index: usize) {
-        if index < self.layout.components.len() {
-            self.selected_component = index;
+        if let Some(path) = flatten(&self.layout.components).into_iter().nth(index) {
+            self.selected_component = path;
+        }
+    }
+
+    /// Selects the component with the path provided.
+    pub fn select_path(&mut self, path: &[usize]) {
+        if self.component(path).is_some() {
+            self.selected_component = path.to_vec();
+        }
+    }
+
+    /// Accesses the path of the selected component.
+    pub fn selected_path(&self) -> &[usize] {
+        &self.selected_component
+    }
+
+    fn component(&self, path: &[usize]) -> Option<&Component> {
+        let (&first, rest) = path.split_first()?;
+        let mut component = &self.layout.components.get(first)?.component;
+        for &index in rest {
+            component = match *component {
+                Component::Container(ref container) => &container.children.get(index)?.component,
+                _ => return None,
+            };
+        }
+        Some(component)
+    }
+
+    fn selected(&self) -> &Component {
+        self.component(&self.selected_component).unwrap()
+    }
+
+    fn selected_visibility(&self) -> Visibility {
+        let path = &self.selected_component;
+        let (&index, parent) = path.split_last().unwrap();
+        if parent.is_empty() {
+            return self.layout.components[index].visibility.clone();
+        }
+        match *self.component(parent).unwrap() {
+            Component::Container(ref container) => container.children[index].visibility.clone(),
+            _ => unreachable!(),
         }
     }
 
+    fn selected_index(&self) -> usize {
+        *self.selected_component.last().unwrap()
+    }
+
+    /// Accesses the components that share their parent with the component
+    /// at the path provided.
+    fn siblings(&mut self, path: &[usize]) -> Siblings {
+        let mut siblings = Siblings::Layout(&mut self.layout);
+        for &index in &path[..path.len() - 1] {
+            siblings = match *siblings.get_mut(index) {
+                Component::Container(ref mut container) => {
+                    Siblings::Container(&mut container.children)
+                }
+                _ => unreachable!(),
+            };
+        }
+        siblings
+    }
+
+    fn selected_siblings(&mut self) -> Siblings {
+        let path = self.selected_component.clone();
+        self.siblings(&path)
+    }
+
+    /// Adds the component to the selected container. If the selected
+    /// component isn't a container, the component gets added to the end of
+    /// the container the selected component is part of instead.
     pub fn add_component<C: Into<Component>>(&mut self, component: C) {
-        self.selected_component = self.layout.components.len();
-        self.layout.push(component);
+        if let Component::Container(_) = *self.selected() {
+            self.selected_component.push(0);
+        }
+        let mut siblings = self.selected_siblings();
+        let index = siblings.len();
+        siblings.insert(index, Child::new(component));
+        *self.selected_component.last_mut().unwrap() = index;
+        self.layout.remount();
     }
 
     pub fn can_remove_component(&self) -> bool {
         // We need to ensure there's always at least one component.
-        self.layout.components.len() > 1
+        self.selected_component.len() > 1 || self.layout.components.len() > 1
     }
 
     pub fn remove_component(&mut self) {
         if self.can_remove_component() {
-            self.layout.components.remove(self.selected_component);
-            if self.selected_component >= self.layout.components.len() {
-                self.selected_component = self.layout.components.len() - 1;
+            let index = self.selected_index();
+            let mut siblings = self.selected_siblings();
+            siblings.remove(index);
+            let len = siblings.len();
+            if len == 0 {
+                self.selected_component.pop();
+            } else if index >= len {
+                *self.selected_component.last_mut().unwrap() = len - 1;
             }
             self.layout.remount();
         }
     }
 
     pub fn can_move_component_up(&self) -> bool {
-        self.selected_component > 0
+        self.selected_index() > 0
     }
 
     pub fn move_component_up(&mut self) {
         if self.can_move_component_up() {
-            self.layout
-                .components
-                .swap(self.selected_component, self.selected_component - 1);
-            self.selected_component -= 1;
+            let index = self.selected_index();
+            self.selected_siblings().swap(index, index - 1);
+            *self.selected_component.last_mut().unwrap() -= 1;
             self.layout.remount();
         }
     }
 
     pub fn can_move_component_down(&self) -> bool {
-        self.selected_component < self.layout.components.len() - 1
+        let path = &self.selected_component;
+        let len = if path.len() > 1 {
+            match *self.component(&path[..path.len() - 1]).unwrap() {
+                Component::Container(ref container) => container.children.len(),
+                _ => unreachable!(),
+            }
+        } else {
+            self.layout.components.len()
+        };
+        self.selected_index() < len - 1
     }
 
     pub fn move_component_down(&mut self) {
         if self.can_move_component_down() {
-            self.layout
-                .components
-                .swap(self.selected_component, self.selected_component + 1);
-            self.selected_component += 1;
+            let index = self.selected_index();
+            self.selected_siblings().swap(index, index + 1);
+            *self.selected_component.last_mut().unwrap() += 1;
             self.layout.remount();
         }
     }
 
+    /// Moves the selected component to where the component at the index
+    /// provided is. Both components need to be part of the same container.
     pub fn move_component(&mut self, dst_index: usize) {
-        if dst_index < self.layout.components.len() {
-            while self.selected_component > dst_index {
-                self.move_component_up();
-            }
-            while self.selected_component < dst_index {
-                self.move_component_down();
-            }
+        let dst = match flatten(&self.layout.components).into_iter().nth(dst_index) {
+            Some(dst) => dst,
+            None => return,
+        };
+        let parent = &self.selected_component[..self.selected_component.len() - 1];
+        if dst.len() != self.selected_component.len() || dst[..dst.len() - 1] != *parent {
+            return;
+        }
+        let dst_index = *dst.last().unwrap();
+        while self.selected_index() > dst_index {
+            self.move_component_up();
+        }
+        while self.selected_index() < dst_index {
+            self.move_component_down();
         }
     }
 
-    pub fn duplicate_component(&mut self) {
-        let index = self.selected_component;
-        let new_index = index + 1;
+    /// Returns whether the component right above the selected one is a
+    /// container that the selected component can be moved into.
+    pub fn can_move_component_into(&self) -> bool {
+        let mut path = self.selected_component.clone();
+        if *path.last().unwrap() == 0 {
+            return false;
+        }
+        *path.last_mut().unwrap() -= 1;
+        matches!(self.component(&path), Some(&Component::Container(_)))
+    }
 
-        let component = self.layout.components[index].clone();
-        self.layout.components.insert(new_index, component);
+    /// Moves the selected component to the end of the container right above
+    /// it.
+    pub fn move_component_into(&mut self) {
+        if self.can_move_component_into() {
+            let index = self.selected_index();
+            let child = self.selected_siblings().remove(index);
+            *self.selected_component.last_mut().unwrap() -= 1;
+            self.selected_component.push(0);
+            let mut siblings = self.selected_siblings();
+            let len = siblings.len();
+            siblings.insert(len, child);
+            *self.selected_component.last_mut().unwrap() = len;
+            self.layout.remount();
+        }
+    }
 
-        self.selected_component = new_index;
+    pub fn can_move_component_out(&self) -> bool {
+        self.selected_component.len() > 1
+    }
+
+    /// Moves the selected component out of its container, such that it
+    /// directly follows the container.
+    pub fn move_component_out(&mut self) {
+        if self.can_move_component_out() {
+            let index = self.selected_index();
+            let child = self.selected_siblings().remove(index);
+            self.selected_component.pop();
+            *self.selected_component.last_mut().unwrap() += 1;
+            let index = self.selected_index();
+            self.selected_siblings().insert(index, child);
+            self.layout.remount();
+        }
+    }
+
+    pub fn duplicate_component(&mut self) {
+        let index = self.selected_index();
+        self.selected_siblings().duplicate(index);
+        *self.selected_component.last_mut().unwrap() += 1;
         self.layout.remount();
     }
 
     pub fn set_component_settings_value(&mut self, index: usize, value: Value) {
-        self.layout.components[self.selected_component].set_value(index, value);
+        let path = self.selected_component.clone();
+        self.siblings(&path)
+            .get_mut(*path.last().unwrap())
+            .set_value(index, value);
+    }
+
+    /// Changes a setting of the selected component's visibility. The
+    /// settings are described by the editor state's component visibility.
+    pub fn set_component_visibility_value(&mut self, index: usize, value: Value) {
+        let path = self.selected_component.clone();
+        self.siblings(&path)
+            .visibility_mut(*path.last().unwrap())
+            .set_value(index, value);
     }
 
     pub fn set_general_settings_value(&mut self, index: usize, value: Value) {
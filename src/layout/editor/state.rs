@@ -1,14 +1,16 @@
 use serde_json::{to_writer, Result as JsonResult};
 use std::io::Write;
-use super::Editor;
+use super::{flatten, Editor};
 use settings::SettingsDescription;
 
 #[derive(Serialize, Deserialize)]
 pub struct State {
     pub components: Vec<String>,
+    pub component_depths: Vec<u32>,
     pub buttons: Buttons,
     pub selected_component: u32,
     pub component_settings: SettingsDescription,
+    pub component_visibility: SettingsDescription,
     pub general_settings: SettingsDescription,
 }
 
@@ -17,6 +19,8 @@ pub struct Buttons {
     pub can_remove: bool,
     pub can_move_up: bool,
     pub can_move_down: bool,
+    pub can_move_into: bool,
+    pub can_move_out: bool,
 }
 
 impl State {
@@ -30,24 +34,32 @@ impl State {
 
 impl Editor {
     pub fn state(&self) -> State {
-        let components = self.layout
-            .components
+        let paths = flatten(&self.layout.components);
+        let components = paths
             .iter()
-            .map(|c| c.name().into_owned())
+            .map(|p| self.component(p).unwrap().name().into_owned())
             .collect();
+        let component_depths = paths.iter().map(|p| p.len() as u32 - 1).collect();
+        let selected_component = paths
+            .iter()
+            .position(|p| *p == self.selected_component)
+            .unwrap();
 
         let buttons = Buttons {
             can_remove: self.can_remove_component(),
             can_move_up: self.can_move_component_up(),
             can_move_down: self.can_move_component_down(),
+            can_move_into: self.can_move_component_into(),
+            can_move_out: self.can_move_component_out(),
         };
 
         State {
             components,
+            component_depths,
             buttons,
-            selected_component: self.selected_component as u32,
-            component_settings: self.layout.components[self.selected_component]
-                .settings_description(),
+            selected_component: selected_component as u32,
+            component_settings: self.selected().settings_description(),
+            component_visibility: self.selected_visibility().settings_description(),
             general_settings: self.layout.general_settings().settings_description(),
         }
     }
@@ -0,0 +1,186 @@
+use Layout;
+use component::{container, separator, splits, timer, title};
+use settings::{LayoutDirection, Value};
+use layout::Visibility;
+use super::Editor;
+
+fn names(editor: &Editor) -> Vec<(String, u32)> {
+    let state = editor.state();
+    state
+        .components
+        .into_iter()
+        .zip(state.component_depths)
+        .collect()
+}
+
+fn name(name: &str, depth: u32) -> (String, u32) {
+    (name.to_owned(), depth)
+}
+
+fn editor_with_container() -> Editor {
+    let mut row = container::Component::new();
+    row.push(timer::Component::new());
+    row.push(splits::Component::new());
+
+    let mut layout = Layout::new();
+    layout.push(title::Component::new());
+    layout.push(row);
+    layout.push(separator::Component::new());
+    Editor::new(layout).unwrap()
+}
+
+#[test]
+fn components_inside_containers_are_listed() {
+    let editor = editor_with_container();
+    assert_eq!(
+        names(&editor),
+        [
+            name("Title", 0),
+            name("Row", 0),
+            name("Timer", 1),
+            name("Splits", 1),
+            name("Separator", 0),
+        ]
+    );
+}
+
+#[test]
+fn selecting_components_inside_containers() {
+    let mut editor = editor_with_container();
+    editor.select(3);
+    assert_eq!(editor.selected_path(), [1, 1]);
+    assert_eq!(editor.state().selected_component, 3);
+
+    let buttons = editor.state().buttons;
+    assert!(buttons.can_move_up);
+    assert!(!buttons.can_move_down);
+    assert!(buttons.can_move_out);
+
+    editor.select(5);
+    assert_eq!(editor.selected_path(), [1, 1]);
+    editor.select_path(&[0, 1]);
+    assert_eq!(editor.selected_path(), [1, 1]);
+}
+
+#[test]
+fn moving_components_inside_containers() {
+    let mut editor = editor_with_container();
+    editor.select(3);
+    editor.move_component_up();
+    assert_eq!(editor.selected_path(), [1, 0]);
+    assert_eq!(names(&editor)[2], name("Splits", 1));
+
+    // Components can only be moved within their container.
+    editor.move_component(4);
+    assert_eq!(editor.selected_path(), [1, 0]);
+    editor.move_component(3);
+    assert_eq!(editor.selected_path(), [1, 1]);
+    assert_eq!(names(&editor)[3], name("Splits", 1));
+}
+
+#[test]
+fn moving_components_into_and_out_of_containers() {
+    let mut editor = editor_with_container();
+    editor.select(4);
+    assert!(editor.can_move_component_into());
+    editor.move_component_into();
+    assert_eq!(editor.selected_path(), [1, 2]);
+    assert_eq!(names(&editor)[4], name("Separator", 1));
+
+    editor.select(2);
+    editor.move_component_out();
+    assert_eq!(editor.selected_path(), [2]);
+    assert_eq!(
+        names(&editor),
+        [
+            name("Title", 0),
+            name("Row", 0),
+            name("Splits", 1),
+            name("Separator", 1),
+            name("Timer", 0),
+        ]
+    );
+    assert!(editor.can_move_component_into());
+    editor.select(0);
+    assert!(!editor.can_move_component_into());
+    assert!(!editor.can_move_component_out());
+}
+
+#[test]
+fn adding_and_removing_components_inside_containers() {
+    let mut editor = editor_with_container();
+    editor.select(1);
+    editor.add_component(separator::Component::new());
+    assert_eq!(editor.selected_path(), [1, 2]);
+
+    editor.remove_component();
+    editor.remove_component();
+    assert_eq!(editor.selected_path(), [1, 0]);
+    editor.remove_component();
+    assert_eq!(editor.selected_path(), [1]);
+    assert_eq!(names(&editor)[1], name("Row", 0));
+
+    editor.duplicate_component();
+    assert_eq!(editor.selected_path(), [2]);
+    assert_eq!(editor.close().components.len(), 4);
+}
+
+#[test]
+fn changing_the_settings_of_containers() {
+    let mut editor = editor_with_container();
+    editor.select(1);
+    editor.set_component_settings_value(0, Value::LayoutDirection(LayoutDirection::Vertical));
+    editor.set_component_settings_value(2, Value::Float(2.0));
+    assert_eq!(names(&editor)[1], name("Column", 0));
+
+    let fields = editor.state().component_settings.fields;
+    assert_eq!(fields.len(), 3);
+    assert_eq!(fields[2].text, "Size of Splits");
+
+    editor.select(2);
+    editor.set_component_settings_value(2, Value::UInt(100));
+    let layout = editor.close();
+    match layout.components[1].component {
+        ::layout::Component::Container(ref container) => {
+            assert_eq!(container.children[1].size, 2.0);
+            match container.children[0].component {
+                ::layout::Component::Timer(ref timer) => assert_eq!(timer.settings().height, 100),
+                _ => panic!("Expected the timer component"),
+            }
+        }
+        _ => panic!("Expected the container component"),
+    }
+}
+
+#[test]
+fn visibility_moves_with_the_component() {
+    let mut editor = editor_with_container();
+    editor.select(4);
+    editor.set_component_visibility_value(0, Value::Bool(true));
+    assert_eq!(editor.state().component_visibility.fields.len(), 4);
+
+    editor.move_component_into();
+    editor.duplicate_component();
+    editor.select(0);
+    editor.set_component_visibility_value(3, Value::UInt(2));
+
+    let layout = editor.close();
+    assert_eq!(
+        layout.visibility(0),
+        Some(&Visibility {
+            only_after_segment: 2,
+            ..Default::default()
+        })
+    );
+    assert_eq!(layout.visibility(1), Some(&Visibility::default()));
+    assert_eq!(layout.visibility(2), None);
+    match layout.components[1].component {
+        ::layout::Component::Container(ref container) => {
+            assert_eq!(container.children.len(), 4);
+            assert!(!container.children[1].visibility.only_while_running);
+            assert!(container.children[2].visibility.only_while_running);
+            assert!(container.children[3].visibility.only_while_running);
+        }
+        _ => panic!("Expected the container component"),
+    }
+}
@@ -1,8 +1,9 @@
-use settings::{Color, Field, Gradient, SettingsDescription, Value};
+use settings::{Color, Field, Font, Gradient, LayoutDirection, SettingsDescription, Value};
 
 #[derive(Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct GeneralSettings {
+    pub direction: LayoutDirection,
     pub background: Gradient,
     pub best_segment_color: Color,
     pub ahead_gaining_time_color: Color,
@@ -15,11 +16,20 @@ pub struct GeneralSettings {
     pub thin_separators_color: Color,
     pub separators_color: Color,
     pub text_color: Color,
+    /// The font of the timers. If there's none, the frontend's font is used.
+    /// Like in LiveSplit, only the timer, the detailed timer, the title and
+    /// the splits component can override these fonts.
+    pub timer_font: Option<Font>,
+    /// The font of all the other times.
+    pub times_font: Option<Font>,
+    /// The font of all the other text.
+    pub text_font: Option<Font>,
 }
 
 impl Default for GeneralSettings {
     fn default() -> Self {
         Self {
+            direction: LayoutDirection::Vertical,
             background: Gradient::Plain(Color::hsla(0.0, 0.0, 0.06, 1.0)),
             best_segment_color: Color::hsla(50.0, 1.0, 0.5, 1.0),
             ahead_gaining_time_color: Color::hsla(136.0, 1.0, 0.4, 1.0),
@@ -32,6 +42,9 @@ impl Default for GeneralSettings {
             thin_separators_color: Color::hsla(0.0, 0.0, 1.0, 0.06),
             separators_color: Color::hsla(0.0, 0.0, 1.0, 0.35),
             text_color: Color::hsla(0.0, 0.0, 1.0, 1.0),
+            timer_font: None,
+            times_font: None,
+            text_font: None,
         }
     }
 }
@@ -63,6 +76,10 @@ impl GeneralSettings {
             Field::new("Thin Separators".into(), self.thin_separators_color.into()),
             Field::new("Separators".into(), self.separators_color.into()),
             Field::new("Text".into(), self.text_color.into()),
+            Field::new("Layout Direction".into(), self.direction.into()),
+            Field::new("Timer Font".into(), self.timer_font.clone().into()),
+            Field::new("Times Font".into(), self.times_font.clone().into()),
+            Field::new("Text Font".into(), self.text_font.clone().into()),
         ])
     }
 
@@ -80,6 +97,10 @@ impl GeneralSettings {
             9 => self.thin_separators_color = value.into(),
             10 => self.separators_color = value.into(),
             11 => self.text_color = value.into(),
+            12 => self.direction = value.into(),
+            13 => self.timer_font = value.into(),
+            14 => self.times_font = value.into(),
+            15 => self.text_font = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
@@ -1,10 +1,29 @@
 use {component, Timer};
-use super::{Component, GeneralSettings, LayoutSettings, LayoutState};
+use serde_json::{to_value, Value};
+use super::{Component, GeneralSettings, LayoutSettings, LayoutState, LayoutStateDiff, Visibility};
 
 #[derive(Clone)]
 pub struct Layout {
-    pub components: Vec<Component>,
+    pub components: Vec<Entry>,
     settings: GeneralSettings,
+    previous_state: Option<Value>,
+}
+
+/// A component of the layout, together with when it is visible.
+#[derive(Clone)]
+pub struct Entry {
+    pub component: Component,
+    pub visibility: Visibility,
+}
+
+impl Entry {
+    /// Creates an entry for the component that is always visible.
+    pub fn new<C: Into<Component>>(component: C) -> Self {
+        Self {
+            component: component.into(),
+            visibility: Visibility::default(),
+        }
+    }
 }
 
 impl Layout {
@@ -12,29 +31,38 @@ impl Layout {
         Self {
             components: Vec::new(),
             settings: GeneralSettings::default(),
+            previous_state: None,
         }
     }
 
     pub fn default_layout() -> Self {
         Self {
             components: vec![
-                component::title::Component::new().into(),
-                component::splits::Component::new().into(),
-                component::timer::Component::new().into(),
-                component::previous_segment::Component::new().into(),
+                Entry::new(component::title::Component::new()),
+                Entry::new(component::splits::Component::new()),
+                Entry::new(component::timer::Component::new()),
+                Entry::new(component::previous_segment::Component::new()),
             ],
             settings: GeneralSettings::default(),
+            previous_state: None,
         }
     }
 
     pub fn from_settings(layout_settings: LayoutSettings) -> Self {
+        // Settings without a visibility for some of the components show them
+        // all the time.
+        let mut visibility = layout_settings.visibility.into_iter();
         Self {
             components: layout_settings
                 .components
                 .into_iter()
-                .map(Into::into)
+                .map(|settings| Entry {
+                    component: settings.into(),
+                    visibility: visibility.next().unwrap_or_default(),
+                })
                 .collect(),
             settings: layout_settings.general,
+            previous_state: None,
         }
     }
 
@@ -46,8 +74,58 @@ impl Layout {
         &mut self.settings
     }
 
+    /// Adds the component to the end of the layout. It is always visible.
     pub fn push<C: Into<Component>>(&mut self, component: C) {
-        self.components.push(component.into());
+        self.push_with_visibility(component, Visibility::default());
+    }
+
+    /// Adds the component to the end of the layout, only showing it under the
+    /// conditions provided.
+    pub fn push_with_visibility<C>(&mut self, component: C, visibility: Visibility)
+    where
+        C: Into<Component>,
+    {
+        self.components.push(Entry {
+            component: component.into(),
+            visibility,
+        });
+    }
+
+    /// Inserts the component at the index provided, shifting all the
+    /// components after it.
+    pub fn insert(&mut self, index: usize, component: Component, visibility: Visibility) {
+        self.components.insert(
+            index,
+            Entry {
+                component,
+                visibility,
+            },
+        );
+    }
+
+    /// Removes the component at the index provided and returns it along with
+    /// its visibility.
+    pub fn remove(&mut self, index: usize) -> (Component, Visibility) {
+        let entry = self.components.remove(index);
+        (entry.component, entry.visibility)
+    }
+
+    /// Swaps the components at the indices provided.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        self.components.swap(a, b);
+    }
+
+    /// Accesses the conditions under which the component at the index
+    /// provided is visible. There are none if there's no component at that
+    /// index.
+    pub fn visibility(&self, index: usize) -> Option<&Visibility> {
+        self.components.get(index).map(|entry| &entry.visibility)
+    }
+
+    /// Grants mutable access to the conditions under which the component at
+    /// the index provided is visible.
+    pub fn visibility_mut(&mut self, index: usize) -> &mut Visibility {
+        &mut self.components[index].visibility
     }
 
     pub fn state(&mut self, timer: &Timer) -> LayoutState {
@@ -55,37 +133,67 @@ impl Layout {
         LayoutState {
             components: self.components
                 .iter_mut()
-                .map(|c| c.state(timer, settings))
+                .filter(|entry| entry.visibility.is_visible(timer))
+                .map(|entry| entry.component.state(timer, settings))
                 .collect(),
+            direction: self.settings.direction,
             background: self.settings.background,
             thin_separators_color: self.settings.thin_separators_color,
             separators_color: self.settings.separators_color,
             text_color: self.settings.text_color,
+            timer_font: self.settings.timer_font.clone(),
+            times_font: self.settings.times_font.clone(),
+            text_font: self.settings.text_font.clone(),
         }
     }
 
+    /// Calculates the layout state and returns only the changes to the state
+    /// returned by the previous call as a JSON Patch. The first call and the
+    /// first call after remounting replace the whole state.
+    ///
+    /// This costs more CPU time than `state` on every call, not less: the
+    /// whole state still gets calculated and serialized into a JSON value,
+    /// which is kept for the next call, and both JSON values are walked in
+    /// full to find the changes. What gets saved is the size of what the
+    /// frontend receives, so this is only worth it when passing the state to
+    /// the frontend is more expensive than that.
+    pub fn state_diff(&mut self, timer: &Timer) -> LayoutStateDiff {
+        let state = to_value(self.state(timer)).unwrap();
+        let diff = LayoutStateDiff::new(self.previous_state.as_ref(), &state);
+        self.previous_state = Some(state);
+        diff
+    }
+
     pub fn settings(&self) -> LayoutSettings {
         LayoutSettings {
-            components: self.components.iter().map(|c| c.settings()).collect(),
+            components: self.components
+                .iter()
+                .map(|entry| entry.component.settings())
+                .collect(),
             general: self.settings.clone(),
+            visibility: self.components
+                .iter()
+                .map(|entry| entry.visibility.clone())
+                .collect(),
         }
     }
 
     pub fn scroll_up(&mut self) {
-        for component in &mut self.components {
-            component.scroll_up();
+        for entry in &mut self.components {
+            entry.component.scroll_up();
         }
     }
 
     pub fn scroll_down(&mut self) {
-        for component in &mut self.components {
-            component.scroll_down();
+        for entry in &mut self.components {
+            entry.component.scroll_down();
         }
     }
 
     pub fn remount(&mut self) {
-        for component in &mut self.components {
-            component.remount();
+        self.previous_state = None;
+        for entry in &mut self.components {
+            entry.component.remount();
         }
     }
 }
@@ -1,4 +1,4 @@
-use super::{ComponentSettings, GeneralSettings};
+use super::{ComponentSettings, GeneralSettings, Visibility};
 use serde_json::{from_reader, to_writer, Result};
 use std::io::{Read, Write};
 
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 pub struct LayoutSettings {
     pub components: Vec<ComponentSettings>,
     pub general: GeneralSettings,
+    #[serde(default)]
+    pub visibility: Vec<Visibility>,
 }
 
 impl LayoutSettings {
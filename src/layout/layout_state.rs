@@ -1,15 +1,20 @@
 use super::ComponentState;
-use settings::{Color, Gradient};
+use settings::{Color, Font, Gradient, LayoutDirection};
 use serde_json::{to_writer, Result};
 use std::io::Write;
 
 #[derive(Serialize, Deserialize)]
 pub struct LayoutState {
+    #[serde(with = "::layout::with_arrangements")]
     pub components: Vec<ComponentState>,
+    pub direction: LayoutDirection,
     pub background: Gradient,
     pub thin_separators_color: Color,
     pub separators_color: Color,
     pub text_color: Color,
+    pub timer_font: Option<Font>,
+    pub times_font: Option<Font>,
+    pub text_font: Option<Font>,
 }
 
 impl LayoutState {
@@ -0,0 +1,111 @@
+use serde_json::{to_writer, Result, Value};
+use std::io::Write;
+
+/// A single operation of a JSON Patch as described by RFC 6902.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum PatchOperation {
+    Add { path: String, value: Value },
+    Remove { path: String },
+    Replace { path: String, value: Value },
+}
+
+/// Describes how the JSON representation of a layout state changed since the
+/// previous layout state. The operations form a JSON Patch, so frontends can
+/// apply them to the layout state they received before.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayoutStateDiff {
+    pub operations: Vec<PatchOperation>,
+}
+
+impl LayoutStateDiff {
+    /// Calculates the operations that turn the previous JSON value into the
+    /// current one. Without a previous value, the whole document is replaced.
+    pub fn new(previous: Option<&Value>, current: &Value) -> Self {
+        let mut operations = Vec::new();
+        if let Some(previous) = previous {
+            diff(&mut String::new(), previous, current, &mut operations);
+        } else {
+            operations.push(PatchOperation::Replace {
+                path: String::new(),
+                value: current.clone(),
+            });
+        }
+        Self { operations }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    pub fn write_json<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        to_writer(writer, &self.operations)
+    }
+}
+
+fn push_token(path: &mut String, token: &str) {
+    path.push('/');
+    for c in token.chars() {
+        match c {
+            '~' => path.push_str("~0"),
+            '/' => path.push_str("~1"),
+            c => path.push(c),
+        }
+    }
+}
+
+fn diff(path: &mut String, previous: &Value, current: &Value, ops: &mut Vec<PatchOperation>) {
+    let len = path.len();
+    match (previous, current) {
+        (Value::Object(previous), Value::Object(current)) => {
+            for key in previous.keys().filter(|k| !current.contains_key(*k)) {
+                push_token(path, key);
+                ops.push(PatchOperation::Remove { path: path.clone() });
+                path.truncate(len);
+            }
+            for (key, value) in current {
+                push_token(path, key);
+                if let Some(previous) = previous.get(key) {
+                    diff(path, previous, value, ops);
+                } else {
+                    ops.push(PatchOperation::Add {
+                        path: path.clone(),
+                        value: value.clone(),
+                    });
+                }
+                path.truncate(len);
+            }
+        }
+        (Value::Array(previous), Value::Array(current)) => {
+            for (index, (previous, current)) in previous.iter().zip(current).enumerate() {
+                push_token(path, &index.to_string());
+                diff(path, previous, current, ops);
+                path.truncate(len);
+            }
+            for (index, value) in current.iter().enumerate().skip(previous.len()) {
+                push_token(path, &index.to_string());
+                ops.push(PatchOperation::Add {
+                    path: path.clone(),
+                    value: value.clone(),
+                });
+                path.truncate(len);
+            }
+            // Removing from the back keeps the indices of the remaining
+            // elements intact.
+            for index in (current.len()..previous.len()).rev() {
+                push_token(path, &index.to_string());
+                ops.push(PatchOperation::Remove { path: path.clone() });
+                path.truncate(len);
+            }
+        }
+        (previous, current) => if previous != current {
+            ops.push(PatchOperation::Replace {
+                path: path.clone(),
+                value: current.clone(),
+            });
+        },
+    }
+}
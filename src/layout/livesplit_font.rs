@@ -0,0 +1,294 @@
+//! LiveSplit stores the fonts of a layout as `System.Drawing.Font` objects
+//! that are serialized with .NET's BinaryFormatter and encoded as base64. This
+//! module reads and writes exactly the records that make up such a font.
+//!
+//! A font that isn't set is stored as LiveSplit's default font of that kind,
+//! and LiveSplit's default fonts are read back as fonts that aren't set. The
+//! size of the font is stored as its scale relative to the default font's
+//! size.
+
+use std::io::{Read, Write};
+use base64::{self, STANDARD};
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
+use settings::{Font, FontStyle, FontWeight};
+
+const LIBRARY: &str = "System.Drawing, Version=4.0.0.0, Culture=neutral, \
+                       PublicKeyToken=b03f5f7f11d50a3a";
+const LIBRARY_ID: i32 = 2;
+
+const FONT_STYLE: &str = "System.Drawing.FontStyle";
+const GRAPHICS_UNIT: &str = "System.Drawing.GraphicsUnit";
+
+const BOLD: i32 = 1;
+const ITALIC: i32 = 2;
+const PIXEL: i32 = 2;
+const POINT: i32 = 3;
+
+const SERIALIZED_STREAM_HEADER: u8 = 0;
+const CLASS_WITH_MEMBERS_AND_TYPES: u8 = 5;
+const BINARY_OBJECT_STRING: u8 = 6;
+const BINARY_LIBRARY: u8 = 12;
+const MESSAGE_END: u8 = 11;
+
+const BINARY_TYPE_PRIMITIVE: u8 = 0;
+const BINARY_TYPE_STRING: u8 = 1;
+const BINARY_TYPE_CLASS: u8 = 4;
+const PRIMITIVE_INT32: u8 = 8;
+const PRIMITIVE_SINGLE: u8 = 11;
+
+/// The kinds of fonts a LiveSplit layout has, which differ in their default
+/// font.
+#[derive(Copy, Clone)]
+pub enum Kind {
+    Timer,
+    Times,
+    Text,
+}
+
+struct DefaultFont {
+    family: &'static str,
+    size: f32,
+    bold: bool,
+}
+
+impl Kind {
+    fn default_font(self) -> DefaultFont {
+        match self {
+            Kind::Timer => DefaultFont {
+                family: "Century Gothic",
+                size: 43.75,
+                bold: true,
+            },
+            Kind::Times => DefaultFont {
+                family: "Segoe UI",
+                size: 13.0,
+                bold: true,
+            },
+            Kind::Text => DefaultFont {
+                family: "Segoe UI",
+                size: 13.0,
+                bold: false,
+            },
+        }
+    }
+}
+
+/// Encodes the font as the base64 text that LiveSplit stores in its layout
+/// files.
+pub fn encode(font: Option<&Font>, kind: Kind) -> String {
+    let default = kind.default_font();
+    let (family, size, style) = match font {
+        Some(font) => {
+            let mut style = 0;
+            if font.weight.value() >= FontWeight::SemiBold.value() {
+                style |= BOLD;
+            }
+            if font.style == FontStyle::Italic {
+                style |= ITALIC;
+            }
+            (font.family.as_str(), default.size * font.scale, style)
+        }
+        None => (default.family, default.size, if default.bold { BOLD } else { 0 }),
+    };
+
+    let mut data = Vec::new();
+    write_font(&mut data, family, size, style).unwrap();
+    base64::encode_config(&data, STANDARD)
+}
+
+/// Decodes a font from the base64 text that LiveSplit stores in its layout
+/// files. Returns `Some(None)` for LiveSplit's default font of that kind.
+pub fn decode(text: &str, kind: Kind) -> Option<Option<Font>> {
+    let data = base64::decode_config(text.trim(), STANDARD).ok()?;
+    let (family, mut size, style, unit) = read_font(&mut &data[..])?;
+    if unit == POINT {
+        size *= 96.0 / 72.0;
+    }
+
+    let default = kind.default_font();
+    let bold = style & BOLD != 0;
+    if family == default.family && size == default.size && bold == default.bold
+        && style & ITALIC == 0
+    {
+        return Some(None);
+    }
+
+    Some(Some(Font {
+        family,
+        style: if style & ITALIC != 0 {
+            FontStyle::Italic
+        } else {
+            FontStyle::Normal
+        },
+        weight: if bold {
+            FontWeight::Bold
+        } else {
+            FontWeight::Normal
+        },
+        scale: size / default.size,
+    }))
+}
+
+fn write_string<W: Write>(writer: &mut W, text: &str) -> ::std::io::Result<()> {
+    // The length is encoded with 7 bits per byte, least significant first.
+    let mut len = text.len();
+    while len >= 0x80 {
+        writer.write_u8(len as u8 | 0x80)?;
+        len >>= 7;
+    }
+    writer.write_u8(len as u8)?;
+    writer.write_all(text.as_bytes())
+}
+
+fn write_enum<W: Write>(
+    writer: &mut W,
+    object_id: i32,
+    name: &str,
+    value: i32,
+) -> ::std::io::Result<()> {
+    writer.write_u8(CLASS_WITH_MEMBERS_AND_TYPES)?;
+    writer.write_i32::<LE>(object_id)?;
+    write_string(writer, name)?;
+    writer.write_i32::<LE>(1)?;
+    write_string(writer, "value__")?;
+    writer.write_all(&[BINARY_TYPE_PRIMITIVE, PRIMITIVE_INT32])?;
+    writer.write_i32::<LE>(LIBRARY_ID)?;
+    writer.write_i32::<LE>(value)
+}
+
+fn write_font<W: Write>(
+    writer: &mut W,
+    family: &str,
+    size: f32,
+    style: i32,
+) -> ::std::io::Result<()> {
+    writer.write_u8(SERIALIZED_STREAM_HEADER)?;
+    for &value in &[1, -1, 1, 0] {
+        writer.write_i32::<LE>(value)?;
+    }
+
+    writer.write_u8(BINARY_LIBRARY)?;
+    writer.write_i32::<LE>(LIBRARY_ID)?;
+    write_string(writer, LIBRARY)?;
+
+    writer.write_u8(CLASS_WITH_MEMBERS_AND_TYPES)?;
+    writer.write_i32::<LE>(1)?;
+    write_string(writer, "System.Drawing.Font")?;
+    writer.write_i32::<LE>(4)?;
+    for member in &["Name", "Size", "Style", "Unit"] {
+        write_string(writer, member)?;
+    }
+    writer.write_all(&[
+        BINARY_TYPE_STRING,
+        BINARY_TYPE_PRIMITIVE,
+        BINARY_TYPE_CLASS,
+        BINARY_TYPE_CLASS,
+        PRIMITIVE_SINGLE,
+    ])?;
+    for class in &[FONT_STYLE, GRAPHICS_UNIT] {
+        write_string(writer, class)?;
+        writer.write_i32::<LE>(LIBRARY_ID)?;
+    }
+    writer.write_i32::<LE>(LIBRARY_ID)?;
+
+    writer.write_u8(BINARY_OBJECT_STRING)?;
+    writer.write_i32::<LE>(3)?;
+    write_string(writer, family)?;
+    writer.write_f32::<LE>(size)?;
+    write_enum(writer, -4, FONT_STYLE, style)?;
+    write_enum(writer, -5, GRAPHICS_UNIT, PIXEL)?;
+
+    writer.write_u8(MESSAGE_END)
+}
+
+fn read_string<R: Read>(reader: &mut R) -> Option<String> {
+    let mut len = 0;
+    for shift in 0..5 {
+        let byte = reader.read_u8().ok()?;
+        len |= ((byte & 0x7F) as usize) << (7 * shift);
+        if byte & 0x80 == 0 {
+            let mut buf = Vec::new();
+            reader.take(len as u64).read_to_end(&mut buf).ok()?;
+            if buf.len() != len {
+                return None;
+            }
+            return String::from_utf8(buf).ok();
+        }
+    }
+    None
+}
+
+fn expect_string<R: Read>(reader: &mut R, expected: &str) -> Option<()> {
+    if read_string(reader)? == expected {
+        Some(())
+    } else {
+        None
+    }
+}
+
+fn expect_u8<R: Read>(reader: &mut R, expected: u8) -> Option<()> {
+    if reader.read_u8().ok()? == expected {
+        Some(())
+    } else {
+        None
+    }
+}
+
+fn read_enum<R: Read>(reader: &mut R, name: &str) -> Option<i32> {
+    expect_u8(reader, CLASS_WITH_MEMBERS_AND_TYPES)?;
+    reader.read_i32::<LE>().ok()?;
+    expect_string(reader, name)?;
+    if reader.read_i32::<LE>().ok()? != 1 {
+        return None;
+    }
+    expect_string(reader, "value__")?;
+    expect_u8(reader, BINARY_TYPE_PRIMITIVE)?;
+    expect_u8(reader, PRIMITIVE_INT32)?;
+    reader.read_i32::<LE>().ok()?;
+    reader.read_i32::<LE>().ok()
+}
+
+fn read_font<R: Read>(reader: &mut R) -> Option<(String, f32, i32, i32)> {
+    expect_u8(reader, SERIALIZED_STREAM_HEADER)?;
+    for _ in 0..4 {
+        reader.read_i32::<LE>().ok()?;
+    }
+
+    expect_u8(reader, BINARY_LIBRARY)?;
+    reader.read_i32::<LE>().ok()?;
+    read_string(reader)?;
+
+    expect_u8(reader, CLASS_WITH_MEMBERS_AND_TYPES)?;
+    reader.read_i32::<LE>().ok()?;
+    expect_string(reader, "System.Drawing.Font")?;
+    if reader.read_i32::<LE>().ok()? != 4 {
+        return None;
+    }
+    for member in &["Name", "Size", "Style", "Unit"] {
+        expect_string(reader, member)?;
+    }
+    for &binary_type in &[
+        BINARY_TYPE_STRING,
+        BINARY_TYPE_PRIMITIVE,
+        BINARY_TYPE_CLASS,
+        BINARY_TYPE_CLASS,
+        PRIMITIVE_SINGLE,
+    ] {
+        expect_u8(reader, binary_type)?;
+    }
+    for class in &[FONT_STYLE, GRAPHICS_UNIT] {
+        expect_string(reader, class)?;
+        reader.read_i32::<LE>().ok()?;
+    }
+    reader.read_i32::<LE>().ok()?;
+
+    expect_u8(reader, BINARY_OBJECT_STRING)?;
+    reader.read_i32::<LE>().ok()?;
+    let family = read_string(reader)?;
+    let size = reader.read_f32::<LE>().ok()?;
+    let style = read_enum(reader, FONT_STYLE)?;
+    let unit = read_enum(reader, GRAPHICS_UNIT)?;
+    expect_u8(reader, MESSAGE_END)?;
+
+    Some((family, size, style, unit))
+}
@@ -1,17 +1,25 @@
 pub mod editor;
+pub mod parser;
+pub mod saver;
 mod component_settings;
 mod component_state;
 mod component;
 mod layout_settings;
 mod layout_state;
+mod layout_state_diff;
+mod visibility;
 mod layout;
 mod general_settings;
+mod livesplit_font;
 
 pub use self::component_settings::ComponentSettings;
-pub use self::component_state::ComponentState;
+pub use self::component_state::{ComponentState, HorizontalArrangement};
+pub(crate) use self::component_state::{with_arrangement, with_arrangements};
 pub use self::component::Component;
 pub use self::layout_settings::LayoutSettings;
 pub use self::layout_state::LayoutState;
-pub use self::layout::Layout;
+pub use self::layout_state_diff::{LayoutStateDiff, PatchOperation};
+pub use self::layout::{Entry, Layout};
 pub use self::general_settings::GeneralSettings;
+pub use self::visibility::Visibility;
 pub use self::editor::Editor;
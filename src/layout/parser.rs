@@ -0,0 +1,1107 @@
+//! Provides a parser for LiveSplit's Layout files (`.lsl`). Components and
+//! settings that don't have a counterpart in livesplit-core don't cause the
+//! import to fail. Instead they are reported as warnings.
+
+use std::io::BufRead;
+use quick_xml::reader::Reader;
+use component::splits::ColumnType;
+use component::{
+    blank_space, current_comparison, current_pace, delta, detailed_timer, graph,
+    possible_time_save, previous_segment, separator, splits, sum_of_best, text, timer, title,
+    total_playtime,
+};
+use settings::{Alignment, Color, Gradient, LayoutDirection};
+use time::formatter::{Accuracy, DigitsFormat};
+use xml_util::{self, end_tag, parse_base, parse_children, Tag};
+use TimingMethod;
+use super::{Component, GeneralSettings, Layout};
+use super::livesplit_font::{self, Kind};
+
+pub use xml_util::{Error, Result};
+
+pub struct ParsedLayout {
+    pub layout: Layout,
+    pub warnings: Vec<String>,
+}
+
+#[derive(Copy, Clone)]
+enum GradientKind {
+    Plain,
+    Vertical,
+    Horizontal,
+}
+
+/// Collects the individual settings that make up a background, as LiveSplit
+/// stores the colors and the kind of gradient separately.
+#[derive(Default)]
+struct GradientBuilder {
+    kind: Option<GradientKind>,
+    first: Option<Color>,
+    second: Option<Color>,
+}
+
+impl GradientBuilder {
+    fn parse<R>(
+        &mut self,
+        reader: &mut Reader<R>,
+        tag: Tag,
+        warnings: &mut Vec<String>,
+    ) -> Result<()>
+    where
+        R: BufRead,
+    {
+        match tag.name() {
+            b"BackgroundColor" | b"CurrentSplitTopColor" => {
+                value(reader, tag, warnings, parse_color, |c| self.first = Some(c))
+            }
+            b"BackgroundColor2" | b"CurrentSplitBottomColor" => {
+                value(reader, tag, warnings, parse_color, |c| {
+                    self.second = Some(c)
+                })
+            }
+            _ => value(reader, tag, warnings, parse_gradient_kind, |k| {
+                self.kind = Some(k)
+            }),
+        }
+    }
+
+    fn build(self, default: Gradient) -> Gradient {
+        if self.kind.is_none() && self.first.is_none() && self.second.is_none() {
+            return default;
+        }
+        let first = self.first.unwrap_or_else(Color::transparent);
+        let second = self.second.unwrap_or_else(Color::transparent);
+        match self.kind.unwrap_or(GradientKind::Plain) {
+            GradientKind::Plain => Gradient::Plain(first),
+            GradientKind::Vertical => Gradient::Vertical(first, second),
+            GradientKind::Horizontal => Gradient::Horizontal(first, second),
+        }
+    }
+}
+
+/// The label and value colors of the components that show a single line of
+/// information.
+#[derive(Default)]
+struct InfoTextColors {
+    label: ColorOverride,
+    value: ColorOverride,
+}
+
+impl InfoTextColors {
+    fn parse<R>(
+        &mut self,
+        reader: &mut Reader<R>,
+        tag: Tag,
+        warnings: &mut Vec<String>,
+    ) -> Result<()>
+    where
+        R: BufRead,
+    {
+        match tag.name() {
+            b"OverrideTextColor" => value(reader, tag, warnings, parse_bool, |b| {
+                self.label.enabled = b
+            }),
+            b"TextColor" => value(reader, tag, warnings, parse_color, |c| {
+                self.label.color = Some(c)
+            }),
+            b"OverrideTimeColor" => value(reader, tag, warnings, parse_bool, |b| {
+                self.value.enabled = b
+            }),
+            _ => value(reader, tag, warnings, parse_color, |c| {
+                self.value.color = Some(c)
+            }),
+        }
+    }
+}
+
+/// LiveSplit stores optional colors as a color and a separate flag that
+/// decides whether the color is used at all.
+#[derive(Default)]
+struct ColorOverride {
+    enabled: bool,
+    color: Option<Color>,
+}
+
+impl ColorOverride {
+    fn get(self) -> Option<Color> {
+        if self.enabled {
+            self.color
+        } else {
+            None
+        }
+    }
+}
+
+fn value<R, T, P, F>(
+    reader: &mut Reader<R>,
+    tag: Tag,
+    warnings: &mut Vec<String>,
+    parse: P,
+    f: F,
+) -> Result<()>
+where
+    R: BufRead,
+    P: FnOnce(&str) -> Option<T>,
+    F: FnOnce(T),
+{
+    let name = String::from_utf8_lossy(tag.name()).into_owned();
+    xml_util::text(reader, tag.into_buf(), |t| match parse(&t) {
+        Some(v) => f(v),
+        None => warnings.push(format!("Invalid value {:?} for {}", t, name)),
+    })
+}
+
+fn unsupported<R>(reader: &mut Reader<R>, tag: Tag, warnings: &mut Vec<String>) -> Result<()>
+where
+    R: BufRead,
+{
+    if tag.name() != b"Version" {
+        warnings.push(format!(
+            "Unsupported setting {}",
+            String::from_utf8_lossy(tag.name())
+        ));
+    }
+    end_tag(reader, tag.into_buf())
+}
+
+fn parse_bool(text: &str) -> Option<bool> {
+    match text {
+        "True" | "true" => Some(true),
+        "False" | "false" => Some(false),
+        _ => None,
+    }
+}
+
+fn parse_color(text: &str) -> Option<Color> {
+    if text.len() != 8 {
+        return None;
+    }
+    let argb = u32::from_str_radix(text, 16).ok()?;
+    let channel = |shift: u32| ((argb >> shift) & 0xFF) as f32 / 255.0;
+    Some((channel(16), channel(8), channel(0), channel(24)).into())
+}
+
+fn parse_height(text: &str) -> Option<u32> {
+    text.parse::<f32>()
+        .ok()
+        .filter(|h| *h >= 0.0)
+        .map(|h| h.round() as u32)
+}
+
+fn parse_count(text: &str) -> Option<usize> {
+    text.parse().ok()
+}
+
+fn parse_string(text: &str) -> Option<String> {
+    Some(text.to_owned())
+}
+
+fn parse_gradient_kind(text: &str) -> Option<GradientKind> {
+    match text {
+        "Plain" => Some(GradientKind::Plain),
+        "Vertical" => Some(GradientKind::Vertical),
+        "Horizontal" => Some(GradientKind::Horizontal),
+        _ => None,
+    }
+}
+
+fn parse_accuracy(text: &str) -> Option<Accuracy> {
+    match text {
+        "Seconds" => Some(Accuracy::Seconds),
+        "Tenths" => Some(Accuracy::Tenths),
+        "Hundredths" => Some(Accuracy::Hundredths),
+        _ => None,
+    }
+}
+
+fn parse_comparison(text: &str) -> Option<Option<String>> {
+    Some(match text {
+        "Current Comparison" => None,
+        comparison => Some(comparison.to_owned()),
+    })
+}
+
+fn parse_timing_method(text: &str) -> Option<Option<TimingMethod>> {
+    match text {
+        "Current Timing Method" => Some(None),
+        "Real Time" => Some(Some(TimingMethod::RealTime)),
+        "Game Time" => Some(Some(TimingMethod::GameTime)),
+        _ => None,
+    }
+}
+
+fn parse_timer_format(text: &str) -> Option<(DigitsFormat, Accuracy)> {
+    let mut parts = text.splitn(2, '.');
+    let digits_format = match parts.next()? {
+        "1" => DigitsFormat::SingleDigitSeconds,
+        "01" => DigitsFormat::DoubleDigitSeconds,
+        "0:01" => DigitsFormat::SingleDigitMinutes,
+        "00:01" => DigitsFormat::DoubleDigitMinutes,
+        "0:00:01" => DigitsFormat::SingleDigitHours,
+        "00:00:01" => DigitsFormat::DoubleDigitHours,
+        _ => return None,
+    };
+    let accuracy = match parts.next() {
+        None => Accuracy::Seconds,
+        Some("2") => Accuracy::Tenths,
+        Some("23") => Accuracy::Hundredths,
+        _ => return None,
+    };
+    Some((digits_format, accuracy))
+}
+
+fn parse_column_type(text: &str) -> Option<ColumnType> {
+    match text {
+        "Delta" => Some(ColumnType::Delta),
+        "SplitTime" => Some(ColumnType::SplitTime),
+        "SegmentDelta" => Some(ColumnType::SegmentDelta),
+        "SegmentTime" => Some(ColumnType::SegmentTime),
+        _ => None,
+    }
+}
+
+fn general_settings<R>(
+    reader: &mut Reader<R>,
+    buf: &mut Vec<u8>,
+    settings: &mut GeneralSettings,
+    warnings: &mut Vec<String>,
+) -> Result<()>
+where
+    R: BufRead,
+{
+    let (mut first, mut second) = (None, None);
+    let mut background_type = String::from("SolidColor");
+
+    parse_children(reader, buf, |reader, tag| match tag.name() {
+        b"TextColor" => value(reader, tag, warnings, parse_color, |c| {
+            settings.text_color = c
+        }),
+        b"BackgroundColor" => value(reader, tag, warnings, parse_color, |c| first = Some(c)),
+        b"BackgroundColor2" => value(reader, tag, warnings, parse_color, |c| second = Some(c)),
+        b"BackgroundType" => value(reader, tag, warnings, parse_string, |t| background_type = t),
+        b"ThinSeparatorsColor" => value(reader, tag, warnings, parse_color, |c| {
+            settings.thin_separators_color = c
+        }),
+        b"SeparatorsColor" => value(reader, tag, warnings, parse_color, |c| {
+            settings.separators_color = c
+        }),
+        b"PersonalBestColor" => value(reader, tag, warnings, parse_color, |c| {
+            settings.personal_best_color = c
+        }),
+        b"AheadGainingTimeColor" => value(reader, tag, warnings, parse_color, |c| {
+            settings.ahead_gaining_time_color = c
+        }),
+        b"AheadLosingTimeColor" => value(reader, tag, warnings, parse_color, |c| {
+            settings.ahead_losing_time_color = c
+        }),
+        b"BehindGainingTimeColor" => value(reader, tag, warnings, parse_color, |c| {
+            settings.behind_gaining_time_color = c
+        }),
+        b"BehindLosingTimeColor" => value(reader, tag, warnings, parse_color, |c| {
+            settings.behind_losing_time_color = c
+        }),
+        b"BestSegmentColor" => value(reader, tag, warnings, parse_color, |c| {
+            settings.best_segment_color = c
+        }),
+        b"NotRunningColor" => value(reader, tag, warnings, parse_color, |c| {
+            settings.not_running_color = c
+        }),
+        b"PausedColor" => value(reader, tag, warnings, parse_color, |c| {
+            settings.paused_color = c
+        }),
+        b"TimerFont" => value(
+            reader,
+            tag,
+            warnings,
+            |t| livesplit_font::decode(t, Kind::Timer),
+            |f| settings.timer_font = f,
+        ),
+        b"TimesFont" => value(
+            reader,
+            tag,
+            warnings,
+            |t| livesplit_font::decode(t, Kind::Times),
+            |f| settings.times_font = f,
+        ),
+        b"TextFont" => value(
+            reader,
+            tag,
+            warnings,
+            |t| livesplit_font::decode(t, Kind::Text),
+            |f| settings.text_font = f,
+        ),
+        _ => unsupported(reader, tag, warnings),
+    })?;
+
+    let first = first.unwrap_or_else(Color::transparent);
+    let second = second.unwrap_or_else(Color::transparent);
+    settings.background = match &*background_type {
+        "VerticalGradient" => Gradient::Vertical(first, second),
+        "HorizontalGradient" => Gradient::Horizontal(first, second),
+        "SolidColor" => Gradient::Plain(first),
+        other => {
+            warnings.push(format!("Unsupported background type {:?}", other));
+            Gradient::Plain(first)
+        }
+    };
+
+    Ok(())
+}
+
+fn title<R>(
+    reader: &mut Reader<R>,
+    buf: &mut Vec<u8>,
+    warnings: &mut Vec<String>,
+) -> Result<Component>
+where
+    R: BufRead,
+{
+    let mut settings = title::Settings::default();
+    let mut background = GradientBuilder::default();
+    let mut text_color = ColorOverride::default();
+
+    parse_children(reader, buf, |reader, tag| match tag.name() {
+        b"BackgroundColor" | b"BackgroundColor2" | b"BackgroundGradient" => {
+            background.parse(reader, tag, warnings)
+        }
+        b"ShowGameName" => value(reader, tag, warnings, parse_bool, |b| {
+            settings.show_game_name = b
+        }),
+        b"ShowCategoryName" => value(reader, tag, warnings, parse_bool, |b| {
+            settings.show_category_name = b
+        }),
+        b"ShowFinishedRunsCount" => value(reader, tag, warnings, parse_bool, |b| {
+            settings.show_finished_runs_count = b
+        }),
+        b"ShowAttemptCount" => value(reader, tag, warnings, parse_bool, |b| {
+            settings.show_attempt_count = b
+        }),
+        b"SingleLine" => value(reader, tag, warnings, parse_bool, |b| {
+            settings.display_as_single_line = b
+        }),
+        b"DisplayGameIcon" => value(reader, tag, warnings, parse_bool, |b| {
+            settings.display_game_icon = b
+        }),
+        b"ShowRegion" => value(reader, tag, warnings, parse_bool, |b| {
+            settings.show_region = b
+        }),
+        b"ShowPlatform" => value(reader, tag, warnings, parse_bool, |b| {
+            settings.show_platform = b
+        }),
+        b"ShowVariables" => value(reader, tag, warnings, parse_bool, |b| {
+            settings.show_variables = b
+        }),
+        b"CenterTitle" => value(reader, tag, warnings, parse_bool, |b| {
+            settings.text_alignment = if b {
+                Alignment::Center
+            } else {
+                Alignment::Left
+            };
+        }),
+        b"TextAlignment" => value(
+            reader,
+            tag,
+            warnings,
+            |t| match t {
+                "0" | "Auto" => Some(Alignment::Auto),
+                "1" | "Left" => Some(Alignment::Left),
+                "2" | "Center" => Some(Alignment::Center),
+                _ => None,
+            },
+            |a| settings.text_alignment = a,
+        ),
+        b"OverrideTitleColor" => value(reader, tag, warnings, parse_bool, |b| {
+            text_color.enabled = b
+        }),
+        b"TitleColor" => value(reader, tag, warnings, parse_color, |c| {
+            text_color.color = Some(c)
+        }),
+        _ => unsupported(reader, tag, warnings),
+    })?;
+
+    settings.background = background.build(settings.background);
+    settings.text_color = text_color.get();
+
+    Ok(title::Component::with_settings(settings).into())
+}
+
+fn column<R>(
+    reader: &mut Reader<R>,
+    buf: &mut Vec<u8>,
+    warnings: &mut Vec<String>,
+) -> Result<splits::ColumnSettings>
+where
+    R: BufRead,
+{
+    let mut column = splits::ColumnSettings {
+        name: String::new(),
+        column_type: ColumnType::Delta,
+        comparison_override: None,
+        timing_method: None,
+    };
+    let mut column_type = None;
+
+    parse_children(reader, buf, |reader, tag| match tag.name() {
+        b"Name" => value(reader, tag, warnings, parse_string, |n| column.name = n),
+        b"Type" => value(reader, tag, warnings, parse_string, |t| {
+            column_type = Some(t)
+        }),
+        b"Comparison" => value(reader, tag, warnings, parse_comparison, |c| {
+            column.comparison_override = c
+        }),
+        b"TimingMethod" => value(reader, tag, warnings, parse_timing_method, |m| {
+            column.timing_method = m
+        }),
+        _ => unsupported(reader, tag, warnings),
+    })?;
+
+    if let Some(column_type) = column_type {
+        column.column_type = match &*column_type {
+            // LiveSplit can show the comparison's times for upcoming splits
+            // in delta columns, which these columns don't support.
+            "DeltaorSplitTime" | "SegmentDeltaorSegmentTime" => {
+                warnings.push(format!(
+                    "Column type {} is imported without the comparison's times",
+                    column_type
+                ));
+                if column_type == "DeltaorSplitTime" {
+                    ColumnType::Delta
+                } else {
+                    ColumnType::SegmentDelta
+                }
+            }
+            _ => parse_column_type(&column_type).unwrap_or_else(|| {
+                warnings.push(format!("Unsupported column type {:?}", column_type));
+                column.column_type
+            }),
+        };
+    }
+
+    Ok(column)
+}
+
+fn splits<R>(
+    reader: &mut Reader<R>,
+    buf: &mut Vec<u8>,
+    warnings: &mut Vec<String>,
+) -> Result<Component>
+where
+    R: BufRead,
+{
+    let mut settings = splits::Settings::default();
+    let mut current_split_gradient = GradientBuilder::default();
+    let mut columns = None;
+
+    parse_children(reader, buf, |reader, tag| match tag.name() {
+        b"CurrentSplitTopColor" | b"CurrentSplitBottomColor" | b"CurrentSplitGradient" => {
+            current_split_gradient.parse(reader, tag, warnings)
+        }
+        b"VisualSplitCount" => value(reader, tag, warnings, parse_count, |c| {
+            settings.visual_split_count = c
+        }),
+        b"SplitPreviewCount" => value(reader, tag, warnings, parse_count, |c| {
+            settings.split_preview_count = c
+        }),
+        b"AlwaysShowLastSplit" => value(reader, tag, warnings, parse_bool, |b| {
+            settings.always_show_last_split = b
+        }),
+        b"SeparatorLastSplit" => value(reader, tag, warnings, parse_bool, |b| {
+            settings.separator_last_split = b
+        }),
+        b"ShowColumnLabels" => value(reader, tag, warnings, parse_bool, |b| {
+            settings.show_column_labels = b
+        }),
+        b"Columns" => {
+            let columns = columns.get_or_insert_with(Vec::new);
+            parse_children(reader, tag.into_buf(), |reader, tag| {
+                if tag.name() == b"Settings" {
+                    columns.push(column(reader, tag.into_buf(), warnings)?);
+                    Ok(())
+                } else {
+                    unsupported(reader, tag, warnings)
+                }
+            })
+        }
+        _ => unsupported(reader, tag, warnings),
+    })?;
+
+    settings.current_split_gradient = current_split_gradient.build(settings.current_split_gradient);
+    if let Some(columns) = columns {
+        settings.columns = columns;
+    }
+
+    Ok(splits::Component::with_settings(settings).into())
+}
+
+fn timer_settings<R>(
+    reader: &mut Reader<R>,
+    tag: Tag,
+    settings: &mut timer::Settings,
+    warnings: &mut Vec<String>,
+) -> Result<()>
+where
+    R: BufRead,
+{
+    value(
+        reader,
+        tag,
+        warnings,
+        parse_timer_format,
+        |(digits_format, accuracy)| {
+            settings.digits_format = digits_format;
+            settings.accuracy = accuracy;
+        },
+    )
+}
+
+fn timer<R>(
+    reader: &mut Reader<R>,
+    buf: &mut Vec<u8>,
+    warnings: &mut Vec<String>,
+) -> Result<Component>
+where
+    R: BufRead,
+{
+    let mut settings = timer::Settings::default();
+    let mut background = GradientBuilder::default();
+    let mut color = ColorOverride::default();
+
+    parse_children(reader, buf, |reader, tag| match tag.name() {
+        b"BackgroundColor" | b"BackgroundColor2" | b"BackgroundGradient" => {
+            background.parse(reader, tag, warnings)
+        }
+        b"TimerHeight" => value(reader, tag, warnings, parse_height, |h| settings.height = h),
+        b"TimerFormat" => timer_settings(reader, tag, &mut settings, warnings),
+        b"ShowGradient" => value(reader, tag, warnings, parse_bool, |b| {
+            settings.show_gradient = b
+        }),
+        b"TimingMethod" => value(reader, tag, warnings, parse_timing_method, |m| {
+            settings.timing_method = m
+        }),
+        b"OverrideSplitColors" => value(reader, tag, warnings, parse_bool, |b| color.enabled = b),
+        b"TimerColor" => value(reader, tag, warnings, parse_color, |c| {
+            color.color = Some(c)
+        }),
+        _ => unsupported(reader, tag, warnings),
+    })?;
+
+    settings.background = background.build(settings.background);
+    settings.color_override = color.get();
+
+    Ok(timer::Component::with_settings(settings).into())
+}
+
+fn detailed_timer<R>(
+    reader: &mut Reader<R>,
+    buf: &mut Vec<u8>,
+    warnings: &mut Vec<String>,
+) -> Result<Component>
+where
+    R: BufRead,
+{
+    let mut settings = detailed_timer::Settings::default();
+    let mut background = GradientBuilder::default();
+    let mut timer_color = ColorOverride::default();
+    let (mut total_height, mut segment_timer_ratio) = (None, None);
+
+    parse_children(reader, buf, |reader, tag| match tag.name() {
+        b"BackgroundColor" | b"BackgroundColor2" | b"BackgroundGradient" => {
+            background.parse(reader, tag, warnings)
+        }
+        b"Height" => value(reader, tag, warnings, parse_height, |h| {
+            total_height = Some(h)
+        }),
+        b"SegmentTimerSizeRatio" => value(reader, tag, warnings, parse_height, |r| {
+            segment_timer_ratio = Some(r)
+        }),
+        b"TimerShowGradient" => value(reader, tag, warnings, parse_bool, |b| {
+            settings.timer.show_gradient = b
+        }),
+        b"SegmentTimerShowGradient" => value(reader, tag, warnings, parse_bool, |b| {
+            settings.segment_timer.show_gradient = b
+        }),
+        b"TimerFormat" => timer_settings(reader, tag, &mut settings.timer, warnings),
+        b"SegmentTimerFormat" => timer_settings(reader, tag, &mut settings.segment_timer, warnings),
+        b"OverrideTimerColors" => value(reader, tag, warnings, parse_bool, |b| {
+            timer_color.enabled = b
+        }),
+        b"TimerColor" => value(reader, tag, warnings, parse_color, |c| {
+            timer_color.color = Some(c)
+        }),
+        b"TimingMethod" => value(reader, tag, warnings, parse_timing_method, |m| {
+            settings.timer.timing_method = m;
+            settings.segment_timer.timing_method = m;
+        }),
+        b"Comparison" => value(reader, tag, warnings, parse_comparison, |c| {
+            settings.comparison1 = c
+        }),
+        b"Comparison2" => value(reader, tag, warnings, parse_comparison, |c| {
+            settings.comparison2 = c
+        }),
+        b"HideComparison" => value(reader, tag, warnings, parse_bool, |b| {
+            settings.hide_second_comparison = b
+        }),
+        b"DisplayIcon" => value(reader, tag, warnings, parse_bool, |b| {
+            settings.display_icon = b
+        }),
+        b"ShowSplitName" => value(reader, tag, warnings, parse_bool, |b| {
+            settings.show_segment_name = b
+        }),
+        _ => unsupported(reader, tag, warnings),
+    })?;
+
+    settings.background = background.build(settings.background);
+    settings.timer.color_override = timer_color.get();
+    if let Some(total_height) = total_height {
+        // LiveSplit specifies the height of the segment timer as a percentage
+        // of the height of the whole component.
+        let ratio = segment_timer_ratio.unwrap_or(40).min(100);
+        settings.segment_timer.height = (total_height * ratio + 50) / 100;
+        settings.timer.height = total_height - settings.segment_timer.height;
+    }
+
+    Ok(detailed_timer::Component::with_settings(settings).into())
+}
+
+fn graph<R>(
+    reader: &mut Reader<R>,
+    buf: &mut Vec<u8>,
+    warnings: &mut Vec<String>,
+) -> Result<Component>
+where
+    R: BufRead,
+{
+    let mut settings = graph::Settings::default();
+
+    parse_children(reader, buf, |reader, tag| match tag.name() {
+        b"Height" => value(reader, tag, warnings, parse_height, |h| settings.height = h),
+        b"Comparison" => value(reader, tag, warnings, parse_comparison, |c| {
+            settings.comparison_override = c
+        }),
+        b"ShowBestSegments" => value(reader, tag, warnings, parse_bool, |b| {
+            settings.show_best_segments = b
+        }),
+        b"LiveGraph" => value(reader, tag, warnings, parse_bool, |b| {
+            settings.live_graph = b
+        }),
+        b"FlipGraph" => value(reader, tag, warnings, parse_bool, |b| {
+            settings.flip_graph = b
+        }),
+        b"BehindGraphColor" => value(reader, tag, warnings, parse_color, |c| {
+            settings.behind_background_color = c
+        }),
+        b"AheadGraphColor" => value(reader, tag, warnings, parse_color, |c| {
+            settings.ahead_background_color = c
+        }),
+        b"GridlinesColor" => value(reader, tag, warnings, parse_color, |c| {
+            settings.grid_lines_color = c
+        }),
+        b"GraphLinesColor" => value(reader, tag, warnings, parse_color, |c| {
+            settings.graph_lines_color = c
+        }),
+        b"PartialFillColor" => value(reader, tag, warnings, parse_color, |c| {
+            settings.partial_fill_color = c
+        }),
+        b"CompleteFillColor" => value(reader, tag, warnings, parse_color, |c| {
+            settings.complete_fill_color = c
+        }),
+        _ => unsupported(reader, tag, warnings),
+    })?;
+
+    Ok(graph::Component::with_settings(settings).into())
+}
+
+fn text<R>(
+    reader: &mut Reader<R>,
+    buf: &mut Vec<u8>,
+    warnings: &mut Vec<String>,
+) -> Result<Component>
+where
+    R: BufRead,
+{
+    let (mut left, mut right) = (String::new(), String::new());
+
+    parse_children(reader, buf, |reader, tag| match tag.name() {
+        b"Text1" => value(reader, tag, warnings, parse_string, |t| left = t),
+        b"Text2" => value(reader, tag, warnings, parse_string, |t| right = t),
+        _ => unsupported(reader, tag, warnings),
+    })?;
+
+    let mut settings = text::Settings::default();
+    if right.is_empty() {
+        settings.text = text::Text::Center(left);
+    } else {
+        settings.text = text::Text::Split(left, right);
+    }
+
+    Ok(text::Component::with_settings(settings).into())
+}
+
+fn blank_space<R>(
+    reader: &mut Reader<R>,
+    buf: &mut Vec<u8>,
+    warnings: &mut Vec<String>,
+) -> Result<Component>
+where
+    R: BufRead,
+{
+    let mut settings = blank_space::Settings::default();
+    let mut background = GradientBuilder::default();
+
+    parse_children(reader, buf, |reader, tag| match tag.name() {
+        b"BackgroundColor" | b"BackgroundColor2" | b"BackgroundGradient" => {
+            background.parse(reader, tag, warnings)
+        }
+        b"SpaceHeight" => value(reader, tag, warnings, parse_height, |h| settings.height = h),
+        _ => unsupported(reader, tag, warnings),
+    })?;
+
+    settings.background = background.build(settings.background);
+
+    Ok(blank_space::Component::with_settings(settings).into())
+}
+
+fn delta<R>(
+    reader: &mut Reader<R>,
+    buf: &mut Vec<u8>,
+    warnings: &mut Vec<String>,
+) -> Result<Component>
+where
+    R: BufRead,
+{
+    let mut settings = delta::Settings::default();
+    let mut background = GradientBuilder::default();
+    let mut colors = InfoTextColors::default();
+
+    parse_children(reader, buf, |reader, tag| match tag.name() {
+        b"BackgroundColor" | b"BackgroundColor2" | b"BackgroundGradient" => {
+            background.parse(reader, tag, warnings)
+        }
+        b"OverrideTextColor" | b"TextColor" => colors.parse(reader, tag, warnings),
+        b"Comparison" => value(reader, tag, warnings, parse_comparison, |c| {
+            settings.comparison_override = c
+        }),
+        b"DropDecimals" => value(reader, tag, warnings, parse_bool, |b| {
+            settings.drop_decimals = b
+        }),
+        b"Accuracy" => value(reader, tag, warnings, parse_accuracy, |a| {
+            settings.accuracy = a
+        }),
+        _ => unsupported(reader, tag, warnings),
+    })?;
+
+    settings.background = background.build(settings.background);
+    settings.label_color = colors.label.get();
+
+    Ok(delta::Component::with_settings(settings).into())
+}
+
+fn previous_segment<R>(
+    reader: &mut Reader<R>,
+    buf: &mut Vec<u8>,
+    warnings: &mut Vec<String>,
+) -> Result<Component>
+where
+    R: BufRead,
+{
+    let mut settings = previous_segment::Settings::default();
+    let mut background = GradientBuilder::default();
+    let mut colors = InfoTextColors::default();
+
+    parse_children(reader, buf, |reader, tag| match tag.name() {
+        b"BackgroundColor" | b"BackgroundColor2" | b"BackgroundGradient" => {
+            background.parse(reader, tag, warnings)
+        }
+        b"OverrideTextColor" | b"TextColor" => colors.parse(reader, tag, warnings),
+        b"Comparison" => value(reader, tag, warnings, parse_comparison, |c| {
+            settings.comparison_override = c
+        }),
+        b"DropDecimals" => value(reader, tag, warnings, parse_bool, |b| {
+            settings.drop_decimals = b
+        }),
+        b"DeltaAccuracy" => value(reader, tag, warnings, parse_accuracy, |a| {
+            settings.accuracy = a
+        }),
+        b"ShowPossibleTimeSave" => value(reader, tag, warnings, parse_bool, |b| {
+            settings.show_possible_time_save = b
+        }),
+        _ => unsupported(reader, tag, warnings),
+    })?;
+
+    settings.background = background.build(settings.background);
+    settings.label_color = colors.label.get();
+
+    Ok(previous_segment::Component::with_settings(settings).into())
+}
+
+fn sum_of_best<R>(
+    reader: &mut Reader<R>,
+    buf: &mut Vec<u8>,
+    warnings: &mut Vec<String>,
+) -> Result<Component>
+where
+    R: BufRead,
+{
+    let mut settings = sum_of_best::Settings::default();
+    let mut background = GradientBuilder::default();
+    let mut colors = InfoTextColors::default();
+
+    parse_children(reader, buf, |reader, tag| match tag.name() {
+        b"BackgroundColor" | b"BackgroundColor2" | b"BackgroundGradient" => {
+            background.parse(reader, tag, warnings)
+        }
+        b"OverrideTextColor" | b"TextColor" | b"OverrideTimeColor" | b"TimeColor" => {
+            colors.parse(reader, tag, warnings)
+        }
+        b"Accuracy" => value(reader, tag, warnings, parse_accuracy, |a| {
+            settings.accuracy = a
+        }),
+        _ => unsupported(reader, tag, warnings),
+    })?;
+
+    settings.background = background.build(settings.background);
+    settings.label_color = colors.label.get();
+    settings.value_color = colors.value.get();
+
+    Ok(sum_of_best::Component::with_settings(settings).into())
+}
+
+fn possible_time_save<R>(
+    reader: &mut Reader<R>,
+    buf: &mut Vec<u8>,
+    warnings: &mut Vec<String>,
+) -> Result<Component>
+where
+    R: BufRead,
+{
+    let mut settings = possible_time_save::Settings::default();
+    let mut background = GradientBuilder::default();
+    let mut colors = InfoTextColors::default();
+
+    parse_children(reader, buf, |reader, tag| match tag.name() {
+        b"BackgroundColor" | b"BackgroundColor2" | b"BackgroundGradient" => {
+            background.parse(reader, tag, warnings)
+        }
+        b"OverrideTextColor" | b"TextColor" | b"OverrideTimeColor" | b"TimeColor" => {
+            colors.parse(reader, tag, warnings)
+        }
+        b"Comparison" => value(reader, tag, warnings, parse_comparison, |c| {
+            settings.comparison_override = c
+        }),
+        b"TotalTimeSave" => value(reader, tag, warnings, parse_bool, |b| {
+            settings.total_possible_time_save = b
+        }),
+        b"Accuracy" => value(reader, tag, warnings, parse_accuracy, |a| {
+            settings.accuracy = a
+        }),
+        _ => unsupported(reader, tag, warnings),
+    })?;
+
+    settings.background = background.build(settings.background);
+    settings.label_color = colors.label.get();
+    settings.value_color = colors.value.get();
+
+    Ok(possible_time_save::Component::with_settings(settings).into())
+}
+
+fn current_pace<R>(
+    reader: &mut Reader<R>,
+    buf: &mut Vec<u8>,
+    warnings: &mut Vec<String>,
+) -> Result<Component>
+where
+    R: BufRead,
+{
+    let mut settings = current_pace::Settings::default();
+    let mut background = GradientBuilder::default();
+    let mut colors = InfoTextColors::default();
+
+    parse_children(reader, buf, |reader, tag| match tag.name() {
+        b"BackgroundColor" | b"BackgroundColor2" | b"BackgroundGradient" => {
+            background.parse(reader, tag, warnings)
+        }
+        b"OverrideTextColor" | b"TextColor" | b"OverrideTimeColor" | b"TimeColor" => {
+            colors.parse(reader, tag, warnings)
+        }
+        b"Comparison" => value(reader, tag, warnings, parse_comparison, |c| {
+            settings.comparison_override = c
+        }),
+        b"Accuracy" => value(reader, tag, warnings, parse_accuracy, |a| {
+            settings.accuracy = a
+        }),
+        _ => unsupported(reader, tag, warnings),
+    })?;
+
+    settings.background = background.build(settings.background);
+    settings.label_color = colors.label.get();
+    settings.value_color = colors.value.get();
+
+    Ok(current_pace::Component::with_settings(settings).into())
+}
+
+fn current_comparison<R>(
+    reader: &mut Reader<R>,
+    buf: &mut Vec<u8>,
+    warnings: &mut Vec<String>,
+) -> Result<Component>
+where
+    R: BufRead,
+{
+    let mut settings = current_comparison::Settings::default();
+    let mut background = GradientBuilder::default();
+    let mut colors = InfoTextColors::default();
+
+    parse_children(reader, buf, |reader, tag| match tag.name() {
+        b"BackgroundColor" | b"BackgroundColor2" | b"BackgroundGradient" => {
+            background.parse(reader, tag, warnings)
+        }
+        b"OverrideTextColor" | b"TextColor" | b"OverrideTimeColor" | b"TimeColor" => {
+            colors.parse(reader, tag, warnings)
+        }
+        _ => unsupported(reader, tag, warnings),
+    })?;
+
+    settings.background = background.build(settings.background);
+    settings.label_color = colors.label.get();
+    settings.value_color = colors.value.get();
+
+    Ok(current_comparison::Component::with_settings(settings).into())
+}
+
+fn total_playtime<R>(
+    reader: &mut Reader<R>,
+    buf: &mut Vec<u8>,
+    warnings: &mut Vec<String>,
+) -> Result<Component>
+where
+    R: BufRead,
+{
+    let mut settings = total_playtime::Settings::default();
+    let mut background = GradientBuilder::default();
+    let mut colors = InfoTextColors::default();
+
+    parse_children(reader, buf, |reader, tag| match tag.name() {
+        b"BackgroundColor" | b"BackgroundColor2" | b"BackgroundGradient" => {
+            background.parse(reader, tag, warnings)
+        }
+        b"OverrideTextColor" | b"TextColor" | b"OverrideTimeColor" | b"TimeColor" => {
+            colors.parse(reader, tag, warnings)
+        }
+        _ => unsupported(reader, tag, warnings),
+    })?;
+
+    settings.background = background.build(settings.background);
+    settings.label_color = colors.label.get();
+    settings.value_color = colors.value.get();
+
+    Ok(total_playtime::Component::with_settings(settings).into())
+}
+
+fn component<R>(
+    reader: &mut Reader<R>,
+    buf: &mut Vec<u8>,
+    warnings: &mut Vec<String>,
+) -> Result<Option<Component>>
+where
+    R: BufRead,
+{
+    let mut path = String::new();
+    let mut component = None;
+    let mut component_warnings = Vec::new();
+
+    parse_children(reader, buf, |reader, tag| match tag.name() {
+        b"Path" => value(reader, tag, warnings, parse_string, |p| path = p),
+        b"Settings" => {
+            let buf = tag.into_buf();
+            let warnings = &mut component_warnings;
+            component = match &*path {
+                "" => {
+                    end_tag(reader, buf)?;
+                    Some(separator::Component::new().into())
+                }
+                "LiveSplit.BlankSpace.dll" => Some(blank_space(reader, buf, warnings)?),
+                "LiveSplit.CurrentComparison.dll" => {
+                    Some(current_comparison(reader, buf, warnings)?)
+                }
+                "LiveSplit.Delta.dll" => Some(delta(reader, buf, warnings)?),
+                "LiveSplit.DetailedTimer.dll" => Some(detailed_timer(reader, buf, warnings)?),
+                "LiveSplit.Graph.dll" => Some(graph(reader, buf, warnings)?),
+                "LiveSplit.PossibleTimeSave.dll" => {
+                    Some(possible_time_save(reader, buf, warnings)?)
+                }
+                "LiveSplit.PreviousSegment.dll" => Some(previous_segment(reader, buf, warnings)?),
+                "LiveSplit.RunPrediction.dll" => Some(current_pace(reader, buf, warnings)?),
+                "LiveSplit.Splits.dll" | "LiveSplit.Subsplits.dll" => {
+                    Some(splits(reader, buf, warnings)?)
+                }
+                "LiveSplit.SumOfBest.dll" => Some(sum_of_best(reader, buf, warnings)?),
+                "LiveSplit.Text.dll" => Some(text(reader, buf, warnings)?),
+                "LiveSplit.Timer.dll" => Some(timer(reader, buf, warnings)?),
+                "LiveSplit.Title.dll" => Some(title(reader, buf, warnings)?),
+                "LiveSplit.TotalPlaytime.dll" => Some(total_playtime(reader, buf, warnings)?),
+                _ => {
+                    end_tag(reader, buf)?;
+                    None
+                }
+            };
+            Ok(())
+        }
+        _ => end_tag(reader, tag.into_buf()),
+    })?;
+
+    if component.is_none() {
+        warnings.push(format!("Unsupported component {:?}", path));
+    }
+    let name = if path.is_empty() { "Separator" } else { &path };
+    warnings.extend(
+        component_warnings
+            .into_iter()
+            .map(|warning| format!("{}: {}", name, warning)),
+    );
+
+    Ok(component)
+}
+
+pub fn parse<R: BufRead>(source: R) -> Result<ParsedLayout> {
+    let reader = &mut Reader::from_reader(source);
+    reader.expand_empty_elements(true);
+    reader.trim_text(true);
+
+    let mut buf = Vec::with_capacity(4096);
+    let mut layout = Layout::new();
+    let mut warnings = Vec::new();
+    let mut mode = String::from("Vertical");
+
+    parse_base(reader, &mut buf, b"Layout", |reader, tag| {
+        parse_children(reader, tag.into_buf(), |reader, tag| match tag.name() {
+            b"Mode" => value(reader, tag, &mut warnings, parse_string, |m| mode = m),
+            b"Settings" => general_settings(
+                reader,
+                tag.into_buf(),
+                layout.general_settings_mut(),
+                &mut warnings,
+            ),
+            b"Components" => parse_children(reader, tag.into_buf(), |reader, tag| {
+                if tag.name() == b"Component" {
+                    if let Some(component) = component(reader, tag.into_buf(), &mut warnings)? {
+                        layout.push(component);
+                    }
+                    Ok(())
+                } else {
+                    end_tag(reader, tag.into_buf())
+                }
+            }),
+            // The position and the size of the window aren't part of the
+            // layout itself.
+            _ => end_tag(reader, tag.into_buf()),
+        })
+    })?;
+
+    match mode.as_str() {
+        "Vertical" => {}
+        "Horizontal" => layout.general_settings_mut().direction = LayoutDirection::Horizontal,
+        _ => warnings.push(format!("Unsupported layout mode {:?}", mode)),
+    }
+
+    Ok(ParsedLayout { layout, warnings })
+}
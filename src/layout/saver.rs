@@ -0,0 +1,644 @@
+//! Provides a saver that writes Layouts as LiveSplit Layout files (`.lsl`).
+//! Components and columns that don't exist in LiveSplit are left out. The
+//! components and columns that got left out are reported as warnings. LiveSplit has no
+//! containers, so their children are stored as part of the layout instead.
+
+use std::io::Write;
+use quick_xml::Writer;
+use quick_xml::events::{BytesDecl, BytesStart, Event};
+use component::splits::ColumnType;
+use component::{blank_space, current_comparison, current_pace, delta, detailed_timer, graph,
+                possible_time_save, previous_segment, splits, sum_of_best, text, timer, title,
+                total_playtime};
+use settings::{Alignment, Color, Font, Gradient, LayoutDirection};
+use time::formatter::{Accuracy, DigitsFormat};
+use xml_writer::{bool, fmt_buf, new_tag, scoped, scoped_iter, text, write_display, write_end,
+                 write_start};
+use TimingMethod;
+use super::{Component, GeneralSettings, Layout};
+use super::livesplit_font::{self, Kind};
+
+pub use xml_writer::{Error, Result};
+
+/// The color LiveSplit shows when a color override is disabled.
+const DEFAULT_OVERRIDE_COLOR: (f32, f32, f32, f32) = (1.0, 1.0, 1.0, 1.0);
+
+fn fmt_color(color: Color, buf: &mut Vec<u8>) -> &[u8] {
+    let [r, g, b, a]: [f32; 4] = color.rgba.to_pixel();
+    let channel = |c: f32| (c * 255.0).round() as u8;
+    fmt_buf(
+        format_args!(
+            "{:02X}{:02X}{:02X}{:02X}",
+            channel(a),
+            channel(r),
+            channel(g),
+            channel(b)
+        ),
+        buf,
+    )
+}
+
+fn color<W: Write>(
+    writer: &mut Writer<W>,
+    tag: &[u8],
+    color: Color,
+    buf: &mut Vec<u8>,
+) -> Result<()> {
+    text(writer, new_tag(tag), fmt_color(color, buf))
+}
+
+fn color_override<W: Write>(
+    writer: &mut Writer<W>,
+    override_tag: &[u8],
+    color_tag: &[u8],
+    value: Option<Color>,
+    buf: &mut Vec<u8>,
+) -> Result<()> {
+    text(writer, new_tag(override_tag), bool(value.is_some()))?;
+    color(
+        writer,
+        color_tag,
+        value.unwrap_or_else(|| DEFAULT_OVERRIDE_COLOR.into()),
+        buf,
+    )
+}
+
+fn gradient<W: Write>(
+    writer: &mut Writer<W>,
+    tags: [&[u8]; 3],
+    gradient: Gradient,
+    buf: &mut Vec<u8>,
+) -> Result<()> {
+    let (first, second, kind): (_, _, &[u8]) = match gradient {
+        Gradient::Transparent => (Color::transparent(), Color::transparent(), b"Plain"),
+        Gradient::Plain(c) => (c, Color::transparent(), b"Plain"),
+        Gradient::Vertical(top, bottom) => (top, bottom, b"Vertical"),
+        Gradient::Horizontal(left, right) => (left, right, b"Horizontal"),
+    };
+    color(writer, tags[0], first, buf)?;
+    color(writer, tags[1], second, buf)?;
+    text(writer, new_tag(tags[2]), kind)
+}
+
+/// Writes the background of a component. Backgrounds that are the same as
+/// the component's default background are left out, as the colors in the file
+/// can't represent every default background exactly.
+fn background<W: Write>(
+    writer: &mut Writer<W>,
+    background: Gradient,
+    default: Gradient,
+    buf: &mut Vec<u8>,
+) -> Result<()> {
+    if background == default {
+        return Ok(());
+    }
+    gradient(
+        writer,
+        [b"BackgroundColor", b"BackgroundColor2", b"BackgroundGradient"],
+        background,
+        buf,
+    )
+}
+
+fn comparison<W: Write>(
+    writer: &mut Writer<W>,
+    tag: &[u8],
+    comparison: Option<&str>,
+) -> Result<()> {
+    text(writer, new_tag(tag), comparison.unwrap_or("Current Comparison"))
+}
+
+fn timing_method<W: Write>(
+    writer: &mut Writer<W>,
+    method: Option<TimingMethod>,
+) -> Result<()> {
+    let method = match method {
+        None => "Current Timing Method",
+        Some(TimingMethod::RealTime) => "Real Time",
+        Some(TimingMethod::GameTime) => "Game Time",
+    };
+    text(writer, new_tag(b"TimingMethod"), method)
+}
+
+fn accuracy<W: Write>(writer: &mut Writer<W>, tag: &[u8], accuracy: Accuracy) -> Result<()> {
+    let accuracy = match accuracy {
+        Accuracy::Seconds => "Seconds",
+        Accuracy::Tenths => "Tenths",
+        Accuracy::Hundredths => "Hundredths",
+    };
+    text(writer, new_tag(tag), accuracy)
+}
+
+fn timer_format<W: Write>(
+    writer: &mut Writer<W>,
+    tag: &[u8],
+    settings: &timer::Settings,
+) -> Result<()> {
+    let digits = match settings.digits_format {
+        DigitsFormat::SingleDigitSeconds => "1",
+        DigitsFormat::DoubleDigitSeconds => "01",
+        DigitsFormat::SingleDigitMinutes => "0:01",
+        DigitsFormat::DoubleDigitMinutes => "00:01",
+        DigitsFormat::SingleDigitHours => "0:00:01",
+        DigitsFormat::DoubleDigitHours => "00:00:01",
+    };
+    let decimals = match settings.accuracy {
+        Accuracy::Seconds => "",
+        Accuracy::Tenths => ".2",
+        Accuracy::Hundredths => ".23",
+    };
+    text(writer, new_tag(tag), [digits, decimals].concat())
+}
+
+fn font<W: Write>(
+    writer: &mut Writer<W>,
+    tag: &[u8],
+    font: Option<&Font>,
+    kind: Kind,
+) -> Result<()> {
+    text(writer, new_tag(tag), livesplit_font::encode(font, kind))
+}
+
+fn version<W: Write>(writer: &mut Writer<W>, version: &str) -> Result<()> {
+    text(writer, new_tag(b"Version"), version)
+}
+
+fn general_settings<W: Write>(
+    writer: &mut Writer<W>,
+    settings: &GeneralSettings,
+    buf: &mut Vec<u8>,
+) -> Result<()> {
+    let (first, second, kind) = match settings.background {
+        Gradient::Transparent => (Color::transparent(), Color::transparent(), "SolidColor"),
+        Gradient::Plain(c) => (c, Color::transparent(), "SolidColor"),
+        Gradient::Vertical(top, bottom) => (top, bottom, "VerticalGradient"),
+        Gradient::Horizontal(left, right) => (left, right, "HorizontalGradient"),
+    };
+
+    write_start(writer, new_tag(b"Settings"))?;
+    color(writer, b"TextColor", settings.text_color, buf)?;
+    color(writer, b"BackgroundColor", first, buf)?;
+    color(writer, b"BackgroundColor2", second, buf)?;
+    color(writer, b"ThinSeparatorsColor", settings.thin_separators_color, buf)?;
+    color(writer, b"SeparatorsColor", settings.separators_color, buf)?;
+    color(writer, b"PersonalBestColor", settings.personal_best_color, buf)?;
+    color(writer, b"AheadGainingTimeColor", settings.ahead_gaining_time_color, buf)?;
+    color(writer, b"AheadLosingTimeColor", settings.ahead_losing_time_color, buf)?;
+    color(writer, b"BehindGainingTimeColor", settings.behind_gaining_time_color, buf)?;
+    color(writer, b"BehindLosingTimeColor", settings.behind_losing_time_color, buf)?;
+    color(writer, b"BestSegmentColor", settings.best_segment_color, buf)?;
+    text(writer, new_tag(b"UseRainbowColor"), bool(false))?;
+    color(writer, b"NotRunningColor", settings.not_running_color, buf)?;
+    color(writer, b"PausedColor", settings.paused_color, buf)?;
+    // The settings that only LiveSplit has are stored with LiveSplit's
+    // defaults, as LiveSplit expects all of them to be there.
+    text(writer, new_tag(b"TextOutlineColor"), "00000000")?;
+    text(writer, new_tag(b"ShadowsColor"), "80000000")?;
+    font(writer, b"TimerFont", settings.timer_font.as_ref(), Kind::Timer)?;
+    font(writer, b"TimesFont", settings.times_font.as_ref(), Kind::Times)?;
+    font(writer, b"TextFont", settings.text_font.as_ref(), Kind::Text)?;
+    text(writer, new_tag(b"AlwaysOnTop"), bool(true))?;
+    text(writer, new_tag(b"ShowBestSegments"), bool(false))?;
+    text(writer, new_tag(b"AntiAliasing"), bool(true))?;
+    text(writer, new_tag(b"DropShadows"), bool(true))?;
+    text(writer, new_tag(b"BackgroundType"), kind)?;
+    text(writer, new_tag(b"BackgroundImage"), "")?;
+    text(writer, new_tag(b"ImageOpacity"), "1")?;
+    text(writer, new_tag(b"ImageBlur"), "0")?;
+    text(writer, new_tag(b"Opacity"), "1")?;
+    write_end(writer, b"Settings")
+}
+
+fn title<W: Write>(
+    writer: &mut Writer<W>,
+    settings: &title::Settings,
+    buf: &mut Vec<u8>,
+) -> Result<()> {
+    let alignment = match settings.text_alignment {
+        Alignment::Auto => "0",
+        Alignment::Left => "1",
+        Alignment::Center => "2",
+    };
+
+    version(writer, "1.7")?;
+    background(writer, settings.background, title::Settings::default().background, buf)?;
+    text(writer, new_tag(b"ShowGameName"), bool(settings.show_game_name))?;
+    text(writer, new_tag(b"ShowCategoryName"), bool(settings.show_category_name))?;
+    text(writer, new_tag(b"ShowFinishedRunsCount"), bool(settings.show_finished_runs_count))?;
+    text(writer, new_tag(b"ShowAttemptCount"), bool(settings.show_attempt_count))?;
+    text(writer, new_tag(b"TextAlignment"), alignment)?;
+    text(writer, new_tag(b"SingleLine"), bool(settings.display_as_single_line))?;
+    text(writer, new_tag(b"DisplayGameIcon"), bool(settings.display_game_icon))?;
+    text(writer, new_tag(b"ShowRegion"), bool(settings.show_region))?;
+    text(writer, new_tag(b"ShowPlatform"), bool(settings.show_platform))?;
+    text(writer, new_tag(b"ShowVariables"), bool(settings.show_variables))?;
+    color_override(writer, b"OverrideTitleColor", b"TitleColor", settings.text_color, buf)
+}
+
+fn column_type(column_type: ColumnType) -> Option<&'static str> {
+    match column_type {
+        ColumnType::Delta => Some("Delta"),
+        ColumnType::SplitTime => Some("SplitTime"),
+        ColumnType::SegmentTime => Some("SegmentTime"),
+        ColumnType::SegmentDelta => Some("SegmentDelta"),
+        ColumnType::BestSegment | ColumnType::PossibleTimeSave => None,
+    }
+}
+
+fn splits<W: Write>(
+    writer: &mut Writer<W>,
+    settings: &splits::Settings,
+    buf: &mut Vec<u8>,
+    warnings: &mut Vec<String>,
+) -> Result<()> {
+    version(writer, "1.6")?;
+    gradient(
+        writer,
+        [
+            b"CurrentSplitTopColor",
+            b"CurrentSplitBottomColor",
+            b"CurrentSplitGradient",
+        ],
+        settings.current_split_gradient,
+        buf,
+    )?;
+    write_display(writer, new_tag(b"VisualSplitCount"), settings.visual_split_count, buf)?;
+    write_display(writer, new_tag(b"SplitPreviewCount"), settings.split_preview_count, buf)?;
+    text(writer, new_tag(b"AlwaysShowLastSplit"), bool(settings.always_show_last_split))?;
+    text(writer, new_tag(b"SeparatorLastSplit"), bool(settings.separator_last_split))?;
+    text(writer, new_tag(b"ShowColumnLabels"), bool(settings.show_column_labels))?;
+
+    let mut columns = Vec::new();
+    for column in &settings.columns {
+        match column_type(column.column_type) {
+            Some(column_type) => columns.push((column, column_type)),
+            None => warnings.push(format!("Unsupported column {:?}", column.name)),
+        }
+    }
+
+    scoped_iter(writer, new_tag(b"Columns"), columns, |writer, (column, column_type)| {
+        write_start(writer, new_tag(b"Settings"))?;
+        version(writer, "1.5")?;
+        text(writer, new_tag(b"Name"), &column.name)?;
+        text(writer, new_tag(b"Type"), column_type)?;
+        comparison(
+            writer,
+            b"Comparison",
+            column.comparison_override.as_ref().map(String::as_str),
+        )?;
+        timing_method(writer, column.timing_method)?;
+        write_end(writer, b"Settings")
+    })
+}
+
+fn timer<W: Write>(
+    writer: &mut Writer<W>,
+    settings: &timer::Settings,
+    buf: &mut Vec<u8>,
+) -> Result<()> {
+    version(writer, "1.5")?;
+    background(writer, settings.background, timer::Settings::default().background, buf)?;
+    write_display(writer, new_tag(b"TimerHeight"), settings.height, buf)?;
+    timer_format(writer, b"TimerFormat", settings)?;
+    text(writer, new_tag(b"ShowGradient"), bool(settings.show_gradient))?;
+    timing_method(writer, settings.timing_method)?;
+    color_override(writer, b"OverrideSplitColors", b"TimerColor", settings.color_override, buf)
+}
+
+fn detailed_timer<W: Write>(
+    writer: &mut Writer<W>,
+    settings: &detailed_timer::Settings,
+    buf: &mut Vec<u8>,
+) -> Result<()> {
+    let total_height = settings.timer.height + settings.segment_timer.height;
+    let ratio = (settings.segment_timer.height * 100 + total_height / 2)
+        .checked_div(total_height)
+        .unwrap_or(0);
+
+    version(writer, "1.5")?;
+    background(writer, settings.background, detailed_timer::Settings::default().background, buf)?;
+    write_display(writer, new_tag(b"Height"), total_height, buf)?;
+    write_display(writer, new_tag(b"SegmentTimerSizeRatio"), ratio, buf)?;
+    text(writer, new_tag(b"TimerShowGradient"), bool(settings.timer.show_gradient))?;
+    text(
+        writer,
+        new_tag(b"SegmentTimerShowGradient"),
+        bool(settings.segment_timer.show_gradient),
+    )?;
+    timer_format(writer, b"TimerFormat", &settings.timer)?;
+    timer_format(writer, b"SegmentTimerFormat", &settings.segment_timer)?;
+    color_override(
+        writer,
+        b"OverrideTimerColors",
+        b"TimerColor",
+        settings.timer.color_override,
+        buf,
+    )?;
+    timing_method(writer, settings.timer.timing_method)?;
+    comparison(writer, b"Comparison", settings.comparison1.as_ref().map(String::as_str))?;
+    comparison(writer, b"Comparison2", settings.comparison2.as_ref().map(String::as_str))?;
+    text(writer, new_tag(b"HideComparison"), bool(settings.hide_second_comparison))?;
+    text(writer, new_tag(b"DisplayIcon"), bool(settings.display_icon))?;
+    text(writer, new_tag(b"ShowSplitName"), bool(settings.show_segment_name))
+}
+
+fn graph<W: Write>(
+    writer: &mut Writer<W>,
+    settings: &graph::Settings,
+    buf: &mut Vec<u8>,
+) -> Result<()> {
+    version(writer, "1.5")?;
+    write_display(writer, new_tag(b"Height"), settings.height, buf)?;
+    comparison(
+        writer,
+        b"Comparison",
+        settings.comparison_override.as_ref().map(String::as_str),
+    )?;
+    text(writer, new_tag(b"ShowBestSegments"), bool(settings.show_best_segments))?;
+    text(writer, new_tag(b"LiveGraph"), bool(settings.live_graph))?;
+    text(writer, new_tag(b"FlipGraph"), bool(settings.flip_graph))?;
+    color(writer, b"BehindGraphColor", settings.behind_background_color, buf)?;
+    color(writer, b"AheadGraphColor", settings.ahead_background_color, buf)?;
+    color(writer, b"GridlinesColor", settings.grid_lines_color, buf)?;
+    color(writer, b"GraphLinesColor", settings.graph_lines_color, buf)?;
+    color(writer, b"PartialFillColor", settings.partial_fill_color, buf)?;
+    color(writer, b"CompleteFillColor", settings.complete_fill_color, buf)
+}
+
+fn text_component<W: Write>(writer: &mut Writer<W>, settings: &text::Settings) -> Result<()> {
+    let (left, right) = match settings.text {
+        text::Text::Center(ref center) => (center.as_str(), ""),
+        text::Text::Split(ref left, ref right) => (left.as_str(), right.as_str()),
+    };
+
+    version(writer, "1.4")?;
+    text(writer, new_tag(b"Text1"), left)?;
+    text(writer, new_tag(b"Text2"), right)
+}
+
+fn blank_space<W: Write>(
+    writer: &mut Writer<W>,
+    settings: &blank_space::Settings,
+    buf: &mut Vec<u8>,
+) -> Result<()> {
+    version(writer, "1.4")?;
+    background(writer, settings.background, blank_space::Settings::default().background, buf)?;
+    write_display(writer, new_tag(b"SpaceHeight"), settings.height, buf)
+}
+
+fn delta<W: Write>(
+    writer: &mut Writer<W>,
+    settings: &delta::Settings,
+    buf: &mut Vec<u8>,
+) -> Result<()> {
+    version(writer, "1.4")?;
+    background(writer, settings.background, delta::Settings::default().background, buf)?;
+    color_override(writer, b"OverrideTextColor", b"TextColor", settings.label_color, buf)?;
+    comparison(
+        writer,
+        b"Comparison",
+        settings.comparison_override.as_ref().map(String::as_str),
+    )?;
+    text(writer, new_tag(b"DropDecimals"), bool(settings.drop_decimals))?;
+    accuracy(writer, b"Accuracy", settings.accuracy)
+}
+
+fn previous_segment<W: Write>(
+    writer: &mut Writer<W>,
+    settings: &previous_segment::Settings,
+    buf: &mut Vec<u8>,
+) -> Result<()> {
+    version(writer, "1.6")?;
+    background(writer, settings.background, previous_segment::Settings::default().background, buf)?;
+    color_override(writer, b"OverrideTextColor", b"TextColor", settings.label_color, buf)?;
+    comparison(
+        writer,
+        b"Comparison",
+        settings.comparison_override.as_ref().map(String::as_str),
+    )?;
+    text(writer, new_tag(b"DropDecimals"), bool(settings.drop_decimals))?;
+    accuracy(writer, b"DeltaAccuracy", settings.accuracy)?;
+    text(writer, new_tag(b"ShowPossibleTimeSave"), bool(settings.show_possible_time_save))
+}
+
+fn info_text_colors<W: Write>(
+    writer: &mut Writer<W>,
+    label_color: Option<Color>,
+    value_color: Option<Color>,
+    buf: &mut Vec<u8>,
+) -> Result<()> {
+    color_override(writer, b"OverrideTextColor", b"TextColor", label_color, buf)?;
+    color_override(writer, b"OverrideTimeColor", b"TimeColor", value_color, buf)
+}
+
+fn sum_of_best<W: Write>(
+    writer: &mut Writer<W>,
+    settings: &sum_of_best::Settings,
+    buf: &mut Vec<u8>,
+) -> Result<()> {
+    version(writer, "1.4")?;
+    background(writer, settings.background, sum_of_best::Settings::default().background, buf)?;
+    info_text_colors(writer, settings.label_color, settings.value_color, buf)?;
+    accuracy(writer, b"Accuracy", settings.accuracy)
+}
+
+fn possible_time_save<W: Write>(
+    writer: &mut Writer<W>,
+    settings: &possible_time_save::Settings,
+    buf: &mut Vec<u8>,
+) -> Result<()> {
+    version(writer, "1.4")?;
+    background(
+        writer,
+        settings.background,
+        possible_time_save::Settings::default().background,
+        buf,
+    )?;
+    info_text_colors(writer, settings.label_color, settings.value_color, buf)?;
+    comparison(
+        writer,
+        b"Comparison",
+        settings.comparison_override.as_ref().map(String::as_str),
+    )?;
+    text(writer, new_tag(b"TotalTimeSave"), bool(settings.total_possible_time_save))?;
+    accuracy(writer, b"Accuracy", settings.accuracy)
+}
+
+fn current_pace<W: Write>(
+    writer: &mut Writer<W>,
+    settings: &current_pace::Settings,
+    buf: &mut Vec<u8>,
+) -> Result<()> {
+    version(writer, "1.4")?;
+    background(writer, settings.background, current_pace::Settings::default().background, buf)?;
+    info_text_colors(writer, settings.label_color, settings.value_color, buf)?;
+    comparison(
+        writer,
+        b"Comparison",
+        settings.comparison_override.as_ref().map(String::as_str),
+    )?;
+    accuracy(writer, b"Accuracy", settings.accuracy)
+}
+
+fn current_comparison<W: Write>(
+    writer: &mut Writer<W>,
+    settings: &current_comparison::Settings,
+    buf: &mut Vec<u8>,
+) -> Result<()> {
+    version(writer, "1.4")?;
+    background(
+        writer,
+        settings.background,
+        current_comparison::Settings::default().background,
+        buf,
+    )?;
+    info_text_colors(writer, settings.label_color, settings.value_color, buf)
+}
+
+fn total_playtime<W: Write>(
+    writer: &mut Writer<W>,
+    settings: &total_playtime::Settings,
+    buf: &mut Vec<u8>,
+) -> Result<()> {
+    version(writer, "1.4")?;
+    background(writer, settings.background, total_playtime::Settings::default().background, buf)?;
+    info_text_colors(writer, settings.label_color, settings.value_color, buf)
+}
+
+fn path(component: &Component) -> Option<&'static str> {
+    Some(match *component {
+        Component::BlankSpace(_) => "LiveSplit.BlankSpace.dll",
+        Component::CurrentComparison(_) => "LiveSplit.CurrentComparison.dll",
+        Component::CurrentPace(_) => "LiveSplit.RunPrediction.dll",
+        Component::Delta(_) => "LiveSplit.Delta.dll",
+        Component::DetailedTimer(_) => "LiveSplit.DetailedTimer.dll",
+        Component::Graph(_) => "LiveSplit.Graph.dll",
+        Component::PossibleTimeSave(_) => "LiveSplit.PossibleTimeSave.dll",
+        Component::PreviousSegment(_) => "LiveSplit.PreviousSegment.dll",
+        Component::Separator(_) => "",
+        Component::Splits(_) => "LiveSplit.Splits.dll",
+        Component::SumOfBest(_) => "LiveSplit.SumOfBest.dll",
+        Component::Text(_) => "LiveSplit.Text.dll",
+        Component::Timer(_) => "LiveSplit.Timer.dll",
+        Component::Title(_) => "LiveSplit.Title.dll",
+        Component::TotalPlaytime(_) => "LiveSplit.TotalPlaytime.dll",
+        Component::ComparisonTable(_)
+        | Component::Container(_)
+        | Component::PbChance(_)
+        | Component::RunMetadata(_)
+        | Component::RunPrediction(_)
+        | Component::SegmentHistoryGraph(_)
+        | Component::SegmentStatistics(_)
+        | Component::SessionStatistics(_)
+        | Component::WallClock(_) => return None,
+    })
+}
+
+fn component_settings<W: Write>(
+    writer: &mut Writer<W>,
+    component: &Component,
+    buf: &mut Vec<u8>,
+    warnings: &mut Vec<String>,
+) -> Result<()> {
+    match *component {
+        Component::BlankSpace(ref c) => blank_space(writer, c.settings(), buf),
+        Component::CurrentComparison(ref c) => current_comparison(writer, c.settings(), buf),
+        Component::CurrentPace(ref c) => current_pace(writer, c.settings(), buf),
+        Component::Delta(ref c) => delta(writer, c.settings(), buf),
+        Component::DetailedTimer(ref c) => detailed_timer(writer, c.settings(), buf),
+        Component::Graph(ref c) => graph(writer, c.settings(), buf),
+        Component::PossibleTimeSave(ref c) => possible_time_save(writer, c.settings(), buf),
+        Component::PreviousSegment(ref c) => previous_segment(writer, c.settings(), buf),
+        Component::Splits(ref c) => splits(writer, c.settings(), buf, warnings),
+        Component::SumOfBest(ref c) => sum_of_best(writer, c.settings(), buf),
+        Component::Text(ref c) => text_component(writer, c.settings()),
+        Component::Timer(ref c) => timer(writer, c.settings(), buf),
+        Component::Title(ref c) => title(writer, c.settings(), buf),
+        Component::TotalPlaytime(ref c) => total_playtime(writer, c.settings(), buf),
+        // Components without a path are left out entirely.
+        Component::Separator(_)
+        | Component::ComparisonTable(_)
+        | Component::Container(_)
+        | Component::PbChance(_)
+        | Component::RunMetadata(_)
+        | Component::RunPrediction(_)
+        | Component::SegmentHistoryGraph(_)
+        | Component::SegmentStatistics(_)
+        | Component::SessionStatistics(_)
+        | Component::WallClock(_) => Ok(()),
+    }
+}
+
+/// Collects the components to store, along with their paths. The children of
+/// containers get flattened into the list, which keeps them in place if the
+/// container stacks them the same way the layout does.
+fn collect_components<'a>(
+    component: &'a Component,
+    direction: LayoutDirection,
+    components: &mut Vec<(&'a Component, &'static str)>,
+    warnings: &mut Vec<String>,
+) {
+    if let Component::Container(ref container) = *component {
+        if container.direction() != direction {
+            warnings.push(format!("Flattened component {:?}", component.name()));
+        }
+        for child in &container.children {
+            collect_components(&child.component, direction, components, warnings);
+        }
+    } else if let Some(path) = path(component) {
+        components.push((component, path));
+    } else {
+        warnings.push(format!("Unsupported component {:?}", component.name()));
+    }
+}
+
+/// Saves a layout as a LiveSplit Layout file.
+///
+/// Returns a warning for each of the components and columns that don't exist
+/// in LiveSplit and got left out, and for each container whose children can't
+/// be placed the way the container places them.
+pub fn save<W: Write>(layout: &Layout, writer: W) -> Result<Vec<String>> {
+    let writer = &mut Writer::new(writer);
+    let buf = &mut Vec::new();
+
+    writer.write_event(Event::Decl(BytesDecl::new(b"1.0", Some(b"UTF-8"), None)))?;
+    writer.write_event(Event::Start(
+        BytesStart::borrowed(br#"Layout version="1.6.1""#, 6),
+    ))?;
+
+    let direction = layout.general_settings().direction;
+    let mode = match direction {
+        LayoutDirection::Vertical => "Vertical",
+        LayoutDirection::Horizontal => "Horizontal",
+    };
+    text(writer, new_tag(b"Mode"), mode)?;
+    text(writer, new_tag(b"X"), "0")?;
+    text(writer, new_tag(b"Y"), "0")?;
+    text(writer, new_tag(b"VerticalWidth"), "300")?;
+    text(writer, new_tag(b"VerticalHeight"), "500")?;
+    text(writer, new_tag(b"HorizontalWidth"), "-1")?;
+    text(writer, new_tag(b"HorizontalHeight"), "-1")?;
+
+    general_settings(writer, layout.general_settings(), buf)?;
+
+    let mut components = Vec::new();
+    let mut warnings = Vec::new();
+    for entry in &layout.components {
+        collect_components(&entry.component, direction, &mut components, &mut warnings);
+    }
+
+    scoped_iter(writer, new_tag(b"Components"), components, |writer, (component, path)| {
+        write_start(writer, new_tag(b"Component"))?;
+        text(writer, new_tag(b"Path"), path)?;
+        // Separators are the only components without any settings.
+        let is_empty = path.is_empty();
+        scoped(writer, new_tag(b"Settings"), is_empty, |writer| {
+            component_settings(writer, component, buf, &mut warnings)
+        })?;
+        write_end(writer, b"Component")
+    })?;
+
+    write_end(writer, b"Layout")?;
+
+    Ok(warnings)
+}
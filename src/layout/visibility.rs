@@ -0,0 +1,66 @@
+use {Timer, TimerPhase};
+use settings::{Field, SettingsDescription, Value};
+
+/// Describes when a component of a layout is shown. The component is only
+/// visible while all of the conditions that are turned on are met, so by
+/// default it is always visible.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Visibility {
+    /// Only shows the component while an attempt is in progress.
+    pub only_while_running: bool,
+    /// Only shows the component while no attempt is in progress, which
+    /// includes a finished attempt that hasn't been reset yet.
+    pub only_while_not_running: bool,
+    /// Only shows the component while this comparison is selected.
+    pub only_with_comparison: Option<String>,
+    /// Only shows the component once the segment with this number has been
+    /// completed. The segments are numbered starting at 1, so 0 turns this
+    /// condition off.
+    pub only_after_segment: u32,
+}
+
+impl Visibility {
+    pub fn is_visible(&self, timer: &Timer) -> bool {
+        let phase = timer.current_phase();
+        let running = phase == TimerPhase::Running || phase == TimerPhase::Paused;
+
+        (!self.only_while_running || running)
+            && (!self.only_while_not_running || !running)
+            && self.only_with_comparison
+                .as_ref()
+                .map_or(true, |c| c == timer.current_comparison())
+            && (self.only_after_segment == 0
+                || timer
+                    .current_split_index()
+                    .map_or(false, |i| i >= self.only_after_segment as usize))
+    }
+
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Only While Running".into(), self.only_while_running.into()),
+            Field::new(
+                "Only While Not Running".into(),
+                self.only_while_not_running.into(),
+            ),
+            Field::new(
+                "Only With Comparison".into(),
+                self.only_with_comparison.clone().into(),
+            ),
+            Field::new(
+                "Only After Segment".into(),
+                u64::from(self.only_after_segment).into(),
+            ),
+        ])
+    }
+
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.only_while_running = value.into(),
+            1 => self.only_while_not_running = value.into(),
+            2 => self.only_with_comparison = value.into(),
+            3 => self.only_after_segment = value.into_uint().unwrap() as _,
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
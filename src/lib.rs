@@ -10,6 +10,8 @@ extern crate odds;
 #[macro_use]
 extern crate quick_error;
 extern crate quick_xml;
+#[cfg(feature = "software-rendering")]
+extern crate rusttype;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
@@ -29,10 +31,15 @@ macro_rules! catch {
 mod hotkey_config;
 mod hotkey_system;
 mod image;
+mod xml_util;
+mod xml_writer;
+#[cfg(test)]
+mod tests_helper;
 pub mod analysis;
 pub mod comparison;
 pub mod component;
 pub mod layout;
+pub mod rendering;
 pub mod run;
 pub mod settings;
 pub mod time;
@@ -0,0 +1,332 @@
+//! Provides a backend that draws the layout as text colored with ANSI escape
+//! codes, so it can be displayed in a terminal. Every row of the layout
+//! becomes a line of text and all the colors are mapped to the nearest color
+//! of the terminal's 256 color palette. Icons can't be displayed and are left
+//! out.
+
+use std::io::{self, Write};
+use layout::LayoutState;
+use settings::{Color, Gradient};
+use super::{total_height, Backend, TextAlignment, TextFont, ROW_HEIGHT};
+
+#[derive(Copy, Clone)]
+struct Cell {
+    character: char,
+    foreground: [f32; 3],
+    background: [f32; 3],
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self {
+            character: ' ',
+            foreground: [1.0; 3],
+            background: [0.0; 3],
+        }
+    }
+}
+
+fn blend(below: [f32; 3], color: Color) -> [f32; 3] {
+    let [r, g, b, a]: [f32; 4] = color.rgba.to_pixel();
+    [
+        below[0] + (r - below[0]) * a,
+        below[1] + (g - below[1]) * a,
+        below[2] + (b - below[2]) * a,
+    ]
+}
+
+fn mix(a: Color, b: Color, t: f32) -> Color {
+    let (a, b): ([f32; 4], [f32; 4]) = (a.rgba.to_pixel(), b.rgba.to_pixel());
+    let t = t.clamp(0.0, 1.0);
+    (
+        a[0] + (b[0] - a[0]) * t,
+        a[1] + (b[1] - a[1]) * t,
+        a[2] + (b[2] - a[2]) * t,
+        a[3] + (b[3] - a[3]) * t,
+    ).into()
+}
+
+/// Control characters, like the escape character, would be interpreted by the
+/// terminal, so text of the layout could change what gets displayed. They are
+/// shown as this character instead.
+const CONTROL_CHARACTER_PLACEHOLDER: char = '\u{FFFD}';
+
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+fn distance(a: [u8; 3], b: [u8; 3]) -> u32 {
+    a.iter()
+        .zip(&b)
+        .map(|(&a, &b)| (a as i32 - b as i32).pow(2) as u32)
+        .sum()
+}
+
+/// Finds the color of the 256 color palette that is the closest to the color
+/// provided. The 16 system colors are left out, as terminals often change
+/// what they look like.
+fn palette_index(color: [f32; 3]) -> u8 {
+    let rgb = [
+        (color[0] * 255.0).round() as u8,
+        (color[1] * 255.0).round() as u8,
+        (color[2] * 255.0).round() as u8,
+    ];
+
+    let mut cube = [0; 3];
+    for (level, &channel) in cube.iter_mut().zip(&rgb) {
+        *level = (0..CUBE_LEVELS.len())
+            .min_by_key(|&i| (CUBE_LEVELS[i] as i32 - channel as i32).abs())
+            .unwrap();
+    }
+    let cube_color = [
+        CUBE_LEVELS[cube[0]],
+        CUBE_LEVELS[cube[1]],
+        CUBE_LEVELS[cube[2]],
+    ];
+
+    let average = (rgb.iter().map(|&c| c as u32).sum::<u32>() / 3) as u8;
+    let gray = (average.saturating_sub(3) / 10).min(23);
+    let gray_level = 8 + 10 * gray;
+
+    if distance(rgb, [gray_level; 3]) < distance(rgb, cube_color) {
+        232 + gray
+    } else {
+        16 + 36 * cube[0] as u8 + 6 * cube[1] as u8 + cube[2] as u8
+    }
+}
+
+/// How wide a character is in relation to the height of a row. Characters are
+/// a lot wider than they would be with a proportional font, so the layout is
+/// squeezed horizontally to make the text fit.
+const CHARACTER_WIDTH: f32 = 1.0 / 3.0;
+
+/// A grid of colored characters that the layout gets drawn into. Every row is
+/// one unit high, while every character is only `CHARACTER_WIDTH` units wide.
+pub struct Terminal {
+    columns: usize,
+    rows: usize,
+    cells: Vec<Cell>,
+}
+
+impl Terminal {
+    pub fn new(columns: usize, rows: usize) -> Self {
+        Self {
+            columns,
+            rows,
+            cells: vec![Cell::default(); columns * rows],
+        }
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Accesses the text of a row without any colors.
+    pub fn row_text(&self, row: usize) -> String {
+        self.cells[row * self.columns..][..self.columns]
+            .iter()
+            .map(|c| c.character)
+            .collect()
+    }
+
+    /// Writes all the rows, colored with ANSI escape codes. Each row ends
+    /// with the colors being reset.
+    pub fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        for row in self.cells.chunks(self.columns.max(1)).take(self.rows) {
+            let mut current = None;
+            for cell in row {
+                let colors = (
+                    palette_index(cell.foreground),
+                    palette_index(cell.background),
+                );
+                if current != Some(colors) {
+                    write!(writer, "\x1b[38;5;{};48;5;{}m", colors.0, colors.1)?;
+                    current = Some(colors);
+                }
+                write!(writer, "{}", cell.character)?;
+            }
+            writeln!(writer, "\x1b[0m")?;
+        }
+        Ok(())
+    }
+
+    fn resize(&mut self, columns: usize, rows: usize) {
+        self.columns = columns;
+        self.rows = rows;
+        self.cells.clear();
+        self.cells.resize(columns * rows, Cell::default());
+    }
+
+    fn cell(&mut self, column: i32, row: i32) -> Option<&mut Cell> {
+        if column < 0 || row < 0 || column as usize >= self.columns || row as usize >= self.rows {
+            None
+        } else {
+            Some(&mut self.cells[row as usize * self.columns + column as usize])
+        }
+    }
+
+    /// Blends the color on top of the background of the cell. Opaque colors
+    /// hide the character of the cell.
+    fn paint(&mut self, column: i32, row: i32, color: Color) {
+        if let Some(cell) = self.cell(column, row) {
+            cell.background = blend(cell.background, color);
+            if color.rgba.alpha >= 1.0 {
+                cell.character = ' ';
+            }
+        }
+    }
+}
+
+/// Calculates the range of cells whose centers are between the two
+/// coordinates.
+fn cell_range(from: f32, to: f32) -> ::std::ops::Range<i32> {
+    (from - 0.5).ceil() as i32..(to - 0.5).ceil() as i32
+}
+
+impl Backend for Terminal {
+    type Image = ();
+
+    fn fill_rectangle(&mut self, top_left: [f32; 2], bottom_right: [f32; 2], gradient: &Gradient) {
+        let [left, top] = top_left;
+        let [right, bottom] = bottom_right;
+        let (left, right) = (left / CHARACTER_WIDTH, right / CHARACTER_WIDTH);
+        let rows = cell_range(top.max(0.0), bottom.min(self.rows as f32));
+        for row in rows {
+            let columns = cell_range(left.max(0.0), right.min(self.columns as f32));
+            for column in columns {
+                let color = match *gradient {
+                    Gradient::Transparent => return,
+                    Gradient::Plain(color) => color,
+                    Gradient::Vertical(a, b) => {
+                        mix(a, b, (row as f32 + 0.5 - top) / (bottom - top))
+                    }
+                    Gradient::Horizontal(a, b) => {
+                        mix(a, b, (column as f32 + 0.5 - left) / (right - left))
+                    }
+                };
+                self.paint(column, row, color);
+            }
+        }
+    }
+
+    fn fill_polygon(&mut self, points: &[[f32; 2]], color: Color) {
+        let points = points
+            .iter()
+            .map(|&[x, y]| [x / CHARACTER_WIDTH, y])
+            .collect::<Vec<_>>();
+        let min = points
+            .iter()
+            .fold([f32::INFINITY; 2], |m, p| [m[0].min(p[0]), m[1].min(p[1])]);
+        let max = points
+            .iter()
+            .fold([f32::NEG_INFINITY; 2], |m, p| [m[0].max(p[0]), m[1].max(p[1])]);
+        let (min_x, max_x) = (min[0].max(0.0), max[0].min(self.columns as f32));
+        let (min_y, max_y) = (min[1].max(0.0), max[1].min(self.rows as f32));
+
+        for row in cell_range(min_y, max_y) {
+            for column in cell_range(min_x, max_x) {
+                let (x, y) = (column as f32 + 0.5, row as f32 + 0.5);
+                let mut inside = false;
+                for (i, &[x1, y1]) in points.iter().enumerate() {
+                    let [x2, y2] = points[(i + 1) % points.len()];
+                    if (y1 <= y) != (y2 <= y) && x < x1 + (y - y1) / (y2 - y1) * (x2 - x1) {
+                        inside = !inside;
+                    }
+                }
+                if inside {
+                    self.paint(column, row, color);
+                }
+            }
+        }
+    }
+
+    fn stroke_line(&mut self, [x1, y1]: [f32; 2], [x2, y2]: [f32; 2], _: f32, color: Color) {
+        let (x1, x2) = (x1 / CHARACTER_WIDTH, x2 / CHARACTER_WIDTH);
+        let steps = (2.0 * (x2 - x1).abs().max((y2 - y1).abs())).ceil().min(1000.0) as usize;
+        let mut cells = Vec::new();
+        for step in 0..steps + 1 {
+            let t = step as f32 / steps.max(1) as f32;
+            let x = (x1 + (x2 - x1) * t).floor() as i32;
+            let y = (y1 + (y2 - y1) * t).floor() as i32;
+            if !cells.contains(&(x, y)) {
+                cells.push((x, y));
+            }
+        }
+        for (x, y) in cells {
+            self.paint(x, y, color);
+        }
+    }
+
+    fn draw_text(
+        &mut self,
+        text: &str,
+        [x, y]: [f32; 2],
+        size: f32,
+        font: TextFont,
+        [top, bottom]: [Color; 2],
+        alignment: TextAlignment,
+    ) {
+        let width = self.measure_text(text, size, font);
+        let left = match alignment {
+            TextAlignment::Left => x,
+            TextAlignment::Center => x - 0.5 * width,
+            TextAlignment::Right => x - width,
+        } / CHARACTER_WIDTH;
+        let row = (y - 0.35 * size).floor() as i32;
+        let color = mix(top, bottom, 0.5);
+
+        for (i, character) in text.chars().enumerate() {
+            if let Some(cell) = self.cell(left.round() as i32 + i as i32, row) {
+                cell.character = if character.is_control() {
+                    CONTROL_CHARACTER_PLACEHOLDER
+                } else {
+                    character
+                };
+                cell.foreground = blend(cell.background, color);
+            }
+        }
+    }
+
+    fn measure_text(&mut self, text: &str, _: f32, _: TextFont) -> f32 {
+        text.chars().count() as f32 * CHARACTER_WIDTH
+    }
+
+    fn create_image(&mut self, _: &str) -> Option<()> {
+        None
+    }
+
+    fn draw_image(&mut self, _: &(), _: [f32; 2], _: [f32; 2]) {}
+}
+
+/// Renders layout states as text colored with ANSI escape codes.
+pub struct Renderer {
+    renderer: super::Renderer<Terminal>,
+    terminal: Terminal,
+}
+
+impl Default for Renderer {
+    fn default() -> Self {
+        Self {
+            renderer: super::Renderer::new(),
+            terminal: Terminal::new(0, 0),
+        }
+    }
+}
+
+impl Renderer {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Renders the layout state with the amount of columns provided. The
+    /// amount of rows depends on the components of the layout.
+    pub fn render(&mut self, state: &LayoutState, columns: usize) -> &Terminal {
+        let rows = total_height(state) / ROW_HEIGHT;
+        self.terminal.resize(columns, rows.ceil() as usize);
+        self.renderer
+            .render(&mut self.terminal, state, [columns as f32 * CHARACTER_WIDTH, rows]);
+        &self.terminal
+    }
+}
@@ -0,0 +1,1236 @@
+//! The rendering module provides a renderer for layout states that is
+//! independent of what it actually draws to. The renderer figures out where
+//! every component and all of its text, shapes and icons go and hands the
+//! resulting primitives to a backend. The `svg` module provides a backend that
+//! creates SVG documents and the `ansi` module one that draws to terminals.
+//! Enabling the `software-rendering` feature provides a backend that
+//! rasterizes everything on the CPU into an RGBA image.
+
+pub mod ansi;
+#[cfg(feature = "software-rendering")]
+pub mod software;
+pub mod svg;
+
+use base64::{self, STANDARD};
+use component::{comparison_table, container, detailed_timer, graph, run_prediction,
+                segment_history_graph, segment_statistics, splits, text, timer, title};
+use layout::{ComponentState, HorizontalArrangement, LayoutState};
+use settings::{Color, Font, Gradient, LayoutDirection};
+
+const ROW_HEIGHT: f32 = 24.0;
+const TEXT_SIZE: f32 = 15.0;
+const PADDING: f32 = 6.0;
+const COLUMN_WIDTH: f32 = 64.0;
+const SEPARATOR_THICKNESS: f32 = 2.0;
+const THIN_SEPARATOR_THICKNESS: f32 = 1.0;
+const GRAPH_LINE_THICKNESS: f32 = 2.0;
+const GRAPH_POINT_RADIUS: f32 = 2.5;
+const SPLIT_COLUMN_WIDTH: f32 = 2.0 * COLUMN_WIDTH;
+const BLOCK_WIDTH: f32 = 4.0 * COLUMN_WIDTH;
+
+/// Describes which font some text is supposed to be drawn with.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FontKind {
+    /// The font used for the timers.
+    Timer,
+    /// The font used for the times of the splits and the other components.
+    Times,
+    /// The font used for all the other text.
+    Text,
+}
+
+/// The font some text is drawn with. This is the font the component
+/// overrides the layout's font with, or otherwise the layout's font for the
+/// kind of text. If neither specifies a font, it's up to the backend to pick
+/// one for the kind of text.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TextFont<'a> {
+    pub kind: FontKind,
+    pub font: Option<&'a Font>,
+}
+
+/// Describes how text is aligned relative to the position it is drawn at.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TextAlignment {
+    Left,
+    Center,
+    Right,
+}
+
+/// A backend is what the renderer draws to. All coordinates are in pixels,
+/// with the origin being the top left corner of the image.
+pub trait Backend {
+    /// An image that can be drawn by the backend.
+    type Image;
+
+    /// Fills the rectangle spanned by the top left and the bottom right
+    /// corner with the gradient.
+    fn fill_rectangle(&mut self, top_left: [f32; 2], bottom_right: [f32; 2], gradient: &Gradient);
+
+    /// Fills the polygon described by the points with the color.
+    fn fill_polygon(&mut self, points: &[[f32; 2]], color: Color);
+
+    /// Draws a line between the two points with the thickness provided.
+    fn stroke_line(&mut self, from: [f32; 2], to: [f32; 2], thickness: f32, color: Color);
+
+    /// Draws the text at the position provided, which is on the text's
+    /// baseline. The alignment describes whether the position is the left
+    /// end, the center or the right end of the text. The size already
+    /// includes the scale of the font. The text is colored with a vertical
+    /// gradient from the first to the second color.
+    fn draw_text(
+        &mut self,
+        text: &str,
+        position: [f32; 2],
+        size: f32,
+        font: TextFont,
+        colors: [Color; 2],
+        alignment: TextAlignment,
+    );
+
+    /// Measures how wide the text is when drawn with the size and font
+    /// provided.
+    fn measure_text(&mut self, text: &str, size: f32, font: TextFont) -> f32;
+
+    /// Creates an image from its URL. `None` is returned if the image can't
+    /// be decoded.
+    fn create_image(&mut self, url: &str) -> Option<Self::Image>;
+
+    /// Draws the image into the rectangle, scaled to fit the rectangle while
+    /// keeping its aspect ratio.
+    fn draw_image(&mut self, image: &Self::Image, top_left: [f32; 2], bottom_right: [f32; 2]);
+}
+
+/// The renderer lays out the components of a layout state and draws them with
+/// a backend. The icons of the layout state are only provided whenever they
+/// change, so the same renderer needs to be used for every frame of a layout.
+pub struct Renderer<B: Backend> {
+    split_icons: Vec<Option<B::Image>>,
+    title_icon: Option<B::Image>,
+    detailed_timer_icon: Option<B::Image>,
+}
+
+impl<B: Backend> Default for Renderer<B> {
+    fn default() -> Self {
+        Self {
+            split_icons: Vec::new(),
+            title_icon: None,
+            detailed_timer_icon: None,
+        }
+    }
+}
+
+impl<B: Backend> Renderer<B> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Renders the layout state with the backend. The layout gets scaled
+    /// uniformly such that it spans the whole height provided. The
+    /// components of a horizontal layout are placed next to each other,
+    /// starting at the left.
+    pub fn render(&mut self, backend: &mut B, state: &LayoutState, [width, height]: [f32; 2]) {
+        self.update_icons(backend, state);
+
+        backend.fill_rectangle([0.0, 0.0], [width, height], &state.background);
+
+        let total_height = total_height(state);
+        if total_height <= 0.0 || width <= 0.0 || height <= 0.0 {
+            return;
+        }
+
+        let scale = height / total_height;
+        let mut context = Context {
+            backend,
+            scale,
+            top: 0.0,
+            left: 0.0,
+            width: width / scale,
+            layout_fonts: Fonts {
+                timer: state.timer_font.clone(),
+                times: state.times_font.clone(),
+                text: state.text_font.clone(),
+            },
+            fonts: Fonts::default(),
+            text_color: state.text_color,
+            separators_color: state.separators_color,
+            thin_separators_color: state.thin_separators_color,
+        };
+
+        match state.direction {
+            LayoutDirection::Vertical => for component in &state.components {
+                let height = component_height(component);
+                self.render_component(&mut context, component, height);
+                context.top += height;
+            },
+            LayoutDirection::Horizontal => for component in &state.components {
+                let width = self.component_width(&mut context, component);
+                context.width = width;
+                self.render_horizontal_component(&mut context, component, total_height);
+                context.left += width;
+            },
+        }
+    }
+
+    fn update_icons(&mut self, backend: &mut B, state: &LayoutState) {
+        for component in &state.components {
+            self.update_component_icons(backend, component);
+        }
+    }
+
+    fn update_component_icons(&mut self, backend: &mut B, component: &ComponentState) {
+        match *component {
+            ComponentState::Container(ref state) => for child in &state.children {
+                self.update_component_icons(backend, &child.state);
+            },
+            ComponentState::Splits(ref state) => for change in &state.icon_changes {
+                if change.segment_index >= self.split_icons.len() {
+                    let len = change.segment_index + 1;
+                    self.split_icons.extend((self.split_icons.len()..len).map(|_| None));
+                }
+                self.split_icons[change.segment_index] = create_icon(backend, &change.icon);
+            },
+            ComponentState::Title(ref state) => if let Some(ref url) = state.icon_change {
+                self.title_icon = create_icon(backend, url);
+            },
+            ComponentState::DetailedTimer(ref state) => if let Some(ref url) = state.icon_change {
+                self.detailed_timer_icon = create_icon(backend, url);
+            },
+            _ => {}
+        }
+    }
+
+    fn render_component(&self, context: &mut Context<B>, component: &ComponentState, height: f32) {
+        context.fonts = context.layout_fonts.for_component(component);
+        match *component {
+            ComponentState::BlankSpace(ref state) => context.background(&state.background, height),
+            ComponentState::ComparisonTable(ref state) => render_comparison_table(context, state),
+            ComponentState::Container(ref state) => self.render_container(context, state, height),
+            ComponentState::DetailedTimer(ref state) => {
+                render_detailed_timer(context, state, self.detailed_timer_icon.as_ref())
+            }
+            ComponentState::Graph(ref state) => render_graph(context, state),
+            ComponentState::RunPrediction(ref state) => render_run_prediction(context, state),
+            ComponentState::SegmentHistoryGraph(ref state) => {
+                render_segment_history_graph(context, state)
+            }
+            ComponentState::SegmentStatistics(ref state) => {
+                render_segment_statistics(context, state)
+            }
+            ComponentState::Separator(_) => {
+                let color = context.separators_color;
+                context.background(&Gradient::Plain(color), height);
+            }
+            ComponentState::Splits(ref state) => render_splits(context, state, &self.split_icons),
+            ComponentState::Text(ref state) => render_text(context, state),
+            ComponentState::Timer(ref state) => {
+                context.background(&state.background, height);
+                let width = context.width;
+                context.timer(state, 0.0, width, height);
+            }
+            ComponentState::Title(ref state) => {
+                render_title(context, state, self.title_icon.as_ref(), height)
+            }
+            _ => if let Some((background, colors, rows)) = key_values(component) {
+                render_key_values(context, background, colors, &rows);
+            },
+        }
+    }
+
+    fn render_container(&self, context: &mut Context<B>, state: &container::State, height: f32) {
+        let (top, left, width) = (context.top, context.left, context.width);
+        match state.direction {
+            LayoutDirection::Horizontal => {
+                let total = state.children.iter().map(|c| c.size).sum::<f32>();
+                if total <= 0.0 {
+                    return;
+                }
+                // The children of a row all share the height of the row.
+                for child in &state.children {
+                    context.width = width * child.size / total;
+                    self.render_component(context, &child.state, height);
+                    context.left += context.width;
+                }
+            }
+            LayoutDirection::Vertical => for child in &state.children {
+                let height = component_height(&child.state);
+                self.render_component(context, &child.state, height);
+                context.top += height;
+            },
+        }
+        context.top = top;
+        context.left = left;
+        context.width = width;
+    }
+
+    /// Renders a component of a horizontal layout into a column that spans
+    /// the whole height provided.
+    fn render_horizontal_component(
+        &self,
+        context: &mut Context<B>,
+        component: &ComponentState,
+        height: f32,
+    ) {
+        context.fonts = context.layout_fonts.for_component(component);
+        match component.horizontal_arrangement() {
+            HorizontalArrangement::Stacked => if let Some((background, colors, rows)) =
+                key_values(component)
+            {
+                render_stacked_key_values(context, background, colors, &rows, height);
+            },
+            HorizontalArrangement::Columns => if let ComponentState::Splits(ref state) = *component
+            {
+                render_split_columns(context, state, &self.split_icons, height);
+            },
+            HorizontalArrangement::Block => if let ComponentState::Separator(_) = *component {
+                let color = context.separators_color;
+                context.background(&Gradient::Plain(color), height);
+            } else {
+                let component_height = component_height(component);
+                context.top = 0.5 * (height - component_height);
+                self.render_component(context, component, component_height);
+                context.top = 0.0;
+            },
+        }
+    }
+
+    /// Calculates how wide a component is when the components of the layout
+    /// are placed next to each other.
+    fn component_width(&self, context: &mut Context<B>, component: &ComponentState) -> f32 {
+        context.fonts = context.layout_fonts.for_component(component);
+        if let Some((_, _, rows)) = key_values(component) {
+            if component.horizontal_arrangement() == HorizontalArrangement::Stacked {
+                return rows.iter()
+                    .map(|&(label, value)| stacked_width(context, label, value))
+                    .sum();
+            }
+        }
+        match *component {
+            ComponentState::BlankSpace(ref state) => state.height as f32,
+            ComponentState::Separator(_) => SEPARATOR_THICKNESS,
+            ComponentState::Splits(ref state) => state.splits.len() as f32 * SPLIT_COLUMN_WIDTH,
+            ComponentState::Text(text::State(text::Text::Center(ref text))) => {
+                context.measure(text, TEXT_SIZE, FontKind::Text) + 2.0 * PADDING
+            }
+            ComponentState::Timer(ref state) => {
+                let size = 0.8 * state.height as f32;
+                context.fonts.override_timer(state);
+                context.measure(&state.time, size, FontKind::Timer)
+                    + context.measure(&state.fraction, 0.6 * size, FontKind::Timer)
+                    + 2.0 * PADDING
+            }
+            ComponentState::Title(ref state) => {
+                let lines = Some(&state.line1).into_iter().chain(&state.line2);
+                let text = lines
+                    .map(|l| context.measure(l, TEXT_SIZE, FontKind::Text))
+                    .fold(0.0, f32::max);
+                let attempts = match attempts(state) {
+                    ref attempts if attempts.is_empty() => 0.0,
+                    attempts => context.measure(&attempts, TEXT_SIZE, FontKind::Text) + PADDING,
+                };
+                let icon = if self.title_icon.is_some() {
+                    component_height(component)
+                } else {
+                    0.0
+                };
+                text + attempts + icon + 2.0 * PADDING
+            }
+            _ => BLOCK_WIDTH,
+        }
+    }
+}
+
+/// Decodes the data of an image that is stored as a base64 encoded Data-URL.
+/// `None` is returned for any other kind of URL.
+pub fn decode_data_url(url: &str) -> Option<Vec<u8>> {
+    if !url.starts_with("data:") {
+        return None;
+    }
+    let data = &url[url.find(";base64,")? + ";base64,".len()..];
+    base64::decode_config(data, STANDARD).ok()
+}
+
+fn create_icon<B: Backend>(backend: &mut B, url: &str) -> Option<B::Image> {
+    if url.is_empty() {
+        None
+    } else {
+        backend.create_image(url)
+    }
+}
+
+/// Calculates the height of the layout. Horizontal layouts are as high as
+/// their highest component.
+fn total_height(state: &LayoutState) -> f32 {
+    match state.direction {
+        LayoutDirection::Vertical => state.components.iter().map(component_height).sum(),
+        LayoutDirection::Horizontal => state
+            .components
+            .iter()
+            .map(|c| match c.horizontal_arrangement() {
+                HorizontalArrangement::Stacked | HorizontalArrangement::Columns => rows(2),
+                HorizontalArrangement::Block => match *c {
+                    ComponentState::Separator(_) => 0.0,
+                    _ => component_height(c),
+                },
+            })
+            .fold(0.0, f32::max),
+    }
+}
+
+fn rows(count: usize) -> f32 {
+    count as f32 * ROW_HEIGHT
+}
+
+fn component_height(component: &ComponentState) -> f32 {
+    match *component {
+        ComponentState::BlankSpace(ref state) => state.height as f32,
+        ComponentState::ComparisonTable(ref state) => rows(state.rows.len() + 1),
+        ComponentState::Container(ref state) => {
+            let heights = state.children.iter().map(|c| component_height(&c.state));
+            match state.direction {
+                LayoutDirection::Horizontal => heights.fold(0.0, f32::max),
+                LayoutDirection::Vertical => heights.sum(),
+            }
+        }
+        ComponentState::CurrentComparison(_)
+        | ComponentState::CurrentPace(_)
+        | ComponentState::Delta(_)
+        | ComponentState::PbChance(_)
+        | ComponentState::PossibleTimeSave(_)
+        | ComponentState::PreviousSegment(_)
+        | ComponentState::SumOfBest(_)
+        | ComponentState::Text(_)
+        | ComponentState::TotalPlaytime(_) => ROW_HEIGHT,
+        ComponentState::DetailedTimer(ref state) => {
+            (state.timer.height + state.segment_timer.height) as f32
+        }
+        ComponentState::Graph(ref state) => state.height as f32,
+        ComponentState::RunMetadata(ref state) => rows(state.rows.len()),
+        ComponentState::RunPrediction(ref state) => rows(state.rows.len()),
+        ComponentState::SegmentHistoryGraph(ref state) => ROW_HEIGHT + state.height as f32,
+        ComponentState::SegmentStatistics(ref state) => rows(state.rows.len() + 1),
+        ComponentState::Separator(_) => SEPARATOR_THICKNESS,
+        ComponentState::SessionStatistics(ref state) => rows(state.rows.len()),
+        ComponentState::Splits(ref state) => {
+            rows(state.splits.len() + state.column_labels.is_some() as usize)
+        }
+        ComponentState::Timer(ref state) => state.height as f32,
+        ComponentState::Title(ref state) => rows(1 + state.line2.is_some() as usize),
+        ComponentState::WallClock(ref state) => rows(
+            1 + state.time_since_start.is_some() as usize + state.session_time.is_some() as usize,
+        ),
+    }
+}
+
+/// The fonts for each kind of text. Fonts that aren't specified are left to
+/// the backend.
+#[derive(Clone, Default)]
+struct Fonts {
+    timer: Option<Font>,
+    times: Option<Font>,
+    text: Option<Font>,
+}
+
+impl Fonts {
+    fn get(&self, kind: FontKind) -> TextFont {
+        let font = match kind {
+            FontKind::Timer => &self.timer,
+            FontKind::Times => &self.times,
+            FontKind::Text => &self.text,
+        };
+        TextFont {
+            kind,
+            font: font.as_ref(),
+        }
+    }
+
+    /// Applies the fonts the component overrides the layout's fonts with.
+    /// The timers override their font when they get drawn, as the detailed
+    /// timer consists of two timers with fonts of their own.
+    fn for_component(&self, component: &ComponentState) -> Self {
+        let mut fonts = self.clone();
+        match *component {
+            ComponentState::Splits(ref state) => {
+                override_font(&mut fonts.text, &state.text_font);
+                override_font(&mut fonts.times, &state.times_font);
+            }
+            ComponentState::Title(ref state) => override_font(&mut fonts.text, &state.text_font),
+            _ => {}
+        }
+        fonts
+    }
+
+    fn override_timer(&mut self, state: &timer::State) {
+        override_font(&mut self.timer, &state.timer_font);
+    }
+}
+
+fn override_font(font: &mut Option<Font>, font_override: &Option<Font>) {
+    if font_override.is_some() {
+        *font = font_override.clone();
+    }
+}
+
+/// Draws with the backend in the coordinate space of the component that is
+/// currently being rendered. The layout is scaled such that the coordinates
+/// are independent of the size of the image.
+struct Context<'b, B: 'b + Backend> {
+    backend: &'b mut B,
+    scale: f32,
+    top: f32,
+    left: f32,
+    width: f32,
+    layout_fonts: Fonts,
+    /// The fonts of the component that is currently being rendered.
+    fonts: Fonts,
+    text_color: Color,
+    separators_color: Color,
+    thin_separators_color: Color,
+}
+
+impl<'b, B: Backend> Context<'b, B> {
+    fn point(&self, [x, y]: [f32; 2]) -> [f32; 2] {
+        [(self.left + x) * self.scale, (self.top + y) * self.scale]
+    }
+
+    fn rectangle(&mut self, top_left: [f32; 2], bottom_right: [f32; 2], gradient: &Gradient) {
+        let (top_left, bottom_right) = (self.point(top_left), self.point(bottom_right));
+        self.backend.fill_rectangle(top_left, bottom_right, gradient);
+    }
+
+    fn background(&mut self, gradient: &Gradient, height: f32) {
+        let width = self.width;
+        self.rectangle([0.0, 0.0], [width, height], gradient);
+    }
+
+    fn polygon(&mut self, points: &[[f32; 2]], color: Color) {
+        let points = points.iter().map(|&p| self.point(p)).collect::<Vec<_>>();
+        self.backend.fill_polygon(&points, color);
+    }
+
+    fn line(&mut self, from: [f32; 2], to: [f32; 2], thickness: f32, color: Color) {
+        let (from, to) = (self.point(from), self.point(to));
+        self.backend
+            .stroke_line(from, to, thickness * self.scale, color);
+    }
+
+    fn circle(&mut self, [x, y]: [f32; 2], radius: f32, color: Color) {
+        let points = (0..12)
+            .map(|i| {
+                let angle = i as f32 * ::std::f32::consts::PI / 6.0;
+                [x + radius * angle.cos(), y + radius * angle.sin()]
+            })
+            .collect::<Vec<_>>();
+        self.polygon(&points, color);
+    }
+
+    fn image(&mut self, image: &B::Image, top_left: [f32; 2], bottom_right: [f32; 2]) {
+        let (top_left, bottom_right) = (self.point(top_left), self.point(bottom_right));
+        self.backend.draw_image(image, top_left, bottom_right);
+    }
+
+    fn measure(&mut self, text: &str, size: f32, font: FontKind) -> f32 {
+        let font = self.fonts.get(font);
+        let size = size * font.font.map_or(1.0, |f| f.scale);
+        self.backend.measure_text(text, size * self.scale, font) / self.scale
+    }
+
+    fn aligned_text(
+        &mut self,
+        text: &str,
+        position: [f32; 2],
+        size: f32,
+        font: FontKind,
+        colors: [Color; 2],
+        alignment: TextAlignment,
+    ) {
+        let position = self.point(position);
+        let font = self.fonts.get(font);
+        let size = size * font.font.map_or(1.0, |f| f.scale);
+        self.backend
+            .draw_text(text, position, size * self.scale, font, colors, alignment);
+    }
+
+    fn text(
+        &mut self,
+        text: &str,
+        position: [f32; 2],
+        size: f32,
+        font: FontKind,
+        colors: [Color; 2],
+    ) {
+        self.aligned_text(text, position, size, font, colors, TextAlignment::Left);
+    }
+
+    /// Draws the text such that it ends at the x coordinate provided and
+    /// returns where it starts.
+    fn right_aligned(
+        &mut self,
+        text: &str,
+        position: [f32; 2],
+        size: f32,
+        font: FontKind,
+        colors: [Color; 2],
+    ) -> f32 {
+        self.aligned_text(text, position, size, font, colors, TextAlignment::Right);
+        position[0] - self.measure(text, size, font)
+    }
+
+    /// Cuts off the end of the text and appends an ellipsis, if the text
+    /// doesn't fit into the width provided.
+    fn fit(&mut self, text: &str, width: f32) -> String {
+        if self.measure(text, TEXT_SIZE, FontKind::Text) <= width {
+            return text.to_string();
+        }
+        let mut fitted = text.to_string();
+        while fitted.pop().is_some() {
+            let len = fitted.len();
+            fitted.push('…');
+            if self.measure(&fitted, TEXT_SIZE, FontKind::Text) <= width {
+                return fitted;
+            }
+            fitted.truncate(len);
+        }
+        fitted
+    }
+
+    fn label_value_colors(&self, label: Option<Color>, value: Option<Color>) -> (Color, Color) {
+        (
+            label.unwrap_or(self.text_color),
+            value.unwrap_or(self.text_color),
+        )
+    }
+
+    /// Draws left aligned text between the two x coordinates of the row
+    /// starting at the y coordinate provided.
+    fn label(&mut self, top: f32, left: f32, right: f32, text: &str, color: Color) {
+        let text = self.fit(text, right - left);
+        let position = [left, baseline(top, ROW_HEIGHT, TEXT_SIZE)];
+        self.text(&text, position, TEXT_SIZE, FontKind::Text, [color, color]);
+    }
+
+    /// Draws a row with the label on the left and the value on the right.
+    fn key_value(
+        &mut self,
+        top: f32,
+        (label, label_color): (&str, Color),
+        (value, color): (&str, Color),
+        value_font: FontKind,
+    ) {
+        let left = self.columns(top, &[(value, color)], value_font);
+        let right = if value.is_empty() {
+            self.width - PADDING
+        } else {
+            left - PADDING
+        };
+        self.label(top, PADDING, right, label, label_color);
+    }
+
+    /// Draws the values right aligned into fixed size columns at the right
+    /// side of the row. Where the leftmost text starts is returned.
+    fn columns(&mut self, top: f32, columns: &[(&str, Color)], font: FontKind) -> f32 {
+        let baseline = baseline(top, ROW_HEIGHT, TEXT_SIZE);
+        let mut right = self.width - PADDING;
+        let mut left = right;
+        for &(value, color) in columns.iter().rev() {
+            let start = self.right_aligned(
+                value,
+                [right, baseline],
+                TEXT_SIZE,
+                font,
+                [color, color],
+            );
+            left = left.min(start);
+            right -= COLUMN_WIDTH;
+        }
+        left
+    }
+
+    /// Draws the labels of the columns, shortened to fit into the columns.
+    fn column_labels(&mut self, top: f32, labels: &[&str], color: Color) {
+        let labels = labels
+            .iter()
+            .map(|l| self.fit(l, COLUMN_WIDTH - PADDING))
+            .collect::<Vec<_>>();
+        let labels = labels
+            .iter()
+            .map(|l| (l.as_str(), color))
+            .collect::<Vec<_>>();
+        self.columns(top, &labels, FontKind::Text);
+    }
+
+    /// Draws the time and the fraction of a timer right aligned, ending at
+    /// the x coordinate provided.
+    fn timer(&mut self, state: &timer::State, top: f32, right: f32, height: f32) {
+        let fonts = self.fonts.clone();
+        self.fonts.override_timer(state);
+        let size = 0.8 * height;
+        let fraction_size = 0.6 * size;
+        let baseline = baseline(top, height, size);
+        let colors = [state.top_color, state.bottom_color];
+        let right = right - PADDING;
+        let left = self.right_aligned(
+            &state.fraction,
+            [right, baseline],
+            fraction_size,
+            FontKind::Timer,
+            colors,
+        );
+        self.right_aligned(&state.time, [left, baseline], size, FontKind::Timer, colors);
+        self.fonts = fonts;
+    }
+}
+
+fn baseline(top: f32, height: f32, size: f32) -> f32 {
+    top + 0.5 * height + 0.35 * size
+}
+
+type KeyValues<'a> = (&'a Gradient, [Option<Color>; 2], Vec<(&'a str, &'a str)>);
+
+/// Collects the background, the label and value colors and the rows of the
+/// components that consist of labels and values.
+fn key_values(component: &ComponentState) -> Option<KeyValues> {
+    let (background, colors, rows) = match *component {
+        ComponentState::CurrentComparison(ref state) => (
+            &state.background,
+            [state.label_color, state.value_color],
+            vec![(state.text.as_str(), state.comparison.as_str())],
+        ),
+        ComponentState::CurrentPace(ref state) => (
+            &state.background,
+            [state.label_color, state.value_color],
+            vec![(state.text.as_str(), state.time.as_str())],
+        ),
+        ComponentState::Delta(ref state) => (
+            &state.background,
+            [state.label_color, Some(state.visual_color)],
+            vec![(state.text.as_str(), state.time.as_str())],
+        ),
+        ComponentState::PbChance(ref state) => (
+            &state.background,
+            [state.label_color, state.value_color],
+            vec![(state.text.as_str(), state.chance.as_str())],
+        ),
+        ComponentState::PossibleTimeSave(ref state) => (
+            &state.background,
+            [state.label_color, state.value_color],
+            vec![(state.text.as_str(), state.time.as_str())],
+        ),
+        ComponentState::PreviousSegment(ref state) => (
+            &state.background,
+            [state.label_color, Some(state.visual_color)],
+            vec![(state.text.as_str(), state.time.as_str())],
+        ),
+        ComponentState::RunMetadata(ref state) => (
+            &state.background,
+            [state.label_color, state.value_color],
+            state
+                .rows
+                .iter()
+                .map(|r| (r.name.as_str(), r.value.as_str()))
+                .collect(),
+        ),
+        ComponentState::SessionStatistics(ref state) => (
+            &state.background,
+            [state.label_color, state.value_color],
+            state
+                .rows
+                .iter()
+                .map(|r| (r.name.as_str(), r.value.as_str()))
+                .collect(),
+        ),
+        ComponentState::SumOfBest(ref state) => (
+            &state.background,
+            [state.label_color, state.value_color],
+            vec![(state.text.as_str(), state.time.as_str())],
+        ),
+        ComponentState::Text(text::State(text::Text::Split(ref left, ref right))) => (
+            &Gradient::Transparent,
+            [None, None],
+            vec![(left.as_str(), right.as_str())],
+        ),
+        ComponentState::TotalPlaytime(ref state) => (
+            &state.background,
+            [state.label_color, state.value_color],
+            vec![(state.text.as_str(), state.time.as_str())],
+        ),
+        ComponentState::WallClock(ref state) => (
+            &state.background,
+            [state.label_color, state.value_color],
+            Some(("Wall Clock", &state.time))
+                .into_iter()
+                .chain(state.time_since_start.iter().map(|t| ("Time Since Start", t)))
+                .chain(state.session_time.iter().map(|t| ("Session Time", t)))
+                .map(|(name, value)| (name, value.as_str()))
+                .collect(),
+        ),
+        _ => return None,
+    };
+    Some((background, colors, rows))
+}
+
+/// Renders a row with a label and a value for each of the pairs provided.
+fn render_key_values<B: Backend>(
+    context: &mut Context<B>,
+    background: &Gradient,
+    [label_color, value_color]: [Option<Color>; 2],
+    rows: &[(&str, &str)],
+) {
+    context.background(background, self::rows(rows.len()));
+    let (label, value) = context.label_value_colors(label_color, value_color);
+    for (i, &(name, time)) in rows.iter().enumerate() {
+        context.key_value(i as f32 * ROW_HEIGHT, (name, label), (time, value), FontKind::Times);
+    }
+}
+
+fn stacked_width<B: Backend>(context: &mut Context<B>, label: &str, value: &str) -> f32 {
+    let label = context.measure(label, TEXT_SIZE, FontKind::Text);
+    let value = context.measure(value, TEXT_SIZE, FontKind::Times);
+    label.max(value) + 2.0 * PADDING
+}
+
+/// Renders the pairs next to each other, with every label being centered
+/// above its value.
+fn render_stacked_key_values<B: Backend>(
+    context: &mut Context<B>,
+    background: &Gradient,
+    [label_color, value_color]: [Option<Color>; 2],
+    rows: &[(&str, &str)],
+    height: f32,
+) {
+    context.background(background, height);
+    let (label_color, value_color) = context.label_value_colors(label_color, value_color);
+    let top = 0.5 * (height - self::rows(2));
+    let mut left = 0.0;
+    for &(label, value) in rows {
+        let width = stacked_width(context, label, value);
+        let center = left + 0.5 * width;
+        let lines = [
+            (label, label_color, top, FontKind::Text),
+            (value, value_color, top + ROW_HEIGHT, FontKind::Times),
+        ];
+        for &(text, color, top, font) in &lines {
+            context.aligned_text(
+                text,
+                [center, baseline(top, ROW_HEIGHT, TEXT_SIZE)],
+                TEXT_SIZE,
+                font,
+                [color, color],
+                TextAlignment::Center,
+            );
+        }
+        left += width;
+    }
+}
+
+fn render_comparison_table<B: Backend>(context: &mut Context<B>, state: &comparison_table::State) {
+    context.background(&state.background, rows(state.rows.len() + 1));
+    let (label, value) = context.label_value_colors(state.label_color, state.value_color);
+    context.column_labels(
+        0.0,
+        &[&state.comparison1, &state.comparison2, "+/-", "Total"],
+        label,
+    );
+    for (i, row) in state.rows.iter().enumerate() {
+        let top = (i + 1) as f32 * ROW_HEIGHT;
+        let left = context.columns(
+            top,
+            &[
+                (&row.segment_time1, value),
+                (&row.segment_time2, value),
+                (&row.segment_difference, row.segment_visual_color),
+                (&row.total_difference, row.total_visual_color),
+            ],
+            FontKind::Times,
+        );
+        context.label(top, PADDING, left - PADDING, &row.name, label);
+    }
+}
+
+fn render_detailed_timer<B: Backend>(
+    context: &mut Context<B>,
+    state: &detailed_timer::State,
+    icon: Option<&B::Image>,
+) {
+    let timer_height = state.timer.height as f32;
+    let segment_timer_height = state.segment_timer.height as f32;
+    let height = timer_height + segment_timer_height;
+    context.background(&state.background, height);
+
+    let width = context.width;
+    context.timer(&state.timer, 0.0, width, timer_height);
+    context.timer(&state.segment_timer, timer_height, width, segment_timer_height);
+
+    let mut left = PADDING;
+    if let Some(icon) = icon {
+        let size = height - 2.0 * PADDING;
+        context.image(icon, [left, PADDING], [left + size, PADDING + size]);
+        left += size + PADDING;
+    }
+
+    let size = 0.8 * TEXT_SIZE;
+    let line_height = (0.8 * ROW_HEIGHT).min(height / 3.0);
+    let color = context.text_color;
+    let lines = state.segment_name.iter().cloned().chain(
+        state
+            .comparison1
+            .iter()
+            .chain(&state.comparison2)
+            .map(|c| format!("{}: {}", c.name, c.time)),
+    );
+    for (i, line) in lines.enumerate() {
+        let baseline = baseline(i as f32 * line_height, line_height, size);
+        context.text(&line, [left, baseline], size, FontKind::Text, [color, color]);
+    }
+}
+
+fn render_graph<B: Backend>(context: &mut Context<B>, state: &graph::State) {
+    let (width, height) = (context.width, state.height as f32);
+    let middle = state.middle * height;
+    context.rectangle(
+        [0.0, 0.0],
+        [width, middle],
+        &Gradient::Plain(state.top_background_color),
+    );
+    context.rectangle(
+        [0.0, middle],
+        [width, height],
+        &Gradient::Plain(state.bottom_background_color),
+    );
+
+    for &y in &state.horizontal_grid_lines {
+        let y = y * height;
+        context.line([0.0, y], [width, y], 1.0, state.grid_lines_color);
+    }
+    for &x in &state.vertical_grid_lines {
+        let x = x * width;
+        context.line([x, 0.0], [x, height], 1.0, state.grid_lines_color);
+    }
+
+    let points = state
+        .points
+        .iter()
+        .map(|p| [p.x * width, p.y * height])
+        .collect::<Vec<_>>();
+    if points.len() < 2 {
+        return;
+    }
+
+    let complete = if state.is_live_delta_active {
+        points.len() - 1
+    } else {
+        points.len()
+    };
+    if complete >= 2 {
+        let mut fill = vec![[points[0][0], middle]];
+        fill.extend_from_slice(&points[..complete]);
+        fill.push([points[complete - 1][0], middle]);
+        context.polygon(&fill, state.complete_fill_color);
+    }
+    if state.is_live_delta_active {
+        let (from, to) = (points[points.len() - 2], points[points.len() - 1]);
+        context.polygon(
+            &[[from[0], middle], from, to, [to[0], middle]],
+            state.partial_fill_color,
+        );
+    }
+
+    for (i, pair) in points.windows(2).enumerate() {
+        let color = if state.points[i + 1].is_best_segment {
+            state.best_segment_color
+        } else {
+            state.graph_lines_color
+        };
+        context.line(pair[0], pair[1], GRAPH_LINE_THICKNESS, color);
+    }
+
+    for (point, &position) in state.points.iter().zip(&points).skip(1).take(complete - 1) {
+        let color = if point.is_best_segment {
+            state.best_segment_color
+        } else {
+            state.graph_lines_color
+        };
+        context.circle(position, GRAPH_POINT_RADIUS, color);
+    }
+}
+
+fn render_run_prediction<B: Backend>(context: &mut Context<B>, state: &run_prediction::State) {
+    context.background(&state.background, rows(state.rows.len()));
+    let (label, value) = context.label_value_colors(state.label_color, state.value_color);
+    for (i, row) in state.rows.iter().enumerate() {
+        let top = i as f32 * ROW_HEIGHT;
+        let columns = [(row.time.as_str(), value), (row.delta.as_str(), row.visual_color)];
+        let left = context.columns(top, &columns, FontKind::Times);
+        context.label(top, PADDING, left - PADDING, &row.comparison, label);
+    }
+}
+
+fn render_segment_statistics<B: Backend>(
+    context: &mut Context<B>,
+    state: &segment_statistics::State,
+) {
+    context.background(&state.background, rows(state.rows.len() + 1));
+    let (label, value) = context.label_value_colors(state.label_color, state.value_color);
+    let width = context.width;
+    context.label(0.0, PADDING, width - PADDING, &state.segment_name, label);
+    for (i, row) in state.rows.iter().enumerate() {
+        let top = (i + 1) as f32 * ROW_HEIGHT;
+        context.key_value(top, (&row.name, label), (&row.value, value), FontKind::Times);
+    }
+}
+
+fn render_segment_history_graph<B: Backend>(
+    context: &mut Context<B>,
+    state: &segment_history_graph::State,
+) {
+    let height = state.height as f32;
+    context.background(&state.background, ROW_HEIGHT + height);
+    let (width, color) = (context.width, context.text_color);
+    context.label(0.0, PADDING, width - PADDING, &state.segment_name, color);
+
+    let (left, top) = (PADDING, ROW_HEIGHT);
+    let (graph_width, graph_height) = (width - 2.0 * PADDING, height - PADDING);
+    let point = |x: f32, y: f32| [left + x * graph_width, top + y * graph_height];
+
+    if state.is_histogram {
+        let bar_width = 1.0 / state.bars.len() as f32;
+        for (i, &bar) in state.bars.iter().enumerate() {
+            let x = i as f32 * bar_width;
+            context.rectangle(
+                point(x, 1.0 - bar),
+                point(x + bar_width, 1.0),
+                &Gradient::Plain(state.graph_color),
+            );
+        }
+    } else {
+        for pair in state.points.windows(2) {
+            let (from, to) = (point(pair[0].x, pair[0].y), point(pair[1].x, pair[1].y));
+            context.line(from, to, GRAPH_LINE_THICKNESS, state.graph_color);
+        }
+        for p in state.points.iter().filter(|p| p.is_best_segment) {
+            context.circle(point(p.x, p.y), GRAPH_POINT_RADIUS, state.best_segment_color);
+        }
+    }
+
+    let markers = [
+        (state.personal_best_marker, state.personal_best_color),
+        (state.best_segment_marker, state.best_segment_color),
+        (state.live_segment_marker, state.live_segment_color),
+    ];
+    for &(marker, color) in &markers {
+        if let Some(marker) = marker {
+            let (from, to) = if state.is_histogram {
+                (point(marker, 0.0), point(marker, 1.0))
+            } else {
+                (point(0.0, marker), point(1.0, marker))
+            };
+            context.line(from, to, 1.0, color);
+        }
+    }
+}
+
+fn render_splits<B: Backend>(
+    context: &mut Context<B>,
+    state: &splits::State,
+    icons: &[Option<B::Image>],
+) {
+    let width = context.width;
+    let color = context.text_color;
+    let mut top = 0.0;
+
+    if let Some(ref labels) = state.column_labels {
+        let labels = labels.iter().map(String::as_str).collect::<Vec<_>>();
+        context.column_labels(top, &labels, color);
+        top += ROW_HEIGHT;
+    }
+
+    let icon = |split: &splits::SplitState| icons.get(split.index).and_then(Option::as_ref);
+    let has_icons = state.splits.iter().any(|s| icon(s).is_some());
+
+    for (i, split) in state.splits.iter().enumerate() {
+        if split.is_current_split {
+            context.rectangle(
+                [0.0, top],
+                [width, top + ROW_HEIGHT],
+                &state.current_split_gradient,
+            );
+        }
+
+        let is_last = i + 1 == state.splits.len();
+        if is_last && state.show_final_separator && i > 0 {
+            let color = context.separators_color;
+            context.rectangle(
+                [0.0, top - 0.5 * SEPARATOR_THICKNESS],
+                [width, top + 0.5 * SEPARATOR_THICKNESS],
+                &Gradient::Plain(color),
+            );
+        } else if i > 0 {
+            let color = context.thin_separators_color;
+            context.rectangle(
+                [0.0, top - 0.5 * THIN_SEPARATOR_THICKNESS],
+                [width, top + 0.5 * THIN_SEPARATOR_THICKNESS],
+                &Gradient::Plain(color),
+            );
+        }
+
+        let mut left = PADDING;
+        if has_icons {
+            let size = ROW_HEIGHT - PADDING;
+            if let Some(icon) = icon(split) {
+                let icon_top = top + 0.5 * PADDING;
+                context.image(icon, [left, icon_top], [left + size, icon_top + size]);
+            }
+            left += size + PADDING;
+        }
+
+        let columns = split
+            .columns
+            .iter()
+            .map(|c| (c.value.as_str(), c.visual_color))
+            .collect::<Vec<_>>();
+        let right = context.columns(top, &columns, FontKind::Times);
+        context.label(top, left, right - PADDING, &split.name, color);
+
+        top += ROW_HEIGHT;
+    }
+}
+
+/// Renders every split as a column of its own, with the name of the split
+/// above the values of its last two columns.
+fn render_split_columns<B: Backend>(
+    context: &mut Context<B>,
+    state: &splits::State,
+    icons: &[Option<B::Image>],
+    height: f32,
+) {
+    let (left, width) = (context.left, context.width);
+    let color = context.text_color;
+    let top = 0.5 * (height - rows(2));
+
+    let icon = |split: &splits::SplitState| icons.get(split.index).and_then(Option::as_ref);
+    let has_icons = state.splits.iter().any(|s| icon(s).is_some());
+
+    context.width = SPLIT_COLUMN_WIDTH;
+    for (i, split) in state.splits.iter().enumerate() {
+        context.left = left + i as f32 * SPLIT_COLUMN_WIDTH;
+        if split.is_current_split {
+            context.background(&state.current_split_gradient, height);
+        }
+
+        let is_last = i + 1 == state.splits.len();
+        if is_last && state.show_final_separator && i > 0 {
+            let color = context.separators_color;
+            context.rectangle(
+                [-0.5 * SEPARATOR_THICKNESS, 0.0],
+                [0.5 * SEPARATOR_THICKNESS, height],
+                &Gradient::Plain(color),
+            );
+        } else if i > 0 {
+            let color = context.thin_separators_color;
+            context.rectangle(
+                [-0.5 * THIN_SEPARATOR_THICKNESS, 0.0],
+                [0.5 * THIN_SEPARATOR_THICKNESS, height],
+                &Gradient::Plain(color),
+            );
+        }
+
+        let mut name_left = PADDING;
+        if has_icons {
+            let size = ROW_HEIGHT - PADDING;
+            if let Some(icon) = icon(split) {
+                let icon_top = top + 0.5 * PADDING;
+                context.image(icon, [name_left, icon_top], [name_left + size, icon_top + size]);
+            }
+            name_left += size + PADDING;
+        }
+        context.label(top, name_left, SPLIT_COLUMN_WIDTH - PADDING, &split.name, color);
+
+        let columns = split
+            .columns
+            .iter()
+            .map(|c| (c.value.as_str(), c.visual_color))
+            .collect::<Vec<_>>();
+        let shown = columns.len().saturating_sub(2);
+        context.columns(top + ROW_HEIGHT, &columns[shown..], FontKind::Times);
+    }
+    context.left = left;
+    context.width = width;
+}
+
+fn render_text<B: Backend>(context: &mut Context<B>, state: &text::State) {
+    let width = context.width;
+    let color = context.text_color;
+    match state.0 {
+        text::Text::Center(ref text) => {
+            let text = context.fit(text, width - 2.0 * PADDING);
+            let position = [0.5 * width, baseline(0.0, ROW_HEIGHT, TEXT_SIZE)];
+            context.aligned_text(
+                &text,
+                position,
+                TEXT_SIZE,
+                FontKind::Text,
+                [color, color],
+                TextAlignment::Center,
+            );
+        }
+        text::Text::Split(ref left, ref right) => {
+            context.key_value(0.0, (left, color), (right, color), FontKind::Text);
+        }
+    }
+}
+
+fn attempts(state: &title::State) -> String {
+    match (state.finished_runs, state.attempts) {
+        (Some(finished), Some(attempts)) => format!("{}/{}", finished, attempts),
+        (Some(count), None) | (None, Some(count)) => count.to_string(),
+        (None, None) => String::new(),
+    }
+}
+
+fn render_title<B: Backend>(
+    context: &mut Context<B>,
+    state: &title::State,
+    icon: Option<&B::Image>,
+    height: f32,
+) {
+    context.background(&state.background, height);
+    let width = context.width;
+    let color = state.text_color.unwrap_or(context.text_color);
+
+    let mut left = PADDING;
+    if let Some(icon) = icon {
+        let size = height - PADDING;
+        let top = 0.5 * PADDING;
+        context.image(icon, [left, top], [left + size, top + size]);
+        left += size + PADDING;
+    }
+
+    let attempts = attempts(state);
+    let last_line = height - ROW_HEIGHT;
+    let attempts_left = context.right_aligned(
+        &attempts,
+        [width - PADDING, baseline(last_line, ROW_HEIGHT, TEXT_SIZE)],
+        TEXT_SIZE,
+        FontKind::Text,
+        [color, color],
+    );
+
+    let lines = Some(&state.line1).into_iter().chain(&state.line2);
+    for (i, line) in lines.enumerate() {
+        let top = i as f32 * ROW_HEIGHT;
+        let right = if top == last_line && !attempts.is_empty() {
+            attempts_left - PADDING
+        } else {
+            width - PADDING
+        };
+        if state.is_centered {
+            let line = context.fit(line, right - left);
+            let line_width = context.measure(&line, TEXT_SIZE, FontKind::Text);
+            let line_left = (0.5 * (width - line_width)).max(left).min(right - line_width);
+            let baseline = baseline(top, ROW_HEIGHT, TEXT_SIZE);
+            context.text(&line, [line_left, baseline], TEXT_SIZE, FontKind::Text, [color, color]);
+        } else {
+            context.label(top, left, right, line, color);
+        }
+    }
+}
@@ -0,0 +1,355 @@
+//! Provides a backend that rasterizes the layout on the CPU into an RGBA
+//! image. It doesn't require a GPU or a windowing system, so it can be used
+//! anywhere, even in a headless environment.
+
+use imagelib::{self, RgbaImage};
+use layout::LayoutState;
+use rusttype::{point, Font, Scale};
+use settings::{Color, Gradient};
+use super::{decode_data_url, Backend, FontKind, TextAlignment, TextFont};
+
+/// The fonts the software renderer draws the text with. Font families can't
+/// be looked up, so the fonts of the layout only influence the size of the
+/// text. The times are drawn with the text font.
+#[derive(Clone)]
+pub struct Fonts {
+    timer: Font<'static>,
+    text: Font<'static>,
+}
+
+impl Fonts {
+    /// Loads the fonts from the data of TrueType or OpenType font files.
+    /// `None` is returned if any of the fonts can't be loaded.
+    pub fn from_bytes(timer: Vec<u8>, text: Vec<u8>) -> Option<Self> {
+        Some(Self {
+            timer: Font::from_bytes(timer).ok()?,
+            text: Font::from_bytes(text).ok()?,
+        })
+    }
+
+    fn get(&self, kind: FontKind) -> &Font<'static> {
+        match kind {
+            FontKind::Timer => &self.timer,
+            FontKind::Times | FontKind::Text => &self.text,
+        }
+    }
+}
+
+/// A canvas is an RGBA image that the layout gets rasterized into. Text is
+/// only drawn if the canvas has fonts to draw it with.
+pub struct Canvas {
+    image: RgbaImage,
+    fonts: Option<Fonts>,
+}
+
+impl Canvas {
+    /// Creates a new, fully transparent canvas.
+    pub fn new(width: u32, height: u32, fonts: Option<Fonts>) -> Self {
+        Self {
+            image: RgbaImage::new(width, height),
+            fonts,
+        }
+    }
+
+    pub fn image(&self) -> &RgbaImage {
+        &self.image
+    }
+
+    pub fn into_image(self) -> RgbaImage {
+        self.image
+    }
+
+    /// Makes the whole canvas transparent again.
+    pub fn clear(&mut self) {
+        for pixel in self.image.pixels_mut() {
+            pixel.data = [0; 4];
+        }
+    }
+
+    fn resize(&mut self, width: u32, height: u32) {
+        if self.image.dimensions() != (width, height) {
+            self.image = RgbaImage::new(width, height);
+        } else {
+            self.clear();
+        }
+    }
+
+    /// Rounds the point to the nearest pixel corner within the canvas.
+    fn clamp(&self, [x, y]: [f32; 2]) -> (i32, i32) {
+        let (width, height) = self.image.dimensions();
+        (
+            x.round().max(0.0).min(width as f32) as i32,
+            y.round().max(0.0).min(height as f32) as i32,
+        )
+    }
+
+    /// Blends the color with the coverage provided on top of the pixel.
+    fn blend(&mut self, x: i32, y: i32, [r, g, b, a]: [f32; 4], coverage: f32) {
+        let (width, height) = self.image.dimensions();
+        if x < 0 || y < 0 || x as u32 >= width || y as u32 >= height {
+            return;
+        }
+        let alpha = a * coverage.min(1.0);
+        if alpha <= 0.0 {
+            return;
+        }
+
+        let pixel = &mut self.image.get_pixel_mut(x as u32, y as u32).data;
+        let dst_alpha = pixel[3] as f32 / 255.0;
+        let out_alpha = alpha + dst_alpha * (1.0 - alpha);
+        for (channel, &src) in pixel.iter_mut().zip(&[r, g, b]) {
+            let dst = *channel as f32 / 255.0;
+            let out = (src * alpha + dst * dst_alpha * (1.0 - alpha)) / out_alpha;
+            *channel = to_u8(out);
+        }
+        pixel[3] = to_u8(out_alpha);
+    }
+}
+
+fn to_u8(value: f32) -> u8 {
+    (value * 255.0).round() as u8
+}
+
+fn lerp(a: [f32; 4], b: [f32; 4], t: f32) -> [f32; 4] {
+    let t = t.clamp(0.0, 1.0);
+    [
+        a[0] + (b[0] - a[0]) * t,
+        a[1] + (b[1] - a[1]) * t,
+        a[2] + (b[2] - a[2]) * t,
+        a[3] + (b[3] - a[3]) * t,
+    ]
+}
+
+fn pixel(color: Color) -> [f32; 4] {
+    color.rgba.to_pixel()
+}
+
+/// The amount of scanlines sampled per row of pixels when filling polygons.
+const SUBSAMPLES: usize = 4;
+
+impl Backend for Canvas {
+    type Image = RgbaImage;
+
+    fn fill_rectangle(&mut self, top_left: [f32; 2], bottom_right: [f32; 2], gradient: &Gradient) {
+        let [left, top] = top_left;
+        let [right, bottom] = bottom_right;
+        let (x1, y1) = self.clamp([left, top]);
+        let (x2, y2) = self.clamp([right, bottom]);
+        let (width, height) = ((right - left).max(1.0), (bottom - top).max(1.0));
+
+        for y in y1..y2 {
+            for x in x1..x2 {
+                let color = match *gradient {
+                    Gradient::Transparent => return,
+                    Gradient::Plain(color) => pixel(color),
+                    Gradient::Vertical(a, b) => {
+                        lerp(pixel(a), pixel(b), (y as f32 + 0.5 - top) / height)
+                    }
+                    Gradient::Horizontal(a, b) => {
+                        lerp(pixel(a), pixel(b), (x as f32 + 0.5 - left) / width)
+                    }
+                };
+                self.blend(x, y, color, 1.0);
+            }
+        }
+    }
+
+    fn fill_polygon(&mut self, points: &[[f32; 2]], color: Color) {
+        if points.len() < 3 {
+            return;
+        }
+        let color = pixel(color);
+        let min = points
+            .iter()
+            .fold([f32::INFINITY; 2], |m, p| [m[0].min(p[0]), m[1].min(p[1])]);
+        let max = points
+            .iter()
+            .fold([f32::NEG_INFINITY; 2], |m, p| [m[0].max(p[0]), m[1].max(p[1])]);
+        let (min_x, min_y) = self.clamp([min[0].floor(), min[1].floor()]);
+        let (max_x, max_y) = self.clamp([max[0].ceil(), max[1].ceil()]);
+        let columns = (max_x - min_x) as usize;
+
+        let mut crossings = Vec::new();
+
+        for y in min_y..max_y {
+            let mut coverage = vec![0.0; columns];
+
+            for sample in 0..SUBSAMPLES {
+                let sample_y = y as f32 + (sample as f32 + 0.5) / SUBSAMPLES as f32;
+                crossings.clear();
+                for (i, &[x1, y1]) in points.iter().enumerate() {
+                    let [x2, y2] = points[(i + 1) % points.len()];
+                    if (y1 <= sample_y) != (y2 <= sample_y) {
+                        crossings.push(x1 + (sample_y - y1) / (y2 - y1) * (x2 - x1));
+                    }
+                }
+                crossings.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+                for span in crossings.chunks(2) {
+                    if let [start, end] = *span {
+                        let start = (start - min_x as f32).max(0.0);
+                        let end = (end - min_x as f32).min(columns as f32);
+                        if end <= start {
+                            continue;
+                        }
+                        let columns = start.floor() as usize..end.ceil() as usize;
+                        for (c, column) in coverage[columns.clone()].iter_mut().zip(columns) {
+                            let left = start.max(column as f32);
+                            let right = end.min(column as f32 + 1.0);
+                            if right > left {
+                                *c += (right - left) / SUBSAMPLES as f32;
+                            }
+                        }
+                    }
+                }
+            }
+
+            for (column, &c) in coverage.iter().enumerate() {
+                if c > 0.0 {
+                    self.blend(min_x + column as i32, y, color, c);
+                }
+            }
+        }
+    }
+
+    fn stroke_line(
+        &mut self,
+        [x1, y1]: [f32; 2],
+        [x2, y2]: [f32; 2],
+        thickness: f32,
+        color: Color,
+    ) {
+        let (dx, dy) = (x2 - x1, y2 - y1);
+        let length = dx.hypot(dy);
+        if length <= 0.0 {
+            return;
+        }
+        let (nx, ny) = (-dy / length * 0.5 * thickness, dx / length * 0.5 * thickness);
+        self.fill_polygon(
+            &[
+                [x1 + nx, y1 + ny],
+                [x2 + nx, y2 + ny],
+                [x2 - nx, y2 - ny],
+                [x1 - nx, y1 - ny],
+            ],
+            color,
+        );
+    }
+
+    fn draw_text(
+        &mut self,
+        text: &str,
+        [x, y]: [f32; 2],
+        size: f32,
+        text_font: TextFont,
+        [top_color, bottom_color]: [Color; 2],
+        alignment: TextAlignment,
+    ) {
+        let font = match self.fonts {
+            Some(ref fonts) => fonts.get(text_font.kind).clone(),
+            None => return,
+        };
+        let x = match alignment {
+            TextAlignment::Left => x,
+            TextAlignment::Center => x - 0.5 * self.measure_text(text, size, text_font),
+            TextAlignment::Right => x - self.measure_text(text, size, text_font),
+        };
+        let scale = Scale::uniform(size);
+        let metrics = font.v_metrics(scale);
+        let (top, height) = (y - metrics.ascent, metrics.ascent - metrics.descent);
+        let (top_color, bottom_color) = (pixel(top_color), pixel(bottom_color));
+
+        for glyph in font.layout(text, scale, point(x, y)) {
+            if let Some(bounds) = glyph.pixel_bounding_box() {
+                glyph.draw(|gx, gy, coverage| {
+                    let (px, py) = (bounds.min.x + gx as i32, bounds.min.y + gy as i32);
+                    let color = lerp(top_color, bottom_color, (py as f32 + 0.5 - top) / height);
+                    self.blend(px, py, color, coverage);
+                });
+            }
+        }
+    }
+
+    fn measure_text(&mut self, text: &str, size: f32, font: TextFont) -> f32 {
+        let font = match self.fonts {
+            Some(ref fonts) => fonts.get(font.kind),
+            None => return 0.0,
+        };
+        font.layout(text, Scale::uniform(size), point(0.0, 0.0))
+            .last()
+            .map_or(0.0, |g| {
+                g.position().x + g.unpositioned().h_metrics().advance_width
+            })
+    }
+
+    fn create_image(&mut self, url: &str) -> Option<RgbaImage> {
+        let data = decode_data_url(url)?;
+        imagelib::load_from_memory(&data).ok().map(|i| i.to_rgba())
+    }
+
+    fn draw_image(&mut self, image: &RgbaImage, top_left: [f32; 2], bottom_right: [f32; 2]) {
+        let (image_width, image_height) = image.dimensions();
+        if image_width == 0 || image_height == 0 {
+            return;
+        }
+        let [left, top] = top_left;
+        let (width, height) = (bottom_right[0] - left, bottom_right[1] - top);
+        let scale = (width / image_width as f32).min(height / image_height as f32);
+        if scale <= 0.0 {
+            return;
+        }
+        let (width, height) = (image_width as f32 * scale, image_height as f32 * scale);
+        let left = left + 0.5 * (bottom_right[0] - top_left[0] - width);
+        let top = top + 0.5 * (bottom_right[1] - top_left[1] - height);
+
+        let (x1, y1) = self.clamp([left, top]);
+        let (x2, y2) = self.clamp([left + width, top + height]);
+
+        for y in y1..y2 {
+            for x in x1..x2 {
+                let source_x = ((x as f32 + 0.5 - left) / scale) as u32;
+                let source_y = ((y as f32 + 0.5 - top) / scale) as u32;
+                let source = image
+                    .get_pixel(source_x.min(image_width - 1), source_y.min(image_height - 1))
+                    .data;
+                let color = [
+                    source[0] as f32 / 255.0,
+                    source[1] as f32 / 255.0,
+                    source[2] as f32 / 255.0,
+                    source[3] as f32 / 255.0,
+                ];
+                self.blend(x, y, color, 1.0);
+            }
+        }
+    }
+}
+
+/// Renders layout states into RGBA images on the CPU.
+pub struct Renderer {
+    renderer: super::Renderer<Canvas>,
+    canvas: Canvas,
+}
+
+impl Renderer {
+    pub fn new(fonts: Fonts) -> Self {
+        Self {
+            renderer: super::Renderer::new(),
+            canvas: Canvas::new(0, 0, Some(fonts)),
+        }
+    }
+
+    /// Renders the layout state into an image with the width and height
+    /// provided. The same renderer needs to be used for every frame, as the
+    /// icons of the layout state are only provided whenever they change.
+    pub fn render(&mut self, state: &LayoutState, [width, height]: [u32; 2]) -> &RgbaImage {
+        self.canvas.resize(width, height);
+        self.renderer
+            .render(&mut self.canvas, state, [width as f32, height as f32]);
+        self.canvas.image()
+    }
+
+    /// Accesses the image that got rendered last.
+    pub fn image(&self) -> &RgbaImage {
+        self.canvas.image()
+    }
+}
@@ -0,0 +1,304 @@
+//! Provides a backend that turns the layout into a standalone SVG document.
+//! Text stays text and the icons are embedded as images, so the document can
+//! be scaled to any resolution. The fonts of the layout are referred to by
+//! their family, so they are only used if they are available wherever the
+//! document is displayed. As the backend has no access to the fonts the text
+//! is displayed with, the width of text is only estimated.
+
+use std::fmt;
+use std::io::{self, Write};
+use layout::LayoutState;
+use settings::{Color, FontStyle, FontWeight, Gradient};
+use super::{Backend, FontKind, TextAlignment, TextFont};
+
+const TIMER_FONT: &str = "monospace";
+const TEXT_FONT: &str = "sans-serif";
+
+/// Formats a coordinate with at most two decimal places.
+struct Number(f32);
+
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = (self.0 * 100.0).round() / 100.0;
+        if value == 0.0 {
+            write!(f, "0")
+        } else {
+            write!(f, "{}", value)
+        }
+    }
+}
+
+fn channel(value: f32) -> u8 {
+    (value * 255.0).round() as u8
+}
+
+/// Returns the color as a hex string and its opacity.
+fn color(color: Color) -> (String, f32) {
+    let [r, g, b, a]: [f32; 4] = color.rgba.to_pixel();
+    (
+        format!("#{:02x}{:02x}{:02x}", channel(r), channel(g), channel(b)),
+        (a * 1000.0).round() / 1000.0,
+    )
+}
+
+/// Creates the attributes that describe the font. The generic family of the
+/// kind of text serves as the fallback for the font's family.
+fn font_attributes(font: TextFont) -> String {
+    let generic_family = match font.kind {
+        FontKind::Timer => TIMER_FONT,
+        FontKind::Times | FontKind::Text => TEXT_FONT,
+    };
+    let font = match font.font {
+        Some(font) => font,
+        None => return format!(r#"font-family="{}""#, generic_family),
+    };
+    let mut attributes = format!(
+        r#"font-family="'{}', {}""#,
+        escape(&font.family.replace('\'', "")),
+        generic_family
+    );
+    if font.style == FontStyle::Italic {
+        attributes.push_str(r#" font-style="italic""#);
+    }
+    if font.weight != FontWeight::Normal {
+        attributes.push_str(&format!(r#" font-weight="{}""#, font.weight.value()));
+    }
+    attributes
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// An SVG document that the layout gets drawn into.
+#[derive(Default)]
+pub struct Document {
+    definitions: String,
+    elements: String,
+    gradients: usize,
+}
+
+impl Document {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Writes the document with the width and height provided.
+    pub fn write<W: Write>(&self, mut writer: W, [width, height]: [f32; 2]) -> io::Result<()> {
+        writeln!(
+            writer,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" \
+             xmlns:xlink=\"http://www.w3.org/1999/xlink\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">",
+            w = Number(width),
+            h = Number(height)
+        )?;
+        if !self.definitions.is_empty() {
+            writeln!(writer, "<defs>\n{}</defs>", self.definitions)?;
+        }
+        writeln!(writer, "{}</svg>", self.elements)
+    }
+
+    /// Removes everything that has been drawn so far.
+    pub fn clear(&mut self) {
+        self.definitions.clear();
+        self.elements.clear();
+        self.gradients = 0;
+    }
+
+    fn linear_gradient(&mut self, [x2, y2]: [u8; 2], top: Color, bottom: Color) -> String {
+        let id = format!("gradient{}", self.gradients);
+        self.gradients += 1;
+        self.definitions.push_str(&format!(
+            r#"<linearGradient id="{}" x1="0" y1="0" x2="{}" y2="{}">"#,
+            id, x2, y2
+        ));
+        for (offset, &stop) in [top, bottom].iter().enumerate() {
+            let (color, opacity) = color(stop);
+            self.definitions.push_str(&format!(
+                r#"<stop offset="{}" stop-color="{}" stop-opacity="{}"/>"#,
+                offset, color, opacity
+            ));
+        }
+        self.definitions.push_str("</linearGradient>\n");
+        format!("url(#{})", id)
+    }
+
+    /// Creates the attributes for filling an element with the gradient.
+    /// `None` is returned if the element would be invisible.
+    fn fill(&mut self, gradient: &Gradient) -> Option<String> {
+        let fill = match *gradient {
+            Gradient::Transparent => return None,
+            Gradient::Plain(plain) => {
+                let (color, opacity) = color(plain);
+                if opacity <= 0.0 {
+                    return None;
+                } else if opacity >= 1.0 {
+                    format!(r#"fill="{}""#, color)
+                } else {
+                    format!(r#"fill="{}" fill-opacity="{}""#, color, opacity)
+                }
+            }
+            Gradient::Vertical(top, bottom) => {
+                format!(r#"fill="{}""#, self.linear_gradient([0, 1], top, bottom))
+            }
+            Gradient::Horizontal(left, right) => {
+                format!(r#"fill="{}""#, self.linear_gradient([1, 0], left, right))
+            }
+        };
+        Some(fill)
+    }
+}
+
+impl Backend for Document {
+    type Image = String;
+
+    fn fill_rectangle(&mut self, top_left: [f32; 2], bottom_right: [f32; 2], gradient: &Gradient) {
+        if let Some(fill) = self.fill(gradient) {
+            let [left, top] = top_left;
+            self.elements.push_str(&format!(
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" {}/>\n",
+                Number(left),
+                Number(top),
+                Number(bottom_right[0] - left),
+                Number(bottom_right[1] - top),
+                fill
+            ));
+        }
+    }
+
+    fn fill_polygon(&mut self, points: &[[f32; 2]], color: Color) {
+        if let (Some(fill), Some(&[x, y])) = (self.fill(&Gradient::Plain(color)), points.first()) {
+            let mut path = format!("M{} {}", Number(x), Number(y));
+            for &[x, y] in &points[1..] {
+                path.push_str(&format!("L{} {}", Number(x), Number(y)));
+            }
+            self.elements
+                .push_str(&format!("<path d=\"{}Z\" {}/>\n", path, fill));
+        }
+    }
+
+    fn stroke_line(
+        &mut self,
+        [x1, y1]: [f32; 2],
+        [x2, y2]: [f32; 2],
+        thickness: f32,
+        stroke: Color,
+    ) {
+        let (stroke, opacity) = color(stroke);
+        if opacity <= 0.0 {
+            return;
+        }
+        self.elements.push_str(&format!(
+            "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"{}\" stroke-opacity=\"{}\" \
+             stroke-width=\"{}\"/>\n",
+            Number(x1),
+            Number(y1),
+            Number(x2),
+            Number(y2),
+            stroke,
+            opacity,
+            Number(thickness)
+        ));
+    }
+
+    fn draw_text(
+        &mut self,
+        text: &str,
+        [x, y]: [f32; 2],
+        size: f32,
+        font: TextFont,
+        [top, bottom]: [Color; 2],
+        alignment: TextAlignment,
+    ) {
+        if text.is_empty() {
+            return;
+        }
+        let gradient = if top == bottom {
+            Gradient::Plain(top)
+        } else {
+            Gradient::Vertical(top, bottom)
+        };
+        let fill = match self.fill(&gradient) {
+            Some(fill) => fill,
+            None => return,
+        };
+        let anchor = match alignment {
+            TextAlignment::Left => "",
+            TextAlignment::Center => r#" text-anchor="middle""#,
+            TextAlignment::Right => r#" text-anchor="end""#,
+        };
+        self.elements.push_str(&format!(
+            "<text x=\"{}\" y=\"{}\" {} font-size=\"{}\"{} {}>{}</text>\n",
+            Number(x),
+            Number(y),
+            font_attributes(font),
+            Number(size),
+            anchor,
+            fill,
+            escape(text)
+        ));
+    }
+
+    fn measure_text(&mut self, text: &str, size: f32, font: TextFont) -> f32 {
+        let width = match font.kind {
+            FontKind::Timer => 0.6,
+            FontKind::Times | FontKind::Text => 0.55,
+        };
+        width * size * text.chars().count() as f32
+    }
+
+    fn create_image(&mut self, url: &str) -> Option<String> {
+        Some(url.to_owned())
+    }
+
+    fn draw_image(&mut self, url: &String, top_left: [f32; 2], bottom_right: [f32; 2]) {
+        let [left, top] = top_left;
+        self.elements.push_str(&format!(
+            "<image x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" \
+             preserveAspectRatio=\"xMidYMid meet\" xlink:href=\"{}\"/>\n",
+            Number(left),
+            Number(top),
+            Number(bottom_right[0] - left),
+            Number(bottom_right[1] - top),
+            escape(url)
+        ));
+    }
+}
+
+/// Renders layout states into SVG documents.
+#[derive(Default)]
+pub struct Renderer {
+    renderer: super::Renderer<Document>,
+    document: Document,
+}
+
+impl Renderer {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Renders the layout state into an SVG document with the width and
+    /// height provided and writes it to the writer. The same renderer needs
+    /// to be used for every frame, as the icons of the layout state are only
+    /// provided whenever they change.
+    pub fn render<W: Write>(
+        &mut self,
+        state: &LayoutState,
+        dimensions: [f32; 2],
+        writer: W,
+    ) -> io::Result<()> {
+        self.document.clear();
+        self.renderer.render(&mut self.document, state, dimensions);
+        self.document.write(writer, dimensions)
+    }
+}
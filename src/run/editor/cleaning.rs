@@ -1,5 +1,10 @@
 use {Attempt, Run, Segment, TimeSpan, TimingMethod};
 use analysis::sum_of_segments::{best, track_branch};
+use analysis::statistics::{mean, standard_deviation};
+use serde_json::{to_writer, Result as JsonResult};
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::io::Write;
 use std::mem::replace;
 use std::fmt;
 use time::formatter::{Short, TimeFormatter};
@@ -37,14 +42,33 @@ pub struct PotentialCleanUp<'r> {
     combined_sum_of_best: Option<TimeSpan>,
     attempt: &'r Attempt,
     method: TimingMethod,
+    z_score: Option<f64>,
     clean_up: CleanUp,
 }
 
+#[derive(Clone)]
 pub struct CleanUp {
     ending_index: usize,
     run_index: i32,
 }
 
+/// A potential clean up that doesn't borrow the run anymore, so that all of
+/// them can be collected and ranked.
+#[derive(Clone, Serialize)]
+pub struct RankedCleanUp {
+    message: String,
+    z_score: Option<f64>,
+    #[serde(skip_serializing)]
+    clean_up: CleanUp,
+}
+
+/// Lists the clean ups that got applied or would be applied by the automatic
+/// clean up.
+#[derive(Clone, Serialize)]
+pub struct CleanUpReport {
+    pub clean_ups: Vec<RankedCleanUp>,
+}
+
 impl<'r> fmt::Display for PotentialCleanUp<'r> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let short = Short::new();
@@ -98,6 +122,65 @@ impl<'a> From<PotentialCleanUp<'a>> for CleanUp {
     }
 }
 
+impl<'r> PotentialCleanUp<'r> {
+    /// The amount of standard deviations the segment time is faster than the
+    /// mean of the other attempts' times for the same segments. The higher it
+    /// is, the less plausible the segment time is. There's no z-score if
+    /// there are not enough other times to compare against.
+    pub fn z_score(&self) -> Option<f64> {
+        self.z_score
+    }
+}
+
+impl RankedCleanUp {
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn z_score(&self) -> Option<f64> {
+        self.z_score
+    }
+}
+
+impl<'a> From<PotentialCleanUp<'a>> for RankedCleanUp {
+    fn from(potential: PotentialCleanUp) -> Self {
+        Self {
+            message: potential.to_string(),
+            z_score: potential.z_score,
+            clean_up: potential.clean_up,
+        }
+    }
+}
+
+impl From<RankedCleanUp> for CleanUp {
+    fn from(ranked: RankedCleanUp) -> Self {
+        ranked.clean_up
+    }
+}
+
+impl CleanUpReport {
+    pub fn write_json<W>(&self, writer: W) -> JsonResult<()>
+    where
+        W: Write,
+    {
+        to_writer(writer, self)
+    }
+}
+
+impl fmt::Display for CleanUpReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for clean_up in &self.clean_ups {
+            if let Some(z_score) = clean_up.z_score {
+                write!(f, "[z = {:.2}] ", z_score)?;
+            } else {
+                write!(f, "[z = ?] ")?;
+            }
+            writeln!(f, "{}", clean_up.message)?;
+        }
+        Ok(())
+    }
+}
+
 impl<'r> SumOfBestCleaner<'r> {
     pub fn new(run: &'r mut Run) -> Self {
         let predictions = Vec::with_capacity(run.len() + 1);
@@ -117,6 +200,54 @@ impl<'r> SumOfBestCleaner<'r> {
         self.run.mark_as_changed();
     }
 
+    /// Collects all the potential clean ups, starting from the beginning, and
+    /// sorts them by their z-score, so that the least plausible segment times
+    /// come first. The ones without a z-score are sorted last. A segment time
+    /// that is suspicious for both timing methods is only listed once, with
+    /// the higher z-score. This doesn't affect the progress of
+    /// `next_potential_clean_up`.
+    pub fn rank(&mut self) -> Vec<RankedCleanUp> {
+        let mut clean_ups = Vec::new();
+        {
+            let mut cleaner = SumOfBestCleaner::new(&mut *self.run);
+            while let Some(clean_up) = cleaner.next_potential_clean_up() {
+                clean_ups.push(RankedCleanUp::from(clean_up));
+            }
+        }
+
+        clean_ups.sort_by(|a, b| match (a.z_score, b.z_score) {
+            (Some(a), Some(b)) => b.partial_cmp(&a).unwrap_or(Ordering::Equal),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        });
+
+        let mut seen = HashSet::new();
+        clean_ups.retain(|c| seen.insert((c.clean_up.ending_index, c.clean_up.run_index)));
+
+        clean_ups
+    }
+
+    /// Lists all the clean ups the automatic clean up would apply with the
+    /// threshold provided, without applying any of them.
+    pub fn dry_run(&mut self, threshold: f64) -> CleanUpReport {
+        let mut clean_ups = self.rank();
+        clean_ups.retain(|c| c.z_score.map_or(false, |z| z >= threshold));
+        CleanUpReport { clean_ups }
+    }
+
+    /// Automatically applies all the clean ups that have a z-score of at least
+    /// the threshold provided.
+    ///
+    /// Returns a report of all the clean ups that got applied.
+    pub fn auto_clean(&mut self, threshold: f64) -> CleanUpReport {
+        let report = self.dry_run(threshold);
+        for clean_up in &report.clean_ups {
+            self.apply(clean_up.clean_up.clone());
+        }
+        report
+    }
+
     pub fn next_potential_clean_up(&mut self) -> Option<PotentialCleanUp> {
         loop {
             match replace(&mut self.state, State::Poisoned) {
@@ -222,6 +353,13 @@ fn check_prediction<'a>(
                         .find(|attempt| attempt.index() == run_index)
                         .expect("The attempt has to exist"),
                     method,
+                    z_score: z_score(
+                        run,
+                        (starting_index + 1) as usize,
+                        ending_index,
+                        run_index,
+                        method,
+                    ),
                     clean_up: CleanUp {
                         ending_index,
                         run_index,
@@ -248,3 +386,48 @@ fn next_timing_method(run: &Run, predictions: &mut Vec<Option<TimeSpan>>, method
         method,
     );
 }
+
+fn z_score(
+    run: &Run,
+    starting_index: usize,
+    ending_index: usize,
+    run_index: i32,
+    method: TimingMethod,
+) -> Option<f64> {
+    let segments = &run.segments()[starting_index..ending_index + 1];
+    let time = segments.last()?.segment_history().get(run_index)?[method]?;
+
+    // Collect the times of all the other attempts that covered exactly the
+    // same segments, either by splitting in between or by skipping the splits.
+    let times = segments
+        .last()?
+        .segment_history()
+        .iter_actual_runs()
+        .filter(|&&(index, time)| index != run_index && time[method].is_some())
+        .filter_map(|&(index, _)| {
+            if starting_index > 0 {
+                run.segment(starting_index - 1)
+                    .segment_history()
+                    .get(index)?[method]?;
+            }
+            let mut sum = TimeSpan::zero();
+            for segment in segments {
+                if let Some(time) = segment.segment_history().get(index)?[method] {
+                    sum += time;
+                }
+            }
+            Some(sum)
+        })
+        .collect::<Vec<_>>();
+
+    if times.len() < 2 {
+        return None;
+    }
+
+    let standard_deviation = standard_deviation(&times)?.total_seconds();
+    if standard_deviation == 0.0 {
+        return None;
+    }
+
+    Some((mean(&times)?.total_seconds() - time.total_seconds()) / standard_deviation)
+}
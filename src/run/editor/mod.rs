@@ -1,7 +1,10 @@
 use std::num::ParseIntError;
 use std::mem::swap;
-use {unicase, Image, Run, Segment, Time, TimeSpan, TimingMethod};
+use std::path::PathBuf;
+use {Image, Run, Segment, Time, TimeSpan, TimingMethod};
 use time::ParseError as ParseTimeSpanError;
+use super::LinkedComparison;
+use super::linked_comparison::{import_split_times, Error as LoadLinkedComparisonError};
 
 pub mod cleaning;
 mod segment_row;
@@ -30,6 +33,16 @@ quick_error! {
     }
 }
 
+quick_error! {
+    #[derive(Debug)]
+    pub enum LinkError {
+        InvalidName
+        Load(err: LoadLinkedComparisonError) {
+            from()
+        }
+    }
+}
+
 pub struct Editor {
     run: Run,
     selected_method: TimingMethod,
@@ -493,38 +506,7 @@ impl Editor {
         let comparison = comparison.into();
         if validate_comparison_name(&self.run, &comparison) {
             self.run.add_custom_comparison(comparison.as_str());
-
-            // TODO Borrowcheck (remaining_segments isn't used after this block
-            // anymore. NLL should fix this)
-            {
-                let mut remaining_segments = self.run.segments_mut().as_mut_slice();
-
-                for segment in run.segments().iter().take(run.len().saturating_sub(1)) {
-                    // TODO Borrowcheck (new_start is only necessary because the
-                    // remaining_segments reassignment doesn't work inside this
-                    // if)
-                    let new_start = if let Some((segment_index, my_segment)) = remaining_segments
-                        .iter_mut()
-                        .enumerate()
-                        .find(|&(_, ref s)| unicase::eq(segment.name(), s.name()))
-                    {
-                        *my_segment.comparison_mut(&comparison) =
-                            segment.personal_best_split_time();
-                        segment_index + 1
-                    } else {
-                        0
-                    };
-                    // TODO Borrowcheck (get rid of the move block around
-                    // remaining_segments)
-                    remaining_segments = &mut { remaining_segments }[new_start..];
-                }
-            }
-
-            if let (Some(my_segment), Some(segment)) =
-                (self.run.segments_mut().last_mut(), run.segments().last())
-            {
-                *my_segment.comparison_mut(&comparison) = segment.personal_best_split_time();
-            }
+            import_split_times(self.run.segments_mut(), run.segments(), &comparison);
 
             self.fix();
             Ok(())
@@ -533,10 +515,38 @@ impl Editor {
         }
     }
 
+    /// Links a new comparison to the splits file at the path provided. The
+    /// Personal Best split times of that file get imported right away and are
+    /// imported again whenever `Run::update_linked_comparisons` notices that
+    /// the file got modified.
+    pub fn link_comparison<S, P>(&mut self, comparison: S, path: P) -> Result<(), LinkError>
+    where
+        S: Into<String>,
+        P: Into<PathBuf>,
+    {
+        let comparison = comparison.into();
+        if !validate_comparison_name(&self.run, &comparison) {
+            return Err(LinkError::InvalidName);
+        }
+
+        let mut linked = LinkedComparison::new(comparison.as_str(), path);
+        let run = linked.load()?;
+
+        self.run.add_custom_comparison(comparison.as_str());
+        import_split_times(self.run.segments_mut(), run.segments(), &comparison);
+        self.run.linked_comparisons_mut().push(linked);
+
+        self.fix();
+        Ok(())
+    }
+
     pub fn remove_comparison(&mut self, comparison: &str) {
         self.run
             .custom_comparisons_mut()
             .retain(|c| c != comparison);
+        self.run
+            .linked_comparisons_mut()
+            .retain(|c| c.name() != comparison);
 
         for segment in self.run.segments_mut() {
             segment.comparisons_mut().remove(comparison);
@@ -559,6 +569,12 @@ impl Editor {
 
             self.run.custom_comparisons_mut()[position] = new.to_string();
 
+            for linked in self.run.linked_comparisons_mut() {
+                if linked.name() == old {
+                    linked.set_name(new);
+                }
+            }
+
             for segment in self.run.segments_mut() {
                 if let Some(time) = segment.comparisons_mut().remove(old) {
                     *segment.comparison_mut(new) = time;
@@ -1,6 +1,40 @@
-use {Run, Segment};
+use std::{env, process};
+use std::fs::{self, File};
+use std::path::PathBuf;
+use {Run, Segment, Time, TimeSpan};
+use run::parser::livesplit::parse;
+use run::saver::livesplit::save;
 use super::Editor;
 
+fn run_with_splits(splits: &[(&str, f64)]) -> Run {
+    let mut run = Run::new();
+    for &(name, split_time) in splits {
+        let mut segment = Segment::new(name);
+        segment.set_personal_best_split_time(
+            Time::new().with_real_time(Some(TimeSpan::from_seconds(split_time))),
+        );
+        run.push_segment(segment);
+    }
+    run
+}
+
+/// A path in the temporary directory that no other test, or concurrent test
+/// run, uses.
+fn temp_path(test: &str) -> PathBuf {
+    env::temp_dir().join(format!("livesplit-core-{}-{}.lss", test, process::id()))
+}
+
+fn seconds(seconds: f64) -> Option<TimeSpan> {
+    Some(TimeSpan::from_seconds(seconds))
+}
+
+fn comparison_times(run: &Run, comparison: &str) -> Vec<Option<f64>> {
+    run.segments()
+        .iter()
+        .map(|s| s.comparison(comparison).real_time.map(|t| t.total_seconds()))
+        .collect()
+}
+
 #[test]
 fn new_best_segment() {
     let mut run = Run::new();
@@ -60,3 +94,175 @@ fn new_best_segment() {
         Some("0:30".parse().unwrap())
     );
 }
+
+#[test]
+fn import_comparison_interpolates_split_segments() {
+    let run = run_with_splits(&[("A", 10.0), ("B", 30.0), ("C", 60.0)]);
+    let other = run_with_splits(&[("A", 8.0), ("C", 50.0)]);
+
+    let mut editor = Editor::new(run).unwrap();
+    editor.import_comparison(&other, "Ghost").unwrap();
+    let run = editor.close();
+
+    assert_eq!(
+        comparison_times(&run, "Ghost"),
+        [Some(8.0), Some(24.8), Some(50.0)]
+    );
+}
+
+#[test]
+fn import_comparison_skips_merged_segments() {
+    let run = run_with_splits(&[("A", 10.0), ("C", 60.0)]);
+    let other = run_with_splits(&[("A", 8.0), ("B", 20.0), ("C", 50.0)]);
+
+    let mut editor = Editor::new(run).unwrap();
+    editor.import_comparison(&other, "Ghost").unwrap();
+    let run = editor.close();
+
+    assert_eq!(comparison_times(&run, "Ghost"), [Some(8.0), Some(50.0)]);
+}
+
+#[test]
+fn link_comparison() {
+    let path = temp_path("link-comparison");
+    let other = run_with_splits(&[("A", 8.0), ("B", 20.0), ("C", 50.0)]);
+    save(&other, File::create(&path).unwrap()).unwrap();
+
+    let run = run_with_splits(&[("A", 10.0), ("B", 30.0), ("C", 60.0)]);
+    let mut editor = Editor::new(run).unwrap();
+    editor.link_comparison("Ghost", path.clone()).unwrap();
+    assert!(editor.link_comparison("Ghost", path.clone()).is_err());
+    let mut run = editor.close();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(run.linked_comparisons().len(), 1);
+    assert_eq!(
+        comparison_times(&run, "Ghost"),
+        [Some(8.0), Some(20.0), Some(50.0)]
+    );
+    assert!(!run.update_linked_comparisons());
+
+    let mut editor = Editor::new(run).unwrap();
+    editor.remove_comparison("Ghost");
+    assert!(editor.close().linked_comparisons().is_empty());
+}
+
+#[test]
+fn linked_comparisons_are_saved() {
+    let path = temp_path("linked-comparisons-are-saved");
+    let other = run_with_splits(&[("A", 8.0), ("B", 20.0)]);
+    save(&other, File::create(&path).unwrap()).unwrap();
+
+    let run = run_with_splits(&[("A", 10.0), ("B", 30.0)]);
+    let mut editor = Editor::new(run).unwrap();
+    editor.link_comparison("Ghost", path.clone()).unwrap();
+    let run = editor.close();
+
+    let mut buf = Vec::new();
+    save(&run, &mut buf).unwrap();
+    let mut parsed = parse(&buf[..], None).unwrap();
+
+    assert_eq!(parsed.linked_comparisons().len(), 1);
+    assert_eq!(parsed.linked_comparisons()[0].name(), "Ghost");
+    assert_eq!(parsed.linked_comparisons()[0].path(), path.as_path());
+
+    // The times get imported again after the splits are reopened.
+    assert!(parsed.update_linked_comparisons());
+    fs::remove_file(&path).unwrap();
+    assert_eq!(comparison_times(&parsed, "Ghost"), [Some(8.0), Some(20.0)]);
+}
+
+#[test]
+fn updated_linked_comparisons_regenerate_the_comparisons() {
+    let path = temp_path("updated-linked-comparisons-regenerate");
+    let other = run_with_splits(&[("A", 8.0), ("B", 20.0)]);
+    save(&other, File::create(&path).unwrap()).unwrap();
+
+    let run = run_with_splits(&[("A", 10.0), ("B", 30.0)]);
+    let mut editor = Editor::new(run).unwrap();
+    editor.link_comparison("Ghost", path.clone()).unwrap();
+    let mut buf = Vec::new();
+    save(&editor.close(), &mut buf).unwrap();
+    let mut parsed = parse(&buf[..], None).unwrap();
+    assert_eq!(comparison_times(&parsed, "Best Segments"), [None, None]);
+
+    assert!(parsed.update_linked_comparisons());
+    fs::remove_file(&path).unwrap();
+    assert_eq!(
+        comparison_times(&parsed, "Best Segments"),
+        [Some(10.0), Some(30.0)]
+    );
+}
+
+/// A run with two segments, where the last attempt skipped the first split and
+/// has an implausibly fast time for both segments combined.
+fn run_with_suspicious_attempt(with_game_time: bool) -> Run {
+    let time = |seconds: Option<TimeSpan>| {
+        let time = Time::new().with_real_time(seconds);
+        if with_game_time {
+            time.with_game_time(seconds)
+        } else {
+            time
+        }
+    };
+
+    let mut run = Run::new();
+    let times: &[[Option<f64>; 2]] = &[
+        [Some(10.0), Some(10.0)],
+        [Some(11.0), Some(10.0)],
+        [Some(12.0), Some(12.0)],
+        [Some(10.0), Some(11.0)],
+        [Some(11.0), Some(10.0)],
+        [None, Some(5.0)],
+    ];
+    for segment_index in 0..2 {
+        let mut segment = Segment::new(segment_index.to_string());
+        segment.set_best_segment_time(time(seconds(10.0)));
+        for (index, attempt) in times.iter().enumerate() {
+            segment.segment_history_mut().insert(
+                index as i32 + 1,
+                time(attempt[segment_index].and_then(seconds)),
+            );
+        }
+        run.push_segment(segment);
+    }
+    for index in 1..times.len() as i32 + 1 {
+        run.add_attempt_with_index(Time::default(), index, None, None, None);
+    }
+    run
+}
+
+#[test]
+fn auto_clean_sum_of_best() {
+    let run = run_with_suspicious_attempt(false);
+    let mut editor = Editor::new(run).unwrap();
+    {
+        let mut cleaner = editor.clean_sum_of_best();
+        let ranked = cleaner.rank();
+        assert_eq!(ranked.len(), 1);
+        assert!(ranked[0].z_score().unwrap() > 10.0);
+
+        assert!(cleaner.dry_run(100.0).clean_ups.is_empty());
+        assert_eq!(cleaner.dry_run(2.0).clean_ups.len(), 1);
+        assert_eq!(cleaner.auto_clean(2.0).clean_ups.len(), 1);
+        assert!(cleaner.rank().is_empty());
+    }
+    let run = editor.close();
+    assert!(run.segment(1).segment_history().get(6).is_none());
+}
+
+#[test]
+fn ranking_sum_of_best_clean_ups() {
+    let run = run_with_suspicious_attempt(true);
+    let mut editor = Editor::new(run).unwrap();
+    let mut cleaner = editor.clean_sum_of_best();
+
+    // The same segment time is suspicious for both timing methods.
+    assert_eq!(cleaner.rank().len(), 1);
+
+    // Ranking doesn't interfere with the clean ups that are asked about.
+    assert!(cleaner.next_potential_clean_up().is_some());
+    assert_eq!(cleaner.rank().len(), 1);
+    assert!(cleaner.next_potential_clean_up().is_some());
+    assert!(cleaner.next_potential_clean_up().is_none());
+}
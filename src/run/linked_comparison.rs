@@ -0,0 +1,189 @@
+//! Linked Comparisons are custom comparisons whose split times are imported
+//! from another splits file, like the World Record's splits stored locally.
+//! Unlike a one time import, the link is kept around and the times are
+//! imported again whenever the linked file changes.
+
+use std::fs::{self, File};
+use std::io::{self, BufReader};
+use std::path::{Path, PathBuf};
+use std::result::Result as StdResult;
+use std::time::SystemTime;
+use {unicase, Run, Segment, TimeSpan, TimingMethod};
+use super::parser::composite;
+
+quick_error! {
+    #[derive(Debug)]
+    pub enum Error {
+        Io(err: io::Error) {
+            from()
+        }
+        Parse(err: composite::Error) {
+            from()
+        }
+    }
+}
+
+pub type Result<T> = StdResult<T, Error>;
+
+/// Describes a custom comparison that is linked to an external splits file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LinkedComparison {
+    name: String,
+    path: PathBuf,
+    modified: Option<SystemTime>,
+}
+
+impl LinkedComparison {
+    /// Creates a new link between the comparison provided and the file at the
+    /// path provided. The file isn't loaded until `load` is called.
+    pub fn new<S, P>(name: S, path: P) -> Self
+    where
+        S: Into<String>,
+        P: Into<PathBuf>,
+    {
+        Self {
+            name: name.into(),
+            path: path.into(),
+            modified: None,
+        }
+    }
+
+    /// The name of the comparison the times get imported into.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Changes the name of the comparison the times get imported into.
+    pub fn set_name<S: Into<String>>(&mut self, name: S) {
+        self.name = name.into();
+    }
+
+    /// The path of the linked splits file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Checks whether the linked file got modified since it was last loaded.
+    /// If the file can't be accessed, it is not considered modified, so that
+    /// the previously imported times are kept.
+    pub fn has_changed(&self) -> bool {
+        fs::metadata(&self.path)
+            .and_then(|m| m.modified())
+            .ok()
+            .map_or(false, |modified| Some(modified) != self.modified)
+    }
+
+    /// Loads the linked file and parses it as a Run.
+    pub fn load(&mut self) -> Result<Run> {
+        let modified = fs::metadata(&self.path)?.modified().ok();
+        let file = BufReader::new(File::open(&self.path)?);
+        let run = composite::parse(file, Some(self.path.clone()), false)?.run;
+        self.modified = modified;
+        Ok(run)
+    }
+}
+
+/// Imports the Personal Best split times of the source segments into the
+/// comparison provided of the target segments. The segments are mapped by
+/// their names, so the segment lists don't need to match. Target segments
+/// without a matching source segment, like when a source segment got split up
+/// into multiple segments, receive split times that are interpolated based on
+/// their Personal Best or Best Segment times.
+pub fn import_split_times(targets: &mut [Segment], sources: &[Segment], comparison: &str) {
+    let anchors = find_anchors(targets, sources);
+
+    for &method in &TimingMethod::all() {
+        let mut times = vec![None; targets.len()];
+        for &(target_index, source_index) in &anchors {
+            times[target_index] = sources[source_index].personal_best_split_time()[method];
+        }
+
+        let weights = reference_segment_times(targets, method);
+        interpolate(&mut times, &weights);
+
+        let mut previous_time = TimeSpan::zero();
+        for (segment, time) in targets.iter_mut().zip(times) {
+            // Segments that are mapped out of order could cause split times to
+            // decrease, which a comparison isn't allowed to do.
+            let time = time.map(|t| if t < previous_time { previous_time } else { t });
+            if let Some(time) = time {
+                previous_time = time;
+            }
+            segment.comparison_mut(comparison)[method] = time;
+        }
+    }
+}
+
+fn find_anchors(targets: &[Segment], sources: &[Segment]) -> Vec<(usize, usize)> {
+    let mut anchors = Vec::new();
+    let (target_len, source_len) = (targets.len(), sources.len());
+    if target_len == 0 || source_len == 0 {
+        return anchors;
+    }
+
+    let mut start = 0;
+    for (source_index, source) in sources[..source_len - 1].iter().enumerate() {
+        if let Some(offset) = targets[start..target_len - 1]
+            .iter()
+            .position(|s| unicase::eq(source.name(), s.name()))
+        {
+            anchors.push((start + offset, source_index));
+            start += offset + 1;
+        }
+    }
+
+    // The final split always marks the end of the run, no matter its name.
+    anchors.push((target_len - 1, source_len - 1));
+
+    anchors
+}
+
+fn reference_segment_times(targets: &[Segment], method: TimingMethod) -> Vec<Option<TimeSpan>> {
+    let mut previous_pb_time = Some(TimeSpan::zero());
+    targets
+        .iter()
+        .map(|segment| {
+            let pb_time = segment.personal_best_split_time()[method];
+            let pb_segment_time = catch! { pb_time? - previous_pb_time? };
+            previous_pb_time = pb_time;
+            pb_segment_time.or_else(|| segment.best_segment_time()[method])
+        })
+        .collect()
+}
+
+fn interpolate(times: &mut [Option<TimeSpan>], weights: &[Option<TimeSpan>]) {
+    let mut previous = (0, TimeSpan::zero());
+
+    for index in 0..times.len() {
+        let end_time = if let Some(time) = times[index] {
+            time
+        } else {
+            continue;
+        };
+        let (start_index, start_time) = previous;
+        previous = (index + 1, end_time);
+
+        if start_index == index {
+            continue;
+        }
+
+        let range = &weights[start_index..index + 1];
+        let is_usable = |w: &Option<TimeSpan>| w.map_or(false, |w| w > TimeSpan::zero());
+        let weights: Vec<f64> = if range.iter().all(is_usable) {
+            range.iter().map(|w| w.unwrap().total_seconds()).collect()
+        } else {
+            vec![1.0; range.len()]
+        };
+
+        let total_weight: f64 = weights.iter().sum();
+        let duration = (end_time - start_time).total_seconds();
+        let mut accumulated_weight = 0.0;
+
+        for (time, weight) in times[start_index..index].iter_mut().zip(weights) {
+            accumulated_weight += weight;
+            *time = Some(
+                start_time + TimeSpan::from_seconds(duration * accumulated_weight / total_weight),
+            );
+        }
+    }
+}
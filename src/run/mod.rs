@@ -4,6 +4,7 @@ mod run;
 mod segment_history;
 mod segment;
 pub mod editor;
+pub mod linked_comparison;
 pub mod parser;
 pub mod saver;
 
@@ -13,3 +14,4 @@ pub use self::run::{ComparisonsIter, Run};
 pub use self::segment_history::SegmentHistory;
 pub use self::segment::Segment;
 pub use self::editor::Editor;
+pub use self::linked_comparison::LinkedComparison;
@@ -1,14 +1,15 @@
 use std::io::BufRead;
 use std::path::PathBuf;
 use {AtomicDateTime, Run, RunMetadata, Segment, Time, TimeSpan, base64};
+use run::LinkedComparison;
 use quick_xml::reader::Reader;
 use chrono::{DateTime, TimeZone, Utc};
 use std::str;
-use super::xml_util::{attribute, attribute_err, end_tag, optional_attribute_err, parse_attributes,
-                      parse_base, parse_children, reencode_children, text, text_as_bytes_err,
-                      text_err, text_parsed};
+use xml_util::{attribute, attribute_err, end_tag, optional_attribute_err, parse_attributes,
+               parse_base, parse_children, reencode_children, text, text_as_bytes_err,
+               text_err, text_parsed};
 
-pub use super::xml_util::{Error, Result};
+pub use xml_util::{Error, Result};
 
 #[derive(Copy, Clone, PartialOrd, PartialEq, Ord, Eq)]
 struct Version(u32, u32, u32, u32);
@@ -411,6 +412,21 @@ pub fn parse<R: BufRead>(source: R, path: Option<PathBuf>) -> Result<Run> {
                         end_tag(reader, tag.into_buf())
                     }
                 })
+            } else if tag.name() == b"LinkedComparisons" {
+                parse_children(reader, tag.into_buf(), |reader, tag| {
+                    if tag.name() == b"LinkedComparison" {
+                        let mut name = String::new();
+                        attribute(&tag, b"name", |t| {
+                            name = t.into_owned();
+                        })?;
+                        let linked = run.linked_comparisons_mut();
+                        text(reader, tag.into_buf(), |t| {
+                            linked.push(LinkedComparison::new(name, t.into_owned()));
+                        })
+                    } else {
+                        end_tag(reader, tag.into_buf())
+                    }
+                })
             } else if tag.name() == b"AutoSplitterSettings" {
                 let settings = run.auto_splitter_settings_mut();
                 reencode_children(reader, tag.into_buf(), settings)
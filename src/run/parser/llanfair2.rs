@@ -4,10 +4,10 @@ use quick_xml::reader::Reader;
 use std::cmp::min;
 use byteorder::{ByteOrder, BE};
 use imagelib::{png, ColorType, ImageBuffer, Rgba};
-use super::xml_util::{end_tag, parse_base, parse_children, single_child, text, text_err,
-                      text_parsed};
+use xml_util::{end_tag, parse_base, parse_children, single_child, text, text_err,
+               text_parsed};
 
-pub use super::xml_util::{Error, Result};
+pub use xml_util::{Error, Result};
 
 fn time_span<R, F>(reader: &mut Reader<R>, buf: &mut Vec<u8>, mut f: F) -> Result<()>
 where
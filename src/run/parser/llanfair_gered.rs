@@ -4,10 +4,10 @@ use quick_xml::reader::Reader;
 use byteorder::{ReadBytesExt, BE};
 use imagelib::{png, ColorType, ImageBuffer, Rgba};
 use base64::{self, STANDARD};
-use super::xml_util::{end_tag, optional_attribute_err, parse_base, parse_children, single_child,
-                      text, text_as_bytes_err, text_err, text_parsed};
+use xml_util::{end_tag, optional_attribute_err, parse_base, parse_children, single_child,
+               text, text_as_bytes_err, text_err, text_parsed};
 
-pub use super::xml_util::{Error, Result};
+pub use xml_util::{Error, Result};
 
 fn time_span<R, F>(reader: &mut Reader<R>, buf: &mut Vec<u8>, f: F) -> Result<()>
 where
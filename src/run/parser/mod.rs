@@ -13,6 +13,5 @@ pub mod urn;
 pub mod wsplit;
 
 mod timer_kind;
-mod xml_util;
 
 pub use self::timer_kind::TimerKind;
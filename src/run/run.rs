@@ -3,6 +3,7 @@ use std::path::PathBuf;
 use std::cmp::max;
 use {AtomicDateTime, Attempt, Image, RunMetadata, Segment, Time, TimeSpan, TimingMethod};
 use comparison::{default_generators, personal_best, ComparisonGenerator};
+use super::linked_comparison::{import_split_times, LinkedComparison};
 use odds::vec::VecFindRemove;
 use unicase;
 
@@ -20,6 +21,7 @@ pub struct Run {
     path: Option<PathBuf>,
     segments: Vec<Segment>,
     custom_comparisons: Vec<String>,
+    linked_comparisons: Vec<LinkedComparison>,
     comparison_generators: ComparisonGenerators,
     auto_splitter_settings: Vec<u8>,
 }
@@ -52,6 +54,7 @@ impl Run {
             path: None,
             segments: Vec::new(),
             custom_comparisons: vec![personal_best::NAME.to_string()],
+            linked_comparisons: Vec::new(),
             comparison_generators: ComparisonGenerators(default_generators()),
             auto_splitter_settings: Vec::new(),
         }
@@ -185,6 +188,41 @@ impl Run {
         &mut self.custom_comparisons
     }
 
+    #[inline]
+    pub fn linked_comparisons(&self) -> &[LinkedComparison] {
+        &self.linked_comparisons
+    }
+
+    #[inline]
+    pub fn linked_comparisons_mut(&mut self) -> &mut Vec<LinkedComparison> {
+        &mut self.linked_comparisons
+    }
+
+    /// Imports the times of all the Linked Comparisons again whose files got
+    /// modified since they were last loaded. Files that fail to load keep
+    /// their previously imported times.
+    ///
+    /// Returns whether any of the comparisons got updated. The splits get
+    /// fixed and the generated comparisons get regenerated afterwards, just
+    /// like when a new run is set on the timer.
+    pub fn update_linked_comparisons(&mut self) -> bool {
+        let mut updated = false;
+        for linked in &mut self.linked_comparisons {
+            if linked.has_changed() {
+                if let Ok(run) = linked.load() {
+                    import_split_times(&mut self.segments, run.segments(), linked.name());
+                    updated = true;
+                }
+            }
+        }
+        if updated {
+            self.fix_splits();
+            self.regenerate_comparisons();
+            self.has_changed = true;
+        }
+        updated
+    }
+
     #[inline]
     pub fn comparisons(&self) -> ComparisonsIter {
         ComparisonsIter {
@@ -1,6 +1,4 @@
 use std::io::Write;
-use std::result::Result as StdResult;
-use std::fmt::Display;
 use std::borrow::Cow;
 use std::mem::replace;
 use {Image, Run, Time, TimeSpan, base64};
@@ -8,94 +6,13 @@ use time::formatter::{Complete, TimeFormatter};
 use chrono::{DateTime, Utc};
 use byteorder::{WriteBytesExt, LE};
 use quick_xml::Writer;
-use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
-use quick_xml::errors::Error as XmlError;
+use quick_xml::events::{BytesDecl, BytesStart, BytesText, Event};
+use xml_writer::{bool, fmt_buf, new_tag, scoped, scoped_iter, text, write_display, write_end,
+                 write_start};
 
-static LSS_IMAGE_HEADER: &[u8; 156] = include_bytes!("lss_image_header.bin");
-
-quick_error! {
-    #[derive(Debug)]
-    pub enum Error {
-        Xml(err: XmlError) {
-            from()
-        }
-    }
-}
-
-pub type Result<T> = StdResult<T, Error>;
+pub use xml_writer::{Error, Result};
 
-fn new_tag(name: &[u8]) -> BytesStart {
-    BytesStart::borrowed(name, name.len())
-}
-
-fn write_start<W: Write>(writer: &mut Writer<W>, tag: BytesStart) -> Result<()> {
-    writer.write_event(Event::Start(tag))?;
-    Ok(())
-}
-
-fn write_end<W: Write>(writer: &mut Writer<W>, tag: &[u8]) -> Result<()> {
-    writer.write_event(Event::End(BytesEnd::borrowed(tag)))?;
-    Ok(())
-}
-
-fn split_tag<'a>(tag: &'a BytesStart<'a>) -> (BytesStart<'a>, BytesEnd<'a>) {
-    (
-        BytesStart::borrowed(&tag, tag.name().len()),
-        BytesEnd::borrowed(tag.name()),
-    )
-}
-
-fn bool(value: bool) -> &'static [u8] {
-    if value {
-        b"True"
-    } else {
-        b"False"
-    }
-}
-
-fn scoped<W, F>(writer: &mut Writer<W>, tag: BytesStart, is_empty: bool, scope: F) -> Result<()>
-where
-    W: Write,
-    F: FnOnce(&mut Writer<W>) -> Result<()>,
-{
-    if is_empty {
-        writer.write_event(Event::Empty(tag))?;
-    } else {
-        let (start, end) = split_tag(&tag);
-        writer.write_event(Event::Start(start))?;
-        scope(writer)?;
-        writer.write_event(Event::End(end))?;
-    }
-    Ok(())
-}
-
-fn scoped_iter<W, F, I>(
-    writer: &mut Writer<W>,
-    tag: BytesStart,
-    iter: I,
-    mut scope: F,
-) -> Result<()>
-where
-    W: Write,
-    I: IntoIterator,
-    F: FnMut(&mut Writer<W>, <I as IntoIterator>::Item) -> Result<()>,
-{
-    let mut iter = iter.into_iter().peekable();
-    scoped(writer, tag, iter.peek().is_none(), |writer| {
-        for item in iter {
-            scope(writer, item)?;
-        }
-        Ok(())
-    })
-}
-
-fn text<W: Write, T: AsRef<[u8]>>(writer: &mut Writer<W>, tag: BytesStart, text: T) -> Result<()> {
-    let text = text.as_ref();
-    scoped(writer, tag, text.is_empty(), |writer| {
-        writer.write_event(Event::Text(BytesText::borrowed(text)))?;
-        Ok(())
-    })
-}
+static LSS_IMAGE_HEADER: &[u8; 156] = include_bytes!("lss_image_header.bin");
 
 fn vec_as_string<F, R>(vec: &mut Vec<u8>, f: F) -> R
 where
@@ -147,21 +64,6 @@ fn fmt_date(date: DateTime<Utc>, buf: &mut Vec<u8>) -> &[u8] {
     fmt_buf(date.format("%m/%d/%Y %T"), buf)
 }
 
-fn fmt_buf<D: Display>(value: D, buf: &mut Vec<u8>) -> &[u8] {
-    buf.clear();
-    write!(buf, "{}", value).unwrap();
-    buf
-}
-
-fn write_display<W: Write, D: Display>(
-    writer: &mut Writer<W>,
-    tag: BytesStart,
-    value: D,
-    buf: &mut Vec<u8>,
-) -> Result<()> {
-    text(writer, tag, fmt_buf(value, buf))
-}
-
 fn time_span<W: Write>(
     writer: &mut Writer<W>,
     tag: BytesStart,
@@ -329,6 +231,21 @@ pub fn save<W: Write>(run: &Run, writer: W) -> Result<()> {
         },
     )?;
 
+    // LiveSplit skips elements it doesn't know about, so the links to other
+    // splits files can be stored next to the segments.
+    if !run.linked_comparisons().is_empty() {
+        scoped_iter(
+            writer,
+            new_tag(b"LinkedComparisons"),
+            run.linked_comparisons(),
+            |writer, linked| {
+                let mut tag = new_tag(b"LinkedComparison");
+                tag.push_attribute((&b"name"[..], linked.name().as_bytes()));
+                text(writer, tag, linked.path().to_string_lossy().as_bytes())
+            },
+        )?;
+    }
+
     scoped(
         writer,
         new_tag(b"AutoSplitterSettings"),
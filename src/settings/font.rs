@@ -0,0 +1,59 @@
+/// Describes a font that text gets drawn with. Frontends that don't have the
+/// font family available fall back to their own font, but still apply the
+/// style, the weight and the scale.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Font {
+    pub family: String,
+    pub style: FontStyle,
+    pub weight: FontWeight,
+    /// The factor that the size of the text gets scaled by.
+    pub scale: f32,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FontStyle {
+    Normal,
+    Italic,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FontWeight {
+    Thin,
+    ExtraLight,
+    Light,
+    Normal,
+    Medium,
+    SemiBold,
+    Bold,
+    ExtraBold,
+    Black,
+}
+
+impl Font {
+    /// Creates a regular font of the family provided that isn't scaled.
+    pub fn new<S: Into<String>>(family: S) -> Self {
+        Self {
+            family: family.into(),
+            style: FontStyle::Normal,
+            weight: FontWeight::Normal,
+            scale: 1.0,
+        }
+    }
+}
+
+impl FontWeight {
+    /// The numeric value of the weight, as used by CSS and OpenType.
+    pub fn value(self) -> u16 {
+        match self {
+            FontWeight::Thin => 100,
+            FontWeight::ExtraLight => 200,
+            FontWeight::Light => 300,
+            FontWeight::Normal => 400,
+            FontWeight::Medium => 500,
+            FontWeight::SemiBold => 600,
+            FontWeight::Bold => 700,
+            FontWeight::ExtraBold => 800,
+            FontWeight::Black => 900,
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use super::Color;
 
-#[derive(Copy, Clone, Serialize, Deserialize)]
+#[derive(Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Gradient {
     Transparent,
     Plain(Color),
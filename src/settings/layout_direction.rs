@@ -0,0 +1,7 @@
+/// Describes whether the components of a layout are stacked on top of each
+/// other or placed next to each other.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LayoutDirection {
+    Vertical,
+    Horizontal,
+}
@@ -1,7 +1,9 @@
 mod alignment;
 mod color;
 mod field;
+mod font;
 mod gradient;
+mod layout_direction;
 mod semantic_color;
 mod settings_description;
 mod value;
@@ -9,7 +11,9 @@ mod value;
 pub use self::alignment::Alignment;
 pub use self::color::Color;
 pub use self::field::Field;
+pub use self::font::{Font, FontStyle, FontWeight};
 pub use self::gradient::Gradient;
+pub use self::layout_direction::LayoutDirection;
 pub use self::semantic_color::SemanticColor;
 pub use self::settings_description::SettingsDescription;
 pub use self::value::{Error as ValueError, Result as ValueResult, Value};
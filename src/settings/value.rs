@@ -1,6 +1,7 @@
 use TimingMethod;
-use super::{Alignment, Color, Gradient};
+use super::{Alignment, Color, Font, Gradient, LayoutDirection};
 use time::formatter::{Accuracy, DigitsFormat};
+use component::splits::ColumnType;
 use std::result::Result as StdResult;
 
 #[derive(From, Serialize, Deserialize)]
@@ -18,6 +19,9 @@ pub enum Value {
     OptionalColor(Option<Color>),
     Gradient(Gradient),
     Alignment(Alignment),
+    ColumnType(ColumnType),
+    LayoutDirection(LayoutDirection),
+    OptionalFont(Option<Font>),
 }
 
 quick_error! {
@@ -121,6 +125,27 @@ impl Value {
             _ => Err(Error::WrongType),
         }
     }
+
+    pub fn into_column_type(self) -> Result<ColumnType> {
+        match self {
+            Value::ColumnType(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    pub fn into_layout_direction(self) -> Result<LayoutDirection> {
+        match self {
+            Value::LayoutDirection(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    pub fn into_optional_font(self) -> Result<Option<Font>> {
+        match self {
+            Value::OptionalFont(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
 }
 
 impl Into<bool> for Value {
@@ -200,3 +225,21 @@ impl Into<Alignment> for Value {
         self.into_alignment().unwrap()
     }
 }
+
+impl Into<ColumnType> for Value {
+    fn into(self) -> ColumnType {
+        self.into_column_type().unwrap()
+    }
+}
+
+impl Into<LayoutDirection> for Value {
+    fn into(self) -> LayoutDirection {
+        self.into_layout_direction().unwrap()
+    }
+}
+
+impl Into<Option<Font>> for Value {
+    fn into(self) -> Option<Font> {
+        self.into_optional_font().unwrap()
+    }
+}
@@ -0,0 +1,42 @@
+//! Builds the runs and timers that the unit tests of the components share.
+
+use {Run, Segment, Time, TimeSpan, Timer, TimingMethod};
+
+/// Creates a time where both the Real Time and the Game Time are set.
+pub fn time(seconds: f64) -> Time {
+    let time = Some(TimeSpan::from_seconds(seconds));
+    Time::new().with_real_time(time).with_game_time(time)
+}
+
+/// Creates a run out of the name, the Personal Best split time and the Best
+/// Segment time of each of its segments.
+pub fn run_with_splits(splits: &[(&str, f64, f64)]) -> Run {
+    let mut run = Run::new();
+    for &(name, personal_best, best_segment) in splits {
+        let mut segment = Segment::new(name);
+        segment.set_personal_best_split_time(time(personal_best));
+        segment.set_best_segment_time(time(best_segment));
+        run.push_segment(segment);
+    }
+    run
+}
+
+/// Creates a timer for the run that `run_with_splits` creates.
+pub fn timer_with_splits(splits: &[(&str, f64, f64)]) -> Timer {
+    Timer::new(run_with_splits(splits)).unwrap()
+}
+
+/// Starts an attempt that is timed by the Game Time. The Game Time stays
+/// paused, so the tests set the times the splits happen at themselves.
+pub fn start_run(timer: &mut Timer) {
+    timer.set_current_timing_method(TimingMethod::GameTime);
+    timer.start();
+    timer.initialize_game_time();
+    timer.pause_game_time();
+}
+
+/// Splits at the given Game Time.
+pub fn split_at(timer: &mut Timer, seconds: f64) {
+    timer.set_game_time(TimeSpan::from_seconds(seconds));
+    timer.split();
+}
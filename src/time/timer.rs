@@ -22,6 +22,7 @@ pub struct Timer {
     is_game_time_paused: bool,
     game_time_pause_time: Option<TimeSpan>,
     loading_times: Option<TimeSpan>,
+    run_version: u64,
 }
 
 pub type SharedTimer = Arc<RwLock<Timer>>;
@@ -58,6 +59,7 @@ impl Timer {
             is_game_time_paused: false,
             game_time_pause_time: None,
             loading_times: None,
+            run_version: 0,
         })
     }
 
@@ -75,6 +77,7 @@ impl Timer {
             self.current_comparison = personal_best::NAME.to_string();
         }
 
+        self.run_version = self.run_version.wrapping_add(1);
         Ok(mem::replace(&mut self.run, run))
     }
 
@@ -87,6 +90,16 @@ impl Timer {
         &self.run
     }
 
+    /// Returns a number that changes whenever the times of the run change
+    /// outside of the current attempt, which is when the run gets replaced,
+    /// when an attempt gets reset and when the Linked Comparisons get
+    /// updated. Anything that is derived from the times of the run only needs
+    /// to be calculated again when this changes.
+    #[inline]
+    pub fn run_version(&self) -> u64 {
+        self.run_version
+    }
+
     #[inline]
     pub fn current_phase(&self) -> TimerPhase {
         self.phase
@@ -235,6 +248,7 @@ impl Timer {
             self.reset_splits();
             self.run.fix_splits();
             self.run.regenerate_comparisons();
+            self.run_version = self.run_version.wrapping_add(1);
         }
     }
 
@@ -334,6 +348,26 @@ impl Timer {
         // TODO OnPreviousComparison
     }
 
+    /// Imports the times of the run's Linked Comparisons again if their files
+    /// got modified. Returns whether any of the comparisons got updated.
+    pub fn update_linked_comparisons(&mut self) -> bool {
+        let updated = self.run.update_linked_comparisons();
+        if updated {
+            self.run_version = self.run_version.wrapping_add(1);
+        }
+        updated
+    }
+
+    /// Returns the date the current attempt started at, or None if there's
+    /// no attempt in progress.
+    pub fn attempt_started(&self) -> Option<AtomicDateTime> {
+        if self.phase == NotRunning {
+            None
+        } else {
+            self.attempt_started
+        }
+    }
+
     pub fn current_attempt_duration(&self) -> TimeSpan {
         match self.current_phase() {
             NotRunning => TimeSpan::zero(),
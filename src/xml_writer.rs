@@ -0,0 +1,114 @@
+use std::io::Write;
+use std::result::Result as StdResult;
+use std::fmt::Display;
+use quick_xml::Writer;
+use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
+use quick_xml::errors::Error as XmlError;
+
+quick_error! {
+    #[derive(Debug)]
+    pub enum Error {
+        Xml(err: XmlError) {
+            from()
+        }
+    }
+}
+
+pub type Result<T> = StdResult<T, Error>;
+
+pub fn new_tag(name: &[u8]) -> BytesStart {
+    BytesStart::borrowed(name, name.len())
+}
+
+pub fn write_start<W: Write>(writer: &mut Writer<W>, tag: BytesStart) -> Result<()> {
+    writer.write_event(Event::Start(tag))?;
+    Ok(())
+}
+
+pub fn write_end<W: Write>(writer: &mut Writer<W>, tag: &[u8]) -> Result<()> {
+    writer.write_event(Event::End(BytesEnd::borrowed(tag)))?;
+    Ok(())
+}
+
+fn split_tag<'a>(tag: &'a BytesStart<'a>) -> (BytesStart<'a>, BytesEnd<'a>) {
+    (
+        BytesStart::borrowed(&tag, tag.name().len()),
+        BytesEnd::borrowed(tag.name()),
+    )
+}
+
+pub fn bool(value: bool) -> &'static [u8] {
+    if value {
+        b"True"
+    } else {
+        b"False"
+    }
+}
+
+pub fn scoped<W, F>(
+    writer: &mut Writer<W>,
+    tag: BytesStart,
+    is_empty: bool,
+    scope: F,
+) -> Result<()>
+where
+    W: Write,
+    F: FnOnce(&mut Writer<W>) -> Result<()>,
+{
+    if is_empty {
+        writer.write_event(Event::Empty(tag))?;
+    } else {
+        let (start, end) = split_tag(&tag);
+        writer.write_event(Event::Start(start))?;
+        scope(writer)?;
+        writer.write_event(Event::End(end))?;
+    }
+    Ok(())
+}
+
+pub fn scoped_iter<W, F, I>(
+    writer: &mut Writer<W>,
+    tag: BytesStart,
+    iter: I,
+    mut scope: F,
+) -> Result<()>
+where
+    W: Write,
+    I: IntoIterator,
+    F: FnMut(&mut Writer<W>, <I as IntoIterator>::Item) -> Result<()>,
+{
+    let mut iter = iter.into_iter().peekable();
+    scoped(writer, tag, iter.peek().is_none(), |writer| {
+        for item in iter {
+            scope(writer, item)?;
+        }
+        Ok(())
+    })
+}
+
+pub fn text<W, T>(writer: &mut Writer<W>, tag: BytesStart, text: T) -> Result<()>
+where
+    W: Write,
+    T: AsRef<[u8]>,
+{
+    let text = text.as_ref();
+    scoped(writer, tag, text.is_empty(), |writer| {
+        writer.write_event(Event::Text(BytesText::borrowed(text)))?;
+        Ok(())
+    })
+}
+
+pub fn fmt_buf<D: Display>(value: D, buf: &mut Vec<u8>) -> &[u8] {
+    buf.clear();
+    write!(buf, "{}", value).unwrap();
+    buf
+}
+
+pub fn write_display<W: Write, D: Display>(
+    writer: &mut Writer<W>,
+    tag: BytesStart,
+    value: D,
+    buf: &mut Vec<u8>,
+) -> Result<()> {
+    text(writer, tag, fmt_buf(value, buf))
+}
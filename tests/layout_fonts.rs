@@ -0,0 +1,97 @@
+extern crate livesplit_core;
+
+mod fonts {
+    use livesplit_core::{Layout, Run, Segment, Timer};
+    use livesplit_core::component::{splits, timer, title};
+    use livesplit_core::layout::{ComponentState, LayoutSettings};
+    use livesplit_core::settings::{Font, FontStyle, FontWeight, Value};
+    use std::io::Cursor;
+
+    fn timer() -> Timer {
+        let mut run = Run::new();
+        run.push_segment(Segment::new("A"));
+        Timer::new(run).unwrap()
+    }
+
+    fn font() -> Font {
+        Font {
+            family: String::from("Fira Sans"),
+            style: FontStyle::Italic,
+            weight: FontWeight::Bold,
+            scale: 1.5,
+        }
+    }
+
+    #[test]
+    fn general_fonts_are_part_of_the_state() {
+        let mut layout = Layout::default_layout();
+        let state = layout.state(&timer());
+        assert_eq!(state.timer_font, None);
+
+        let fields = layout.general_settings().settings_description().fields;
+        assert_eq!(fields[13].text, "Timer Font");
+        layout
+            .general_settings_mut()
+            .set_value(13, Value::OptionalFont(Some(font())));
+        layout
+            .general_settings_mut()
+            .set_value(15, Value::OptionalFont(Some(Font::new("Arial"))));
+
+        let state = layout.state(&timer());
+        assert_eq!(state.timer_font, Some(font()));
+        assert_eq!(state.times_font, None);
+        assert_eq!(state.text_font.unwrap().weight.value(), 400);
+    }
+
+    #[test]
+    fn components_override_the_fonts() {
+        let mut layout = Layout::new();
+        let mut timer_component = timer::Component::new();
+        timer_component.set_value(7, Value::OptionalFont(Some(font())));
+        let mut title_component = title::Component::new();
+        title_component.set_value(12, Value::OptionalFont(Some(font())));
+        layout.push(timer_component);
+        layout.push(title_component);
+
+        let state = layout.state(&timer());
+        match state.components[0] {
+            ComponentState::Timer(ref state) => assert_eq!(state.timer_font, Some(font())),
+            _ => panic!("Expected the timer component"),
+        }
+        match state.components[1] {
+            ComponentState::Title(ref state) => assert_eq!(state.text_font, Some(font())),
+            _ => panic!("Expected the title component"),
+        }
+    }
+
+    #[test]
+    fn splits_fonts_follow_the_columns() {
+        let mut component = splits::Component::new();
+        let fields = component.settings_description().fields;
+        let index = fields.len() - 1;
+        assert_eq!(fields[index].text, "Times Font");
+
+        component.set_value(index, Value::OptionalFont(Some(font())));
+        component.set_value(6, Value::UInt(1));
+        let fields = component.settings_description().fields;
+        assert_eq!(fields.len(), 13);
+        assert_eq!(fields[11].text, "Text Font");
+        component.set_value(11, Value::OptionalFont(Some(Font::new("Arial"))));
+
+        let settings = component.settings();
+        assert_eq!(settings.times_font, Some(font()));
+        assert_eq!(settings.text_font, Some(Font::new("Arial")));
+    }
+
+    #[test]
+    fn fonts_are_stored_in_the_settings() {
+        let mut layout = Layout::default_layout();
+        layout.general_settings_mut().times_font = Some(font());
+
+        let mut buf = Vec::new();
+        layout.settings().write_json(&mut buf).unwrap();
+        let settings = LayoutSettings::from_json(Cursor::new(buf)).unwrap();
+        assert_eq!(settings.general.times_font, Some(font()));
+        assert_eq!(settings.general.timer_font, None);
+    }
+}
@@ -0,0 +1,181 @@
+extern crate livesplit_core;
+
+mod parse {
+    use std::fs::File;
+    use std::io::BufReader;
+    use livesplit_core::{Component, TimingMethod};
+    use livesplit_core::component::splits::ColumnType;
+    use livesplit_core::component::text::Text;
+    use livesplit_core::layout::parser::{self, ParsedLayout};
+    use livesplit_core::settings::{Alignment, Color, Gradient};
+    use livesplit_core::time::formatter::{Accuracy, DigitsFormat};
+
+    fn file(path: &str) -> BufReader<File> {
+        BufReader::new(File::open(path).unwrap())
+    }
+
+    fn livesplit() -> ParsedLayout {
+        parser::parse(file("tests/layout_files/livesplit.lsl")).unwrap()
+    }
+
+    fn color(r: u8, g: u8, b: u8, a: u8) -> Color {
+        (
+            r as f32 / 255.0,
+            g as f32 / 255.0,
+            b as f32 / 255.0,
+            a as f32 / 255.0,
+        ).into()
+    }
+
+    #[test]
+    fn general_settings() {
+        let layout = livesplit().layout;
+        let settings = layout.general_settings();
+        match settings.background {
+            Gradient::Vertical(top, bottom) => {
+                assert!(top == color(0x0F, 0x0F, 0x0F, 0xFF));
+                assert!(bottom == color(0x2A, 0x2A, 0x2A, 0xFF));
+            }
+            _ => panic!("Expected a vertical gradient"),
+        }
+        assert!(settings.best_segment_color == color(0xD8, 0xAF, 0x1F, 0xFF));
+        assert!(settings.thin_separators_color == color(0xFF, 0xFF, 0xFF, 0x03));
+        assert!(settings.paused_color == color(0x7A, 0x7A, 0x7A, 0xFF));
+    }
+
+    #[test]
+    fn components() {
+        let components = livesplit().layout.components;
+        assert_eq!(components.len(), 7);
+
+        match components[0].component {
+            Component::Title(ref title) => {
+                let settings = title.settings();
+                assert!(!settings.show_attempt_count);
+                assert!(settings.display_as_single_line);
+                assert!(settings.text_color == Some(color(0xFF, 0, 0, 0xFF)));
+                match settings.text_alignment {
+                    Alignment::Center => {}
+                    _ => panic!("Expected the title to be centered"),
+                }
+            }
+            _ => panic!("Expected the title component"),
+        }
+
+        match components[1].component {
+            Component::Splits(ref splits) => {
+                let settings = splits.settings();
+                assert_eq!(settings.visual_split_count, 12);
+                assert_eq!(settings.split_preview_count, 2);
+                assert!(!settings.separator_last_split);
+                assert!(settings.show_column_labels);
+                assert_eq!(settings.columns.len(), 3);
+                assert_eq!(settings.columns[0].name, "+/-");
+                assert_eq!(settings.columns[0].comparison_override, None);
+                assert_eq!(settings.columns[1].column_type, ColumnType::SplitTime);
+                assert_eq!(
+                    settings.columns[1].comparison_override.as_ref().map(String::as_str),
+                    Some("Best Segments")
+                );
+                assert_eq!(settings.columns[1].timing_method, Some(TimingMethod::GameTime));
+                assert_eq!(settings.columns[2].column_type, ColumnType::SegmentDelta);
+            }
+            _ => panic!("Expected the splits component"),
+        }
+
+        match components[2].component {
+            Component::Separator(_) => {}
+            _ => panic!("Expected a separator"),
+        }
+
+        match components[3].component {
+            Component::Timer(ref timer) => {
+                let settings = timer.settings();
+                assert_eq!(settings.height, 69);
+                assert_eq!(settings.digits_format, DigitsFormat::DoubleDigitMinutes);
+                assert_eq!(settings.accuracy, Accuracy::Tenths);
+                assert_eq!(settings.timing_method, Some(TimingMethod::RealTime));
+                assert!(settings.color_override.is_none());
+            }
+            _ => panic!("Expected the timer component"),
+        }
+
+        match components[4].component {
+            Component::PreviousSegment(ref previous_segment) => {
+                let settings = previous_segment.settings();
+                assert_eq!(settings.accuracy, Accuracy::Hundredths);
+                assert!(settings.show_possible_time_save);
+                assert!(settings.label_color == Some(color(0, 0xFF, 0, 0xFF)));
+                assert_eq!(
+                    settings.comparison_override.as_ref().map(String::as_str),
+                    Some("Personal Best")
+                );
+            }
+            _ => panic!("Expected the previous segment component"),
+        }
+
+        match components[5].component {
+            Component::SumOfBest(ref sum_of_best) => {
+                let settings = sum_of_best.settings();
+                assert_eq!(settings.accuracy, Accuracy::Tenths);
+                assert!(settings.label_color.is_none());
+                assert!(settings.value_color == Some(color(0, 0, 0xFF, 0xFF)));
+            }
+            _ => panic!("Expected the sum of best component"),
+        }
+
+        match components[6].component {
+            Component::Text(ref text) => match text.settings().text {
+                Text::Split(ref left, ref right) => {
+                    assert_eq!(left, "Left");
+                    assert_eq!(right, "Right");
+                }
+                _ => panic!("Expected split text"),
+            },
+            _ => panic!("Expected the text component"),
+        }
+    }
+
+    #[test]
+    fn warnings() {
+        let warnings = livesplit().warnings;
+        assert!(warnings.contains(&String::from(
+            "Unsupported component \"LiveSplit.ScriptableAutoSplit.dll\""
+        )));
+        assert!(warnings.contains(&String::from(
+            "LiveSplit.Splits.dll: Unsupported setting SplitWidth"
+        )));
+        assert!(warnings.contains(&String::from("Unsupported setting AlwaysOnTop")));
+        assert!(!warnings.iter().any(|w| w.contains("Version")));
+    }
+
+    #[test]
+    fn invalid_values_are_warnings() {
+        let layout = r#"
+            <Layout version="1.6.1">
+                <Components>
+                    <Component>
+                        <Path>LiveSplit.BlankSpace.dll</Path>
+                        <Settings>
+                            <SpaceHeight>tall</SpaceHeight>
+                            <BackgroundColor>red</BackgroundColor>
+                        </Settings>
+                    </Component>
+                </Components>
+            </Layout>"#;
+        let parsed = parser::parse(layout.as_bytes()).unwrap();
+        assert_eq!(parsed.layout.components.len(), 1);
+        assert_eq!(
+            parsed.warnings,
+            [
+                "LiveSplit.BlankSpace.dll: Invalid value \"tall\" for SpaceHeight",
+                "LiveSplit.BlankSpace.dll: Invalid value \"red\" for BackgroundColor",
+            ]
+        );
+    }
+
+    #[test]
+    fn not_a_layout() {
+        assert!(parser::parse(file("tests/run_files/livesplit1.6.lss")).is_err());
+    }
+}
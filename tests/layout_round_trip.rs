@@ -0,0 +1,228 @@
+extern crate livesplit_core;
+
+mod round_trip {
+    use std::fs::File;
+    use std::io::BufReader;
+    use livesplit_core::Layout;
+    use livesplit_core::component::splits::ColumnType;
+    use livesplit_core::component::{blank_space, comparison_table, container,
+                                    current_comparison, current_pace, delta, detailed_timer, graph,
+                                    possible_time_save, previous_segment, separator, splits,
+                                    sum_of_best, text, timer, title, total_playtime};
+    use livesplit_core::layout::Component;
+    use livesplit_core::layout::parser::{self, ParsedLayout};
+    use livesplit_core::layout::saver;
+    use livesplit_core::settings::{Font, FontStyle, FontWeight, LayoutDirection};
+
+    fn file(path: &str) -> BufReader<File> {
+        BufReader::new(File::open(path).unwrap())
+    }
+
+    fn save(layout: &Layout) -> Vec<u8> {
+        let mut buf = Vec::new();
+        let warnings = saver::save(layout, &mut buf).unwrap();
+        assert!(warnings.is_empty(), "{:?}", warnings);
+        buf
+    }
+
+    /// The general settings that only LiveSplit has. The saver stores them
+    /// with LiveSplit's defaults and the parser reports them as unsupported.
+    const LIVESPLIT_ONLY_SETTINGS: &[&str] = &[
+        "UseRainbowColor",
+        "TextOutlineColor",
+        "ShadowsColor",
+        "AlwaysOnTop",
+        "ShowBestSegments",
+        "AntiAliasing",
+        "DropShadows",
+        "BackgroundImage",
+        "ImageOpacity",
+        "ImageBlur",
+        "Opacity",
+    ];
+
+    fn parse(buf: &[u8]) -> ParsedLayout {
+        let mut parsed = parser::parse(buf).unwrap();
+        parsed.warnings.retain(|warning| {
+            !LIVESPLIT_ONLY_SETTINGS
+                .iter()
+                .any(|s| *warning == format!("Unsupported setting {}", s))
+        });
+        parsed
+    }
+
+    fn reparse(layout: &Layout) -> ParsedLayout {
+        parse(&save(layout))
+    }
+
+    fn json(layout: &Layout) -> String {
+        let mut buf = Vec::new();
+        layout.settings().write_json(&mut buf).unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn livesplit_file() {
+        let layout = parser::parse(file("tests/layout_files/livesplit.lsl"))
+            .unwrap()
+            .layout;
+        let parsed = reparse(&layout);
+        assert!(parsed.warnings.is_empty(), "{:?}", parsed.warnings);
+        assert_eq!(json(&parsed.layout), json(&layout));
+    }
+
+    #[test]
+    fn default_layout() {
+        let layout = Layout::default_layout();
+        let saved = save(&layout);
+        let parsed = reparse(&layout);
+        assert!(parsed.warnings.is_empty(), "{:?}", parsed.warnings);
+        assert_eq!(parsed.layout.components.len(), layout.components.len());
+        assert_eq!(save(&parsed.layout), saved);
+    }
+
+    #[test]
+    fn all_components() {
+        let mut layout = Layout::new();
+        layout.push(blank_space::Component::new());
+        layout.push(current_comparison::Component::new());
+        layout.push(current_pace::Component::new());
+        layout.push(delta::Component::new());
+        layout.push(detailed_timer::Component::new());
+        layout.push(graph::Component::new());
+        layout.push(possible_time_save::Component::new());
+        layout.push(previous_segment::Component::new());
+        layout.push(separator::Component::new());
+        layout.push(splits::Component::new());
+        layout.push(sum_of_best::Component::new());
+        layout.push(text::Component::new());
+        layout.push(timer::Component::new());
+        layout.push(title::Component::new());
+        layout.push(total_playtime::Component::new());
+
+        let saved = save(&layout);
+        let parsed = reparse(&layout);
+        assert!(parsed.warnings.is_empty(), "{:?}", parsed.warnings);
+        assert_eq!(parsed.layout.components.len(), layout.components.len());
+        assert_eq!(save(&parsed.layout), saved);
+    }
+
+    #[test]
+    fn unsupported_components_are_left_out() {
+        let mut layout = Layout::new();
+        layout.push(title::Component::new());
+        layout.push(comparison_table::Component::new());
+        layout.push(timer::Component::new());
+
+        let mut buf = Vec::new();
+        let warnings = saver::save(&layout, &mut buf).unwrap();
+        assert_eq!(warnings, ["Unsupported component \"Comparison Table\""]);
+
+        let parsed = parse(&buf);
+        assert!(parsed.warnings.is_empty(), "{:?}", parsed.warnings);
+        assert_eq!(parsed.layout.components.len(), 2);
+    }
+
+    #[test]
+    fn unsupported_columns_are_left_out() {
+        let mut component = splits::Component::new();
+        {
+            let columns = &mut component.settings_mut().columns;
+            columns[0].column_type = ColumnType::BestSegment;
+            columns[0].name = String::from("Best");
+            columns.push(splits::ColumnSettings {
+                name: String::from("Save"),
+                column_type: ColumnType::PossibleTimeSave,
+                ..Default::default()
+            });
+        }
+        let mut layout = Layout::new();
+        layout.push(component);
+
+        let mut buf = Vec::new();
+        let warnings = saver::save(&layout, &mut buf).unwrap();
+        assert_eq!(
+            warnings,
+            ["Unsupported column \"Best\"", "Unsupported column \"Save\""]
+        );
+
+        let parsed = parse(&buf);
+        assert!(parsed.warnings.is_empty(), "{:?}", parsed.warnings);
+        match parsed.layout.components[0].component {
+            Component::Splits(ref component) => {
+                let columns = &component.settings().columns;
+                assert_eq!(columns.len(), 1);
+                assert_eq!(columns[0].column_type, ColumnType::SplitTime);
+            }
+            _ => panic!("Expected the splits component"),
+        }
+    }
+
+    #[test]
+    fn containers_are_flattened() {
+        let mut column = container::Component::with_direction(LayoutDirection::Vertical);
+        column.push(title::Component::new());
+        column.push(splits::Component::new());
+        let mut row = container::Component::new();
+        row.push(delta::Component::new());
+        row.push(sum_of_best::Component::new());
+        column.push(row);
+
+        let mut layout = Layout::new();
+        layout.push(column);
+        layout.push(timer::Component::new());
+
+        let mut buf = Vec::new();
+        let warnings = saver::save(&layout, &mut buf).unwrap();
+        assert_eq!(warnings, ["Flattened component \"Row\""]);
+
+        let parsed = parse(&buf);
+        assert!(parsed.warnings.is_empty(), "{:?}", parsed.warnings);
+        let names = parsed
+            .layout
+            .components
+            .iter()
+            .map(|entry| entry.component.name().into_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            ["Title", "Splits", "Delta", "Sum of Best Segments", "Timer"]
+        );
+    }
+
+    #[test]
+    fn fonts() {
+        let font = Font {
+            family: String::from("Fira Mono"),
+            style: FontStyle::Italic,
+            weight: FontWeight::Bold,
+            scale: 1.5,
+        };
+        let mut layout = Layout::default_layout();
+        layout.general_settings_mut().timer_font = Some(font.clone());
+        layout.general_settings_mut().text_font = Some(Font::new("Arial"));
+
+        let saved = String::from_utf8(save(&layout)).unwrap();
+        assert!(saved.contains("<TimesFont>AAEAAAD/////"));
+
+        let parsed = reparse(&layout);
+        assert!(parsed.warnings.is_empty(), "{:?}", parsed.warnings);
+        let settings = parsed.layout.general_settings();
+        assert_eq!(settings.timer_font, Some(font));
+        assert_eq!(settings.times_font, None);
+        assert_eq!(settings.text_font, Some(Font::new("Arial")));
+    }
+
+    #[test]
+    fn horizontal_layouts() {
+        let mut layout = Layout::default_layout();
+        layout.general_settings_mut().direction = LayoutDirection::Horizontal;
+
+        let parsed = reparse(&layout);
+        assert!(parsed.warnings.is_empty(), "{:?}", parsed.warnings);
+        assert_eq!(
+            parsed.layout.general_settings().direction,
+            LayoutDirection::Horizontal
+        );
+    }
+}
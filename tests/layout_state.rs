@@ -0,0 +1,75 @@
+extern crate livesplit_core;
+extern crate serde_json;
+
+mod state {
+    use livesplit_core::{Layout, Run, Segment, Timer};
+    use livesplit_core::component::{container, delta, splits};
+    use livesplit_core::layout::{ComponentState, HorizontalArrangement, LayoutState};
+    use serde_json::{self, Value};
+
+    fn timer() -> Timer {
+        let mut run = Run::new();
+        run.push_segment(Segment::new("A"));
+        Timer::new(run).unwrap()
+    }
+
+    fn arrangement(state: &Value) -> &str {
+        state["horizontal_arrangement"].as_str().unwrap()
+    }
+
+    #[test]
+    fn components_are_serialized_with_their_horizontal_arrangement() {
+        let state = Layout::default_layout().state(&timer());
+        let json = serde_json::to_value(&state).unwrap();
+        let components = json["components"].as_array().unwrap();
+
+        let arrangements: Vec<_> = components.iter().map(arrangement).collect();
+        assert_eq!(arrangements, ["Block", "Columns", "Block", "Stacked"]);
+        assert!(components[1]["Splits"].is_object());
+        assert_eq!(components[1].as_object().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn children_of_containers_are_serialized_with_their_horizontal_arrangement() {
+        let mut container = container::Component::new();
+        container.push(splits::Component::new());
+        container.push(delta::Component::new());
+        let mut layout = Layout::new();
+        layout.push(container);
+
+        let json = serde_json::to_value(layout.state(&timer())).unwrap();
+        let container = &json["components"][0];
+        assert_eq!(arrangement(container), "Block");
+        let children = container["Container"]["children"].as_array().unwrap();
+        assert_eq!(arrangement(&children[0]["state"]), "Columns");
+        assert_eq!(arrangement(&children[1]["state"]), "Stacked");
+    }
+
+    #[test]
+    fn states_can_be_deserialized_again() {
+        let state = Layout::default_layout().state(&timer());
+        let json = serde_json::to_string(&state).unwrap();
+        let state: LayoutState = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(state.components.len(), 4);
+        match state.components[1] {
+            ComponentState::Splits(_) => {}
+            _ => panic!("The second component should be the splits"),
+        }
+        let arrangements: Vec<_> = state
+            .components
+            .iter()
+            .map(|c| c.horizontal_arrangement())
+            .collect();
+        assert_eq!(
+            arrangements,
+            [
+                HorizontalArrangement::Block,
+                HorizontalArrangement::Columns,
+                HorizontalArrangement::Block,
+                HorizontalArrangement::Stacked,
+            ]
+        );
+        assert_eq!(serde_json::to_string(&state).unwrap(), json);
+    }
+}
@@ -0,0 +1,144 @@
+extern crate livesplit_core;
+extern crate serde_json;
+
+mod state_diff {
+    use livesplit_core::{Layout, Run, Segment, Timer};
+    use livesplit_core::layout::{LayoutStateDiff, PatchOperation};
+    use serde_json::{self, Value};
+
+    fn timer() -> Timer {
+        let mut run = Run::new();
+        run.set_game_name("Game");
+        run.set_category_name("Category");
+        run.push_segment(Segment::new("A"));
+        run.push_segment(Segment::new("B"));
+        Timer::new(run).unwrap()
+    }
+
+    fn parent<'a>(document: &'a mut Value, path: &str) -> (&'a mut Value, String) {
+        let index = path.rfind('/').unwrap();
+        let token = path[index + 1..].replace("~1", "/").replace("~0", "~");
+        (document.pointer_mut(&path[..index]).unwrap(), token)
+    }
+
+    fn apply(document: &mut Value, diff: &LayoutStateDiff) {
+        for operation in &diff.operations {
+            match *operation {
+                PatchOperation::Replace { ref path, ref value } => {
+                    *document.pointer_mut(path).unwrap() = value.clone();
+                }
+                PatchOperation::Add { ref path, ref value } => {
+                    match parent(document, path) {
+                        (&mut Value::Array(ref mut array), token) => {
+                            array.insert(token.parse().unwrap(), value.clone())
+                        }
+                        (&mut Value::Object(ref mut object), token) => {
+                            object.insert(token, value.clone());
+                        }
+                        _ => panic!("Can't add to {}", path),
+                    }
+                }
+                PatchOperation::Remove { ref path } => match parent(document, path) {
+                    (&mut Value::Array(ref mut array), token) => {
+                        array.remove(token.parse().unwrap());
+                    }
+                    (&mut Value::Object(ref mut object), token) => {
+                        object.remove(&token);
+                    }
+                    _ => panic!("Can't remove {}", path),
+                },
+            }
+        }
+    }
+
+    fn state(layout: &mut Layout, timer: &Timer) -> Value {
+        serde_json::to_value(layout.state(timer)).unwrap()
+    }
+
+    #[test]
+    fn first_diff_replaces_everything() {
+        let (mut layout, timer) = (Layout::default_layout(), timer());
+        let expected = state(&mut layout.clone(), &timer);
+        let diff = layout.state_diff(&timer);
+        assert_eq!(
+            diff.operations,
+            [
+                PatchOperation::Replace {
+                    path: String::new(),
+                    value: expected,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn unchanged_states_result_in_empty_diffs() {
+        let (mut layout, timer) = (Layout::default_layout(), timer());
+        layout.state_diff(&timer);
+        // The icon changes of the first state get cleared by the second one.
+        assert!(!layout.state_diff(&timer).is_empty());
+        assert!(layout.state_diff(&timer).is_empty());
+
+        let mut buf = Vec::new();
+        layout.state_diff(&timer).write_json(&mut buf).unwrap();
+        assert_eq!(buf, b"[]");
+    }
+
+    #[test]
+    fn applied_diffs_match_the_state() {
+        let (mut layout, mut timer) = (Layout::default_layout(), timer());
+        let mut expected_layout = layout.clone();
+        let mut document = Value::Null;
+
+        let mut check = |layout: &mut Layout, timer: &Timer| {
+            let diff = layout.state_diff(timer);
+            apply(&mut document, &diff);
+            assert_eq!(document, state(&mut expected_layout, timer));
+        };
+
+        check(&mut layout, &timer);
+        timer.start();
+        timer.split();
+        timer.pause();
+        check(&mut layout, &timer);
+        timer.undo_split();
+        check(&mut layout, &timer);
+        timer.reset(true);
+        check(&mut layout, &timer);
+    }
+
+    #[test]
+    fn remounting_replaces_everything() {
+        let (mut layout, timer) = (Layout::default_layout(), timer());
+        layout.state_diff(&timer);
+        layout.remount();
+        let diff = layout.state_diff(&timer);
+        assert_eq!(diff.operations.len(), 1);
+        match diff.operations[0] {
+            PatchOperation::Replace { ref path, .. } => assert_eq!(path, ""),
+            _ => panic!("The whole state should be replaced"),
+        }
+    }
+
+    #[test]
+    fn operations() {
+        let previous: Value =
+            serde_json::from_str(r#"{"a/b":1,"c~":[1,2,3],"d":true,"e":{"f":null}}"#).unwrap();
+        let current: Value =
+            serde_json::from_str(r#"{"a/b":2,"c~":[1],"e":{"f":null,"g":[]}}"#).unwrap();
+        let diff = LayoutStateDiff::new(Some(&previous), &current);
+
+        let mut buf = Vec::new();
+        diff.write_json(&mut buf).unwrap();
+        assert_eq!(
+            String::from_utf8(buf).unwrap(),
+            r#"[{"op":"remove","path":"/d"},{"op":"replace","path":"/a~1b","value":2},"#.to_owned()
+                + r#"{"op":"remove","path":"/c~0/2"},{"op":"remove","path":"/c~0/1"},"#
+                + r#"{"op":"add","path":"/e/g","value":[]}]"#
+        );
+
+        let mut document = previous;
+        apply(&mut document, &diff);
+        assert_eq!(document, current);
+    }
+}
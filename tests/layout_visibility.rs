@@ -0,0 +1,171 @@
+extern crate livesplit_core;
+
+mod visibility {
+    use livesplit_core::{Layout, Run, Segment, Timer};
+    use livesplit_core::component::{container, separator, timer, title};
+    use livesplit_core::layout::{ComponentState, Entry, LayoutSettings, Visibility};
+    use std::io::Cursor;
+
+    fn timer() -> Timer {
+        let mut run = Run::new();
+        run.push_segment(Segment::new("A"));
+        run.push_segment(Segment::new("B"));
+        Timer::new(run).unwrap()
+    }
+
+    fn layout(visibility: Visibility) -> Layout {
+        let mut layout = Layout::new();
+        layout.push(title::Component::new());
+        layout.push_with_visibility(timer::Component::new(), visibility);
+        layout
+    }
+
+    fn visible(layout: &mut Layout, timer: &Timer) -> usize {
+        layout.state(timer).components.len()
+    }
+
+    #[test]
+    fn components_are_visible_by_default() {
+        let mut layout = layout(Visibility::default());
+        let mut timer = timer();
+        assert_eq!(visible(&mut layout, &timer), 2);
+        timer.start();
+        assert_eq!(visible(&mut layout, &timer), 2);
+    }
+
+    #[test]
+    fn components_added_directly_are_visible() {
+        let visibility = Visibility {
+            only_while_running: true,
+            ..Default::default()
+        };
+        let mut layout = layout(visibility.clone());
+        layout
+            .components
+            .push(Entry::new(separator::Component::new()));
+        assert_eq!(layout.visibility(2), Some(&Visibility::default()));
+        assert_eq!(layout.visibility(3), None);
+        assert_eq!(visible(&mut layout, &timer()), 2);
+
+        // The visibility stays with its component when the components change.
+        layout.components.remove(0);
+        assert_eq!(visible(&mut layout, &timer()), 1);
+        assert_eq!(layout.visibility(0), Some(&visibility));
+        assert_eq!(layout.visibility(1), Some(&Visibility::default()));
+    }
+
+    #[test]
+    fn only_while_running() {
+        let mut layout = layout(Visibility {
+            only_while_running: true,
+            ..Default::default()
+        });
+        let mut timer = timer();
+        assert_eq!(visible(&mut layout, &timer), 1);
+        timer.start();
+        assert_eq!(visible(&mut layout, &timer), 2);
+        timer.pause();
+        assert_eq!(visible(&mut layout, &timer), 2);
+        timer.resume();
+        timer.split();
+        timer.split();
+        assert_eq!(visible(&mut layout, &timer), 1);
+    }
+
+    #[test]
+    fn only_while_not_running() {
+        let mut layout = layout(Visibility {
+            only_while_not_running: true,
+            ..Default::default()
+        });
+        let mut timer = timer();
+        assert_eq!(visible(&mut layout, &timer), 2);
+        timer.start();
+        assert_eq!(visible(&mut layout, &timer), 1);
+        timer.reset(true);
+        assert_eq!(visible(&mut layout, &timer), 2);
+    }
+
+    #[test]
+    fn only_with_comparison() {
+        let mut layout = layout(Visibility {
+            only_with_comparison: Some(String::from("Personal Best")),
+            ..Default::default()
+        });
+        let mut timer = timer();
+        assert_eq!(timer.current_comparison(), "Personal Best");
+        assert_eq!(visible(&mut layout, &timer), 2);
+        timer.switch_to_next_comparison();
+        assert_eq!(visible(&mut layout, &timer), 1);
+    }
+
+    #[test]
+    fn only_after_segment() {
+        let mut layout = layout(Visibility {
+            only_after_segment: 1,
+            ..Default::default()
+        });
+        let mut timer = timer();
+        assert_eq!(visible(&mut layout, &timer), 1);
+        timer.start();
+        assert_eq!(visible(&mut layout, &timer), 1);
+        timer.split();
+        assert_eq!(visible(&mut layout, &timer), 2);
+        timer.split();
+        assert_eq!(visible(&mut layout, &timer), 2);
+        timer.reset(true);
+        assert_eq!(visible(&mut layout, &timer), 1);
+    }
+
+    #[test]
+    fn hidden_children_of_containers() {
+        let mut row = container::Component::new();
+        row.push(title::Component::new());
+        row.push(timer::Component::new());
+        row.children[0].visibility.only_while_running = true;
+
+        let mut layout = Layout::new();
+        layout.push(row);
+        let mut timer = timer();
+
+        let children = |layout: &mut Layout, timer: &Timer| {
+            match layout.state(timer).components[0] {
+                ComponentState::Container(ref state) => state.children.len(),
+                _ => panic!("Expected the container component"),
+            }
+        };
+        assert_eq!(children(&mut layout, &timer), 1);
+        timer.start();
+        assert_eq!(children(&mut layout, &timer), 2);
+    }
+
+    #[test]
+    fn settings_keep_the_visibility() {
+        let visibility = Visibility {
+            only_while_not_running: true,
+            only_after_segment: 2,
+            ..Default::default()
+        };
+        let mut buf = Vec::new();
+        layout(visibility.clone())
+            .settings()
+            .write_json(&mut buf)
+            .unwrap();
+        let settings = LayoutSettings::from_json(Cursor::new(buf)).unwrap();
+        assert_eq!(settings.visibility, [Visibility::default(), visibility]);
+
+        // Settings stored before there were visibilities don't have any.
+        let mut settings = Layout::default_layout().settings();
+        assert_eq!(settings.visibility.len(), 4);
+        settings.visibility.clear();
+        let mut buf = Vec::new();
+        settings.write_json(&mut buf).unwrap();
+        let json = String::from_utf8(buf).unwrap().replace(r#","visibility":[]"#, "");
+        assert!(!json.contains("visibility"));
+        let settings = LayoutSettings::from_json(json.as_bytes()).unwrap();
+        assert!(settings.visibility.is_empty());
+        let layout = Layout::from_settings(settings);
+        assert_eq!(layout.components.len(), 4);
+        assert_eq!(layout.visibility(3), Some(&Visibility::default()));
+    }
+}
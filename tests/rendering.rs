@@ -0,0 +1,532 @@
+#[cfg(feature = "software-rendering")]
+extern crate image;
+extern crate livesplit_core;
+
+mod render {
+    use livesplit_core::{Layout, Run, Segment, Time, TimeSpan, Timer};
+    use livesplit_core::component::{blank_space, container, previous_segment, separator, splits,
+                                    text, timer};
+    use livesplit_core::layout::LayoutState;
+    use livesplit_core::rendering::{decode_data_url, Backend, FontKind, Renderer, TextAlignment,
+                                    TextFont};
+    use livesplit_core::settings::{Color, Font, Gradient, LayoutDirection};
+
+    // A 2x2 PNG image that is fully red.
+    const RED_PNG: [u8; 74] = [
+        0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0x00, 0x00, 0x00, 0x0D, 0x49, 0x48, 0x44,
+        0x52, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x08, 0x06, 0x00, 0x00, 0x00, 0x72,
+        0xB6, 0x0D, 0x24, 0x00, 0x00, 0x00, 0x11, 0x49, 0x44, 0x41, 0x54, 0x78, 0x9C, 0x63, 0xF8,
+        0xCF, 0xC0, 0xF0, 0x1F, 0x84, 0x19, 0x60, 0x0C, 0x00, 0x47, 0xCA, 0x07, 0xF9, 0x67, 0x59,
+        0x6E, 0xB7, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4E, 0x44, 0xAE, 0x42, 0x60, 0x82,
+    ];
+
+    /// Records what gets drawn. Every character is half as wide as the text
+    /// is large.
+    #[derive(Default)]
+    struct Recorder {
+        texts: Vec<(String, [f32; 2], FontKind, TextAlignment)>,
+        fonts: Vec<(f32, Option<Font>)>,
+        rectangles: usize,
+        images: Vec<[f32; 2]>,
+    }
+
+    impl Backend for Recorder {
+        type Image = String;
+
+        fn fill_rectangle(&mut self, _: [f32; 2], _: [f32; 2], _: &Gradient) {
+            self.rectangles += 1;
+        }
+
+        fn fill_polygon(&mut self, _: &[[f32; 2]], _: Color) {}
+
+        fn stroke_line(&mut self, _: [f32; 2], _: [f32; 2], _: f32, _: Color) {}
+
+        fn draw_text(
+            &mut self,
+            text: &str,
+            pos: [f32; 2],
+            size: f32,
+            font: TextFont,
+            _: [Color; 2],
+            alignment: TextAlignment,
+        ) {
+            self.texts
+                .push((text.to_owned(), pos, font.kind, alignment));
+            self.fonts.push((size, font.font.cloned()));
+        }
+
+        fn measure_text(&mut self, text: &str, size: f32, _: TextFont) -> f32 {
+            0.5 * size * text.chars().count() as f32
+        }
+
+        fn create_image(&mut self, url: &str) -> Option<String> {
+            Some(url.to_owned())
+        }
+
+        fn draw_image(&mut self, _: &String, top_left: [f32; 2], _: [f32; 2]) {
+            self.images.push(top_left);
+        }
+    }
+
+    fn timer() -> Timer {
+        let mut run = Run::new();
+        run.set_game_name("Game");
+        run.set_category_name("Category");
+        for &(name, pb) in &[("A", 10.0), ("B", 25.0)] {
+            let mut segment = Segment::new(name);
+            segment.set_personal_best_split_time(
+                Time::new().with_real_time(Some(TimeSpan::from_seconds(pb))),
+            );
+            run.push_segment(segment);
+        }
+        run.segment_mut(1).set_icon(&RED_PNG[..]);
+        Timer::new(run).unwrap()
+    }
+
+    fn texts(recorder: &Recorder) -> Vec<&str> {
+        recorder.texts.iter().map(|t| t.0.as_str()).collect()
+    }
+
+    #[test]
+    fn default_layout() {
+        let timer = timer();
+        let state = Layout::default_layout().state(&timer);
+        let mut recorder = Recorder::default();
+        Renderer::new().render(&mut recorder, &state, [300.0, 150.0]);
+
+        let texts = texts(&recorder);
+        assert!(texts.contains(&"Game"));
+        assert!(texts.contains(&"Category"));
+        assert!(texts.contains(&"A"));
+        assert!(texts.contains(&"B"));
+        assert!(recorder.texts.iter().any(|t| t.2 == FontKind::Timer));
+        assert_eq!(recorder.images.len(), 1);
+    }
+
+    #[test]
+    fn icons_are_kept_between_frames() {
+        let timer = timer();
+        let mut layout = Layout::default_layout();
+        let mut renderer = Renderer::new();
+        for _ in 0..2 {
+            let state = layout.state(&timer);
+            let mut recorder = Recorder::default();
+            renderer.render(&mut recorder, &state, [300.0, 150.0]);
+            assert_eq!(recorder.images.len(), 1);
+        }
+    }
+
+    #[test]
+    fn text_alignment() {
+        let mut layout = Layout::new();
+        layout.push(text::Component::with_settings(text::Settings {
+            text: text::Text::Split(String::from("Left"), String::from("Right")),
+        }));
+        layout.push(text::Component::with_settings(text::Settings {
+            text: text::Text::Center(String::from("Center")),
+        }));
+        let state = layout.state(&timer());
+
+        let mut recorder = Recorder::default();
+        Renderer::new().render(&mut recorder, &state, [300.0, 48.0]);
+
+        let text = |text: &str| {
+            let t = recorder.texts.iter().find(|t| t.0 == text).unwrap();
+            (t.1, t.3)
+        };
+        let (left, right, center) = (text("Left"), text("Right"), text("Center"));
+        assert_eq!(left, ([6.0, left.0[1]], TextAlignment::Left));
+        assert_eq!(right, ([294.0, left.0[1]], TextAlignment::Right));
+        assert_eq!(center.1, TextAlignment::Center);
+        assert_eq!(center.0[0], 150.0);
+        assert!(center.0[1] > right.0[1]);
+    }
+
+    #[test]
+    fn containers() {
+        let center = |t: &str| {
+            text::Component::with_settings(text::Settings {
+                text: text::Text::Center(String::from(t)),
+            })
+        };
+        let mut row = container::Component::new();
+        row.push(center("A"));
+        row.push(center("B"));
+        row.children[1].size = 3.0;
+
+        let mut layout = Layout::new();
+        layout.push(row);
+        layout.push(center("C"));
+        let state = layout.state(&timer());
+
+        let mut recorder = Recorder::default();
+        Renderer::new().render(&mut recorder, &state, [400.0, 48.0]);
+
+        let text = |text: &str| recorder.texts.iter().find(|t| t.0 == text).unwrap().1;
+        let (a, b, c) = (text("A"), text("B"), text("C"));
+        assert_eq!(a[0], 50.0);
+        assert_eq!(b[0], 250.0);
+        assert_eq!(c[0], 200.0);
+        assert_eq!(a[1], b[1]);
+        assert!(c[1] > a[1]);
+    }
+
+    #[test]
+    fn components_are_scaled_to_the_height() {
+        let mut layout = Layout::new();
+        layout.push(blank_space::Component::new());
+        layout.push(separator::Component::new());
+        let state: LayoutState = layout.state(&timer());
+
+        let mut recorder = Recorder::default();
+        Renderer::new().render(&mut recorder, &state, [100.0, 0.0]);
+        assert_eq!(recorder.rectangles, 1);
+
+        let mut recorder = Recorder::default();
+        Renderer::new().render(&mut recorder, &state, [100.0, 100.0]);
+        assert_eq!(recorder.rectangles, 3);
+    }
+
+    #[test]
+    fn horizontal_layout() {
+        let timer = timer();
+        let mut layout = Layout::default_layout();
+        layout.general_settings_mut().direction = LayoutDirection::Horizontal;
+        let state = layout.state(&timer);
+        let mut recorder = Recorder::default();
+        Renderer::new().render(&mut recorder, &state, [1000.0, 60.0]);
+
+        let text = |text: &str| recorder.texts.iter().find(|t| t.0 == text).unwrap();
+        let (game, a, b) = (text("Game"), text("A"), text("B"));
+        assert!(game.1[0] < a.1[0]);
+        assert!(a.1[0] < b.1[0]);
+        assert_eq!(a.1[1], b.1[1]);
+
+        let label = text("Previous Segment");
+        assert_eq!(label.3, TextAlignment::Center);
+        assert!(label.1[0] > b.1[0]);
+        assert!(recorder.texts.iter().all(|t| t.1[0] < 1000.0 && t.1[1] < 60.0));
+        assert_eq!(recorder.images.len(), 1);
+    }
+
+    #[test]
+    fn fonts() {
+        let mut splits = splits::Component::new();
+        splits.settings_mut().times_font = Some(Font {
+            scale: 2.0,
+            ..Font::new("Splits")
+        });
+        let mut layout = Layout::new();
+        layout.general_settings_mut().times_font = Some(Font::new("Times"));
+        layout.general_settings_mut().text_font = Some(Font::new("Text"));
+        layout.push(splits);
+        layout.push(timer::Component::new());
+        layout.push(previous_segment::Component::new());
+        let state = layout.state(&timer());
+
+        let mut recorder = Recorder::default();
+        Renderer::new().render(&mut recorder, &state, [300.0, 150.0]);
+
+        let text = |text: &str| {
+            let i = recorder.texts.iter().position(|t| t.0 == text).unwrap();
+            let (size, ref font) = recorder.fonts[i];
+            (recorder.texts[i].2, font.as_ref().map(|f| f.family.as_str()), size)
+        };
+        let (name, time) = (text("A"), text("0:10"));
+        assert_eq!((name.0, name.1), (FontKind::Text, Some("Text")));
+        assert_eq!((time.0, time.1), (FontKind::Times, Some("Splits")));
+        assert_eq!(time.2, 2.0 * name.2);
+
+        let timer = recorder.texts.iter().position(|t| t.2 == FontKind::Timer).unwrap();
+        assert_eq!(recorder.fonts[timer].1, None);
+        assert_eq!(text("Previous Segment").1, Some("Text"));
+        assert_eq!(text("—").0, FontKind::Times);
+        assert_eq!(text("—").1, Some("Times"));
+    }
+
+    #[test]
+    fn data_urls() {
+        let url = livesplit_core::Image::new(&RED_PNG).url().to_owned();
+        assert_eq!(decode_data_url(&url).unwrap(), &RED_PNG[..]);
+        assert_eq!(decode_data_url("https://example.com/icon.png"), None);
+    }
+
+    #[cfg(feature = "software-rendering")]
+    mod software {
+        use std::env;
+        use std::fs;
+        use image::{self, RgbaImage};
+        use livesplit_core::Layout;
+        use livesplit_core::rendering::{Backend, FontKind, Renderer, TextAlignment, TextFont};
+        use livesplit_core::rendering::software::{self, Canvas, Fonts};
+        use livesplit_core::settings::Gradient;
+        use super::{timer, RED_PNG};
+
+        /// Loads the subset of DejaVu Sans that is stored next to the tests
+        /// for both the timer and the text.
+        fn fonts() -> Fonts {
+            let font = fs::read("tests/fonts/DejaVuSans.ttf").unwrap();
+            Fonts::from_bytes(font.clone(), font).unwrap()
+        }
+
+        /// Compares the image to the one stored in the snapshot. Setting
+        /// `UPDATE_SNAPSHOTS` stores the new image instead.
+        fn check_snapshot(name: &str, image: &RgbaImage) {
+            let path = format!("tests/png_files/{}.png", name);
+            if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+                image.save(&path).unwrap();
+            }
+            let snapshot = image::open(&path).unwrap().to_rgba();
+            assert_eq!(snapshot.dimensions(), image.dimensions());
+            assert!(snapshot.pixels().eq(image.pixels()));
+        }
+
+        /// The first and the last column that contain any text.
+        fn ink(image: &RgbaImage) -> (i32, i32) {
+            let mut columns = (0..image.width())
+                .filter(|&x| (0..image.height()).any(|y| image.get_pixel(x, y).data[3] > 0));
+            let first = columns.next().unwrap();
+            let last = columns.next_back().unwrap_or(first);
+            (first as i32, last as i32)
+        }
+
+        #[test]
+        fn gradients() {
+            let mut canvas = Canvas::new(4, 4, None);
+            let (black, white) = ((0.0, 0.0, 0.0, 1.0).into(), (1.0, 1.0, 1.0, 1.0).into());
+            canvas.fill_rectangle([0.0, 0.0], [4.0, 4.0], &Gradient::Vertical(black, white));
+            canvas.fill_rectangle([0.0, 0.0], [1.0, 1.0], &Gradient::Transparent);
+
+            let image = canvas.into_image();
+            assert_eq!(image.get_pixel(0, 0).data, [32, 32, 32, 255]);
+            assert_eq!(image.get_pixel(3, 0).data, [32, 32, 32, 255]);
+            assert_eq!(image.get_pixel(0, 3).data, [223, 223, 223, 255]);
+        }
+
+        #[test]
+        fn polygons() {
+            let mut canvas = Canvas::new(4, 4, None);
+            let red = (1.0, 0.0, 0.0, 1.0).into();
+            canvas.fill_polygon(&[[0.0, 0.0], [4.0, 0.0], [0.0, 4.0]], red);
+
+            let image = canvas.into_image();
+            assert_eq!(image.get_pixel(0, 0).data, [255, 0, 0, 255]);
+            assert_eq!(image.get_pixel(3, 3).data[3], 0);
+            let diagonal = image.get_pixel(1, 2).data;
+            assert_eq!(diagonal[0], 255);
+            assert!(diagonal[3] > 0 && diagonal[3] < 255);
+        }
+
+        #[test]
+        fn layouts_render_without_fonts() {
+            let timer = timer();
+            let state = Layout::default_layout().state(&timer);
+            let mut canvas = Canvas::new(150, 250, None);
+            Renderer::new().render(&mut canvas, &state, [150.0, 250.0]);
+
+            let image = canvas.into_image();
+            assert!(image.pixels().all(|p| p.data[3] == 255));
+            assert!(image.pixels().any(|p| p.data == [255, 0, 0, 255]));
+        }
+
+        #[test]
+        fn invalid_images() {
+            let mut canvas = Canvas::new(1, 1, None);
+            assert!(canvas.create_image("data:;base64,AAAA").is_none());
+            let url = ::livesplit_core::Image::new(&RED_PNG).url().to_owned();
+            assert_eq!(canvas.create_image(&url).unwrap().dimensions(), (2, 2));
+        }
+
+        #[test]
+        fn text_alignment() {
+            let font = TextFont {
+                kind: FontKind::Text,
+                font: None,
+            };
+            let white = (1.0, 1.0, 1.0, 1.0).into();
+            let range = |alignment| {
+                let mut canvas = Canvas::new(200, 30, Some(fonts()));
+                let width = canvas.measure_text("Split", 20.0, font);
+                canvas.draw_text("Split", [100.0, 20.0], 20.0, font, [white; 2], alignment);
+                (width, ink(&canvas.into_image()))
+            };
+
+            // The ink of the glyphs doesn't quite reach the edges of their
+            // advance widths, so the bounds are only compared within 2 pixels.
+            let (width, (left, _)) = range(TextAlignment::Left);
+            assert!(width > 30.0 && width < 60.0);
+            assert!((left - 101).abs() <= 1);
+
+            let (_, (_, right)) = range(TextAlignment::Right);
+            assert!((right - 98).abs() <= 2);
+
+            let (_, (left, right)) = range(TextAlignment::Center);
+            assert!((left + right - 200).abs() <= 4);
+        }
+
+        #[test]
+        fn default_layout() {
+            let timer = timer();
+            let state = Layout::default_layout().state(&timer);
+            let mut renderer = software::Renderer::new(fonts());
+            check_snapshot("default_layout", renderer.render(&state, [300, 250]));
+        }
+    }
+
+    mod svg {
+        use std::env;
+        use std::fs;
+        use livesplit_core::Layout;
+        use livesplit_core::component::graph;
+        use livesplit_core::layout::{ComponentState, LayoutState};
+        use livesplit_core::rendering::svg::Renderer;
+        use livesplit_core::settings::{Color, Font, FontStyle, FontWeight, Gradient,
+                                       LayoutDirection};
+        use super::timer;
+
+        /// Compares the SVG document of the layout state to the one stored in
+        /// the snapshot. Setting `UPDATE_SNAPSHOTS` stores the new document
+        /// instead.
+        fn check_snapshot(name: &str, state: &LayoutState, dimensions: [f32; 2]) {
+            let mut buf = Vec::new();
+            Renderer::new().render(state, dimensions, &mut buf).unwrap();
+            let document = String::from_utf8(buf).unwrap();
+
+            let path = format!("tests/svg_files/{}.svg", name);
+            if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+                fs::write(&path, &document).unwrap();
+            }
+            assert_eq!(document, fs::read_to_string(&path).unwrap());
+        }
+
+        fn color(r: f32, g: f32, b: f32, a: f32) -> Color {
+            (r, g, b, a).into()
+        }
+
+        #[test]
+        fn default_layout() {
+            let state = Layout::default_layout().state(&timer());
+            check_snapshot("default_layout", &state, [300.0, 150.0]);
+        }
+
+        #[test]
+        fn fonts() {
+            let mut layout = Layout::default_layout();
+            layout.general_settings_mut().timer_font = Some(Font {
+                style: FontStyle::Italic,
+                weight: FontWeight::Bold,
+                scale: 1.2,
+                ..Font::new("Fira Mono")
+            });
+            layout.general_settings_mut().times_font = Some(Font {
+                weight: FontWeight::SemiBold,
+                ..Font::new("Fira Sans")
+            });
+            layout.general_settings_mut().text_font = Some(Font::new("Fira Sans"));
+            let state = layout.state(&timer());
+            check_snapshot("fonts", &state, [300.0, 150.0]);
+        }
+
+        #[test]
+        fn graph() {
+            let point = |x, y, is_best_segment| graph::Point {
+                x,
+                y,
+                is_best_segment,
+            };
+            let state = LayoutState {
+                components: vec![
+                    ComponentState::Graph(graph::State {
+                        points: vec![
+                            point(0.0, 0.5, false),
+                            point(0.25, 0.2, true),
+                            point(0.5, 0.7, false),
+                            point(0.8, 0.4, false),
+                        ],
+                        horizontal_grid_lines: vec![0.25, 0.75],
+                        vertical_grid_lines: vec![0.5],
+                        middle: 0.5,
+                        is_live_delta_active: true,
+                        is_flipped: false,
+                        top_background_color: color(0.45, 0.16, 0.16, 1.0),
+                        bottom_background_color: color(0.16, 0.45, 0.2, 1.0),
+                        grid_lines_color: color(0.0, 0.0, 0.0, 0.15),
+                        graph_lines_color: color(1.0, 1.0, 1.0, 1.0),
+                        partial_fill_color: color(1.0, 1.0, 1.0, 0.25),
+                        complete_fill_color: color(1.0, 1.0, 1.0, 0.4),
+                        best_segment_color: color(1.0, 0.8, 0.0, 1.0),
+                        height: 80,
+                    }),
+                ],
+                direction: LayoutDirection::Vertical,
+                background: Gradient::Vertical(
+                    color(0.1, 0.1, 0.1, 1.0),
+                    color(0.2, 0.2, 0.2, 1.0),
+                ),
+                thin_separators_color: color(1.0, 1.0, 1.0, 0.1),
+                separators_color: color(1.0, 1.0, 1.0, 0.35),
+                text_color: color(1.0, 1.0, 1.0, 1.0),
+                timer_font: None,
+                times_font: None,
+                text_font: None,
+            };
+            check_snapshot("graph", &state, [200.0, 80.0]);
+        }
+    }
+
+    mod ansi {
+        use livesplit_core::Layout;
+        use livesplit_core::component::text;
+        use livesplit_core::rendering::ansi::Renderer;
+        use livesplit_core::settings::Gradient;
+        use super::timer;
+
+        #[test]
+        fn default_layout() {
+            let state = Layout::default_layout().state(&timer());
+            let mut renderer = Renderer::new();
+            let terminal = renderer.render(&state, 30);
+
+            assert_eq!(terminal.columns(), 30);
+            assert_eq!(terminal.rows(), 8);
+            assert_eq!(terminal.row_text(0), "             Game             ");
+            assert_eq!(terminal.row_text(2), " A                       0:10 ");
+        }
+
+        #[test]
+        fn colors() {
+            let mut layout = Layout::new();
+            layout.general_settings_mut().background = Gradient::Plain((0.0, 0.0, 0.0, 1.0).into());
+            layout.push(text::Component::with_settings(text::Settings {
+                text: text::Text::Center(String::from("Hi")),
+            }));
+            let state = layout.state(&timer());
+
+            let mut output = Vec::new();
+            Renderer::new().render(&state, 4).write(&mut output).unwrap();
+            assert_eq!(
+                String::from_utf8(output).unwrap(),
+                "\x1b[38;5;231;48;5;16m Hi \x1b[0m\n"
+            );
+        }
+
+        #[test]
+        fn control_characters() {
+            let mut layout = Layout::new();
+            layout.push(text::Component::with_settings(text::Settings {
+                text: text::Text::Center(String::from("\x1b[2J\x07")),
+            }));
+            let state = layout.state(&timer());
+
+            let mut renderer = Renderer::new();
+            let terminal = renderer.render(&state, 8);
+            assert_eq!(terminal.row_text(0), "  \u{FFFD}[2J\u{FFFD} ");
+
+            let mut output = Vec::new();
+            terminal.write(&mut output).unwrap();
+            let output = String::from_utf8(output).unwrap();
+            assert!(!output.contains('\x07'));
+            assert_eq!(output.matches('\x1b').count(), 2);
+        }
+    }
+}
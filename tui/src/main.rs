@@ -0,0 +1,155 @@
+//! A speedrun timer for the terminal. It loads a splits file and optionally a
+//! layout, draws the layout with ANSI escape codes and is controlled with the
+//! keyboard. Whenever the timer gets reset, the splits are saved as a
+//! LiveSplit splits file next to the original file.
+
+#[cfg(unix)]
+extern crate libc;
+extern crate livesplit_core;
+
+mod terminal;
+
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+use livesplit_core::{Layout, Timer};
+use livesplit_core::layout::{parser as layout_parser, LayoutSettings};
+use livesplit_core::rendering::ansi::Renderer;
+use livesplit_core::run::parser::composite;
+use livesplit_core::run::saver;
+
+const USAGE: &str = "Usage: livesplit-tui <splits file> [layout file]";
+const HELP: &str = "Space: Split  Backspace: Undo  S: Skip  P: Pause  R: Reset  C: Comparison  \
+                    Q: Quit";
+const DEFAULT_COLUMNS: usize = 40;
+const MAX_COLUMNS: usize = 50;
+const FRAME_DURATION_MS: u64 = 33;
+/// The byte that Ctrl-C produces, as the terminal doesn't turn it into a
+/// signal while the timer is running.
+const CTRL_C: u8 = 0x03;
+
+fn load_timer(path: &Path) -> Result<Timer, String> {
+    let file = File::open(path).map_err(|e| format!("Couldn't open the splits file: {}", e))?;
+    let parsed = composite::parse(BufReader::new(file), Some(path.to_owned()), true)
+        .map_err(|e| format!("Couldn't parse the splits file: {}", e))?;
+    Timer::new(parsed.run).map_err(|_| String::from("The splits file doesn't contain any segments"))
+}
+
+/// Loads a LiveSplit layout file. Layouts stored as JSON are supported as
+/// well.
+fn load_layout(path: &Path) -> Result<Layout, String> {
+    let open = || File::open(path).map_err(|e| format!("Couldn't open the layout file: {}", e));
+    if let Ok(parsed) = layout_parser::parse(BufReader::new(open()?)) {
+        return Ok(parsed.layout);
+    }
+    LayoutSettings::from_json(BufReader::new(open()?))
+        .map(Layout::from_settings)
+        .map_err(|_| String::from("Couldn't parse the layout file"))
+}
+
+/// Saves the splits into a temporary file first, which then replaces the
+/// splits file. This way the splits file is never left behind only partially
+/// written.
+fn save_splits(timer: &Timer, path: &Path) -> Result<(), String> {
+    let temp_path = path.with_extension("lss.tmp");
+    let result = write_splits(timer, &temp_path)
+        .and_then(|_| fs::rename(&temp_path, path).map_err(|e| e.to_string()));
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    result.map_err(|e| format!("Couldn't save the splits: {}", e))
+}
+
+fn write_splits(timer: &Timer, path: &Path) -> Result<(), String> {
+    let file = File::create(path).map_err(|e| e.to_string())?;
+    let mut writer = BufWriter::new(file);
+    saver::livesplit::save(timer.run(), &mut writer).map_err(|e| e.to_string())?;
+    writer.flush().map_err(|e| e.to_string())?;
+    writer.get_ref().sync_all().map_err(|e| e.to_string())
+}
+
+fn draw(
+    renderer: &mut Renderer,
+    layout: &mut Layout,
+    timer: &Timer,
+    columns: usize,
+    status: &str,
+) -> io::Result<()> {
+    let state = layout.state(timer);
+    let stdout = io::stdout();
+    let mut stdout = BufWriter::new(stdout.lock());
+    write!(stdout, "\x1b[H")?;
+    renderer.render(&state, columns).write(&mut stdout)?;
+    write!(stdout, "\x1b[J{}\n{}", HELP, status)?;
+    stdout.flush()
+}
+
+fn run() -> Result<(), String> {
+    let mut args = env::args_os().skip(1);
+    let splits_path = PathBuf::from(args.next().ok_or(USAGE)?);
+    let mut timer = load_timer(&splits_path)?;
+    let mut layout = match args.next() {
+        Some(path) => load_layout(Path::new(&path))?,
+        None => Layout::default_layout(),
+    };
+    let save_path = splits_path.with_extension("lss");
+    let columns = terminal::columns().map_or(DEFAULT_COLUMNS, |c| c.min(MAX_COLUMNS));
+
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for byte in io::stdin().bytes() {
+            match byte {
+                Ok(byte) => if sender.send(byte).is_err() {
+                    break;
+                },
+                Err(_) => break,
+            }
+        }
+    });
+
+    let _raw_mode = terminal::RawMode::enable();
+    print!("\x1b[2J\x1b[?25l");
+
+    let mut renderer = Renderer::new();
+    let mut status = String::new();
+    let result = loop {
+        match receiver.recv_timeout(Duration::from_millis(FRAME_DURATION_MS)) {
+            Ok(b' ') => timer.split_or_start(),
+            Ok(0x08) | Ok(0x7F) => timer.undo_split(),
+            Ok(b's') => timer.skip_split(),
+            Ok(b'p') => timer.toggle_pause(),
+            Ok(b'c') => timer.switch_to_next_comparison(),
+            Ok(b'r') => {
+                timer.reset(true);
+                status = match save_splits(&timer, &save_path) {
+                    Ok(()) => format!("Saved the splits to {}", save_path.display()),
+                    Err(e) => e,
+                };
+            }
+            Ok(b'q') | Ok(CTRL_C) | Err(RecvTimeoutError::Disconnected) => {
+                timer.reset(true);
+                break save_splits(&timer, &save_path);
+            }
+            Ok(_) | Err(RecvTimeoutError::Timeout) => {}
+        }
+
+        if let Err(e) = draw(&mut renderer, &mut layout, &timer, columns, &status) {
+            break Err(format!("Couldn't draw the timer: {}", e));
+        }
+    };
+
+    println!("\x1b[?25h");
+    result
+}
+
+fn main() {
+    if let Err(e) = run() {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
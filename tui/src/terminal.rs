@@ -0,0 +1,72 @@
+//! Switches the terminal into a mode where every key press is available on
+//! stdin right away, instead of only after pressing Enter. Ctrl-C doesn't
+//! interrupt the process in that mode, but is read as a key press as well, so
+//! the terminal is always restored before the process exits.
+
+#[cfg(unix)]
+mod imp {
+    use libc::{self, ioctl, tcgetattr, tcsetattr, termios, winsize, ECHO, ICANON, ISIG,
+               STDIN_FILENO, STDOUT_FILENO, TCSANOW, TIOCGWINSZ};
+    use std::mem;
+
+    pub struct RawMode {
+        original: Option<termios>,
+    }
+
+    impl RawMode {
+        pub fn enable() -> Self {
+            unsafe {
+                let mut original = mem::zeroed();
+                if tcgetattr(STDIN_FILENO, &mut original) != 0 {
+                    return RawMode { original: None };
+                }
+                let mut raw = original;
+                raw.c_lflag &= !(ICANON | ECHO | ISIG);
+                raw.c_cc[libc::VMIN] = 1;
+                raw.c_cc[libc::VTIME] = 0;
+                tcsetattr(STDIN_FILENO, TCSANOW, &raw);
+                RawMode {
+                    original: Some(original),
+                }
+            }
+        }
+    }
+
+    impl Drop for RawMode {
+        fn drop(&mut self) {
+            if let Some(ref original) = self.original {
+                unsafe {
+                    tcsetattr(STDIN_FILENO, TCSANOW, original);
+                }
+            }
+        }
+    }
+
+    pub fn columns() -> Option<usize> {
+        unsafe {
+            let mut size: winsize = mem::zeroed();
+            if ioctl(STDOUT_FILENO, TIOCGWINSZ, &mut size) == 0 && size.ws_col > 0 {
+                Some(size.ws_col as usize)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+#[cfg(not(unix))]
+mod imp {
+    pub struct RawMode;
+
+    impl RawMode {
+        pub fn enable() -> Self {
+            RawMode
+        }
+    }
+
+    pub fn columns() -> Option<usize> {
+        None
+    }
+}
+
+pub use self::imp::{columns, RawMode};